@@ -1,32 +1,215 @@
+#[cfg(feature = "bindgen")]
 extern crate bindgen;
+#[cfg(feature = "static")]
+extern crate cc;
+#[cfg(all(feature = "pkg-config", not(feature = "static")))]
 extern crate pkg_config;
 
 use std::env;
+#[cfg(feature = "static")]
+use std::path::Path;
 use std::path::PathBuf;
 
+/// The checked-in bindings, whose FRIBIDI_VERSION the library has to match.
+const BINDINGS: &str = "src/fribidi_bindings.rs";
+
 fn main() {
-    // Tell cargo to look for shared libraries in the specified directory
-    // println!("cargo:rustc-link-search=/path/to/lib");
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed={}", BINDINGS);
 
-    let pkg_config_library = pkg_config::Config::new()
-        .print_system_libs(false)
-        .statik(false)
-        .probe("fribidi")
-        .unwrap();
-    let include_paths = pkg_config_library.include_paths;
+    #[cfg(feature = "static")]
+    let include_paths = build_vendored(&out_path);
+    #[cfg(not(feature = "static"))]
+    let include_paths = probe_system();
 
-    println!("cargo:rustc-link-lib=fribidi");
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&include_paths, &out_path);
+    #[cfg(not(feature = "bindgen"))]
+    let _ = (include_paths, out_path);
+}
+
+/// The value of the constant name in the checked-in bindings, without the
+/// quotes of a string.
+#[cfg(any(feature = "static", all(feature = "pkg-config", not(feature = "bindgen"))))]
+fn bindings_const(name: &str) -> String {
+    let bindings = std::fs::read_to_string(BINDINGS).unwrap();
+    let prefix = format!("pub const {}:", name);
+    let value = bindings
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .and_then(|declaration| declaration.split(" = ").nth(1))
+        .unwrap_or_else(|| panic!("{} has no {}", BINDINGS, name));
+    value.trim_end_matches(';').trim_start_matches("b\"").trim_end_matches("\\0\"").to_owned()
+}
+
+/// major.minor.micro of a version string.
+#[cfg(any(feature = "static", all(feature = "pkg-config", not(feature = "bindgen"))))]
+fn parse_version(version: &str) -> (u32, u32, u32) {
+    let mut numbers = version.split('.').map(|number| number.parse().unwrap_or(0));
+    (numbers.next().unwrap_or(0), numbers.next().unwrap_or(0), numbers.next().unwrap_or(0))
+}
+
+/// Check the library found matches the checked-in bindings. Releases of the
+/// same minor version keep their ABI, so only a different micro version is
+/// let through, with a warning.
+#[cfg(all(feature = "pkg-config", not(feature = "static"), not(feature = "bindgen")))]
+fn check_version(found: &str) {
+    let expected = bindings_const("FRIBIDI_VERSION");
+    let (found_major, found_minor, _) = parse_version(found);
+    let (expected_major, expected_minor, _) = parse_version(&expected);
+
+    if (found_major, found_minor) != (expected_major, expected_minor) {
+        panic!(
+            "found fribidi {} but the bindings are for fribidi {}, \
+             enable the `bindgen` feature to generate them for the library found",
+            found, expected
+        );
+    }
+    if found != expected {
+        println!("cargo:warning=found fribidi {} but the bindings are for fribidi {}", found, expected);
+    }
+}
+
+/// Generate the bindings of the headers in include_paths into OUT_DIR, with
+/// the fribidi items only.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_paths: &[PathBuf], out_path: &std::path::Path) {
     println!("cargo:rerun-if-changed=wrapper.h");
 
     let bindings = bindgen::Builder::default()
         .header("wrapper.h")
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .clang_args(include_paths.iter().map(|e| "-I".to_owned() + e.to_str().unwrap()))
+        .whitelist_function("fribidi_.*")
+        .whitelist_var("FRIBIDI_.*|fribidi_.*")
+        .whitelist_type("_?FriBidi.*|fribidi_.*")
+        .layout_tests(false)
         .generate()
         .expect("Unable to generate bindings");
 
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bindings
         .write_to_file(out_path.join("fribidi_bindings.rs"))
         .expect("Couldn't write bindings!");
-}
\ No newline at end of file
+}
+
+/// Link the system libfribidi dynamically, return its include paths.
+#[cfg(all(feature = "pkg-config", not(feature = "static")))]
+fn probe_system() -> Vec<PathBuf> {
+    let pkg_config_library = pkg_config::Config::new()
+        .print_system_libs(false)
+        .statik(false)
+        .probe("fribidi")
+        .unwrap();
+
+    #[cfg(not(feature = "bindgen"))]
+    check_version(&pkg_config_library.version);
+
+    println!("cargo:rustc-link-lib=fribidi");
+    pkg_config_library.include_paths
+}
+
+/// Link the system libfribidi dynamically, from the default search paths.
+#[cfg(not(any(feature = "pkg-config", feature = "static")))]
+fn probe_system() -> Vec<PathBuf> {
+    println!("cargo:rustc-link-lib=fribidi");
+    Vec::new()
+}
+
+/// Compile the vendored fribidi sources into a static libfribidi, return
+/// the include paths of its headers.
+#[cfg(feature = "static")]
+fn build_vendored(out_path: &Path) -> Vec<PathBuf> {
+    let source_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("vendor").join("fribidi");
+    let lib_dir = source_dir.join("lib");
+    println!("cargo:rerun-if-changed={}", source_dir.display());
+
+    if !lib_dir.join("fribidi.h").exists() {
+        panic!(
+            "the `static` feature needs the fribidi {} sources in {}, \
+             run tools/vendor_fribidi.py on an unpacked fribidi release to put them there",
+            bindings_const("FRIBIDI_VERSION"),
+            source_dir.display()
+        );
+    }
+
+    let config_dir = out_path.join("include");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(config_dir.join("fribidi-config.h"), fribidi_config_h()).unwrap();
+    std::fs::write(config_dir.join("config.h"), config_h()).unwrap();
+
+    let mut sources: Vec<PathBuf> = std::fs::read_dir(&lib_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "c"))
+        .collect();
+    sources.sort();
+
+    cc::Build::new()
+        .files(&sources)
+        .include(&config_dir)
+        .include(&lib_dir)
+        .define("HAVE_CONFIG_H", None)
+        .define("FRIBIDI_BUILD", None)
+        .define("FRIBIDI_LIB_STATIC", None)
+        .warnings(false)
+        .compile("fribidi");
+
+    // for the crates that depend on us, as DEP_FRIBIDI_INCLUDE
+    println!("cargo:include={}", env::join_paths([&config_dir, &lib_dir]).unwrap().to_str().unwrap());
+    vec![config_dir, lib_dir]
+}
+
+/// The public configuration header, as fribidi's own build writes it, for
+/// the release of the bindings.
+#[cfg(feature = "static")]
+fn fribidi_config_h() -> String {
+    let (major, minor, micro) = parse_version(&bindings_const("FRIBIDI_VERSION"));
+    // the build script runs on the host, so ask cargo about the target: like
+    // core::ffi::c_int, an int is 16 bits on avr and msp430 and 32 bits on
+    // every other target rustc supports
+    let sizeof_int = match env::var("CARGO_CFG_TARGET_ARCH").as_deref() {
+        Ok("avr") | Ok("msp430") => 2,
+        _ => 4,
+    };
+    let msvc = env::var("CARGO_CFG_TARGET_ENV").as_deref() == Ok("msvc");
+
+    format!(
+        "#ifndef FRIBIDI_CONFIG_H\n\
+         #define FRIBIDI_CONFIG_H\n\
+         #define FRIBIDI \"fribidi\"\n\
+         #define FRIBIDI_NAME \"GNU FriBidi\"\n\
+         #define FRIBIDI_BUGREPORT \"https://github.com/fribidi/fribidi/issues/new\"\n\
+         #define FRIBIDI_VERSION \"{major}.{minor}.{micro}\"\n\
+         #define FRIBIDI_MAJOR_VERSION {major}\n\
+         #define FRIBIDI_MINOR_VERSION {minor}\n\
+         #define FRIBIDI_MICRO_VERSION {micro}\n\
+         #define FRIBIDI_INTERFACE_VERSION {interface}\n\
+         #define FRIBIDI_INTERFACE_VERSION_STRING \"{interface}\"\n\
+         #define FRIBIDI_SIZEOF_INT {sizeof_int}\n\
+         #define FRIBIDI_BUILT_WITH_MSVC {msvc}\n\
+         #endif\n",
+        interface = bindings_const("FRIBIDI_INTERFACE_VERSION"),
+        sizeof_int = sizeof_int,
+        msvc = msvc as u8,
+    )
+}
+
+/// The private configuration header, for a hosted C99 target.
+#[cfg(feature = "static")]
+fn config_h() -> String {
+    let unix = env::var("CARGO_CFG_TARGET_FAMILY").as_deref() == Ok("unix");
+    let mut config = String::from(
+        "#define STDC_HEADERS 1\n\
+         #define HAVE_STDLIB_H 1\n\
+         #define HAVE_STRING_H 1\n\
+         #define HAVE_MEMORY_H 1\n\
+         #define HAVE_MEMSET 1\n\
+         #define HAVE_MEMMOVE 1\n\
+         #define HAVE_STRDUP 1\n\
+         #define HAVE_STRINGIZE 1\n",
+    );
+    if unix {
+        config.push_str("#define HAVE_STRINGS_H 1\n#define HAVE_SYS_TIMES_H 1\n");
+    }
+    config
+}
@@ -1,10 +1,11 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
-// suppress warnings about u128 (that we don't even mod ourselves anyway)
-#![allow(improper_ctypes)]
 
+#[cfg(not(feature = "bindgen"))]
 pub mod fribidi_bindings;
+#[cfg(feature = "bindgen")]
+pub mod fribidi_bindings { include!(concat!(env!("OUT_DIR"), "/fribidi_bindings.rs")); }
 
 // #[path="fribidi-bidi.rs"]
 // pub mod fribidi_bidi;
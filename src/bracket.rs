@@ -1,8 +1,8 @@
-use fribidi_sys::fribidi_bindings;
-
-use widestring::U32String;
+use crate::fribidi_bindings;
 
 use crate::CharType;
+use crate::error::Error;
+use crate::input::{self, BidiInput};
 
 pub type Bracket = u32;
 
@@ -74,31 +74,38 @@ impl BracketType
     ///
     /// This function finds the bracketed characters of an string of characters.
     /// See fribidi_get_bracket() for more information about the bracketed
-    /// characters returned by this function.
+    /// characters returned by this function.  char_types must have one entry
+    /// per character of input_str, or per code unit of UTF-16 text, as
+    /// `CharType::into_chartypes` returns them; so do the bracket types.
     ///
-    pub fn parse (
-        input_str: &U32String,
-        char_types: &Vec<CharType>
-    ) -> Vec<BracketType>
+    pub fn parse<T: BidiInput + ?Sized> (
+        input: &T,
+        char_types: &[CharType]
+    ) -> Result<Vec<BracketType>, Error>
     {
+        let input_str = input.to_u32str();
+        let len = Error::ffi_len(input_str.len())?;
+        Error::check_len("char_types", input::units_count(input, &input_str), char_types.len())?;
+        let char_types = input::from_units(input, &input_str, char_types);
+
         let mut bracket_types: Vec<BracketType> = vec![BracketType(0); input_str.len()];
         unsafe {
             fribidi_bindings::fribidi_get_bracket_types(
                 input_str.as_ptr(),
-                input_str.len() as i32,
+                len,
                 char_types.as_ptr() as *const u32,
                 bracket_types.as_mut_ptr() as *mut u32
             )
         };
 
-        bracket_types
+        Ok(input::to_units(input, &input_str, &bracket_types))
     }
 }
 
 #[cfg(test)]
 mod test
 {
-    use widestring::U32String;
+    use widestring::{U16String, U32String};
 
     use crate::bracket::BracketType;
 
@@ -116,12 +123,24 @@ mod test
     {
         let text = U32String::from("[{][أحمد)");
         let char_types = crate::CharType::into_chartypes(text.as_vec());
-        let bracket_types = BracketType::parse(&text, &char_types);
-        let gt: Vec<BracketType> = [2147483739 as u32, 2147483771, 91, 2147483739, 0, 0, 0, 0, 40]
+        let bracket_types = BracketType::parse(&text, &char_types).unwrap();
+        let gt: Vec<BracketType> = [2147483739_u32, 2147483771, 91, 2147483739, 0, 0, 0, 0, 40]
             .iter()
             .map(|n| BracketType(*n))
             .collect();
 
         assert_eq!(bracket_types, gt);
+
+        // both surrogates of U+1F600 get the type of the character
+        let text = U16String::from_str("(😀)");
+        let char_types = crate::CharType::into_chartypes(&text);
+        let bracket_types = BracketType::parse(&text, &char_types).unwrap();
+
+        assert_eq!(char_types.len(), 4);
+        assert_eq!(bracket_types, vec![BracketType(2147483688), BracketType(0), BracketType(0), BracketType(40)]);
+        assert_eq!(
+            BracketType::parse(&text, &char_types[..3]),
+            Err(crate::error::Error::LengthMismatch { name: "char_types", expected: 4, actual: 3 })
+        );
     }
 }
\ No newline at end of file
@@ -1,12 +1,16 @@
-use std::mem::transmute;
+use std::ffi::CStr;
+use std::fmt;
+use std::ops::BitOr;
+use std::str::FromStr;
 
-use fribidi_sys::fribidi_bindings;
-use crate::level::LevelType;
+use crate::fribidi_bindings;
+use crate::error::Error;
+use crate::input::BidiInput;
 
 pub type Char = u32;
 
 #[repr(u32)]
-#[derive(PartialEq, PartialOrd, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Debug, Clone, Copy)]
 pub enum CharType
 {
     LeftToRight              = fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_LTR,
@@ -34,31 +38,124 @@ pub enum CharType
     PopDirectionalIsolate    = fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_PDI,
 }
 
-/// BUG
-impl From<Char> for CharType
+impl From<char> for CharType
 {
-    fn from(raw: Char) -> Self
+    fn from(raw: char) -> Self
     {
-        let char_type = unsafe {
-            transmute (
-                fribidi_bindings::fribidi_get_bidi_type(raw as Char)
-            )
-        };
+        Self::into_chartype(raw as Char)
+    }
+}
 
-        char_type
+/// Writes the UAX#9 short name of the type, see `CharType::short_name`.
+impl fmt::Display for CharType
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str(self.short_name())
     }
 }
 
-impl From<char> for CharType
+/// Parse a UAX#9 short name, as written in BidiTest.txt and
+/// BidiCharacterTest.txt.  The fribidi spellings LTR, RTL, BS and SS are
+/// accepted as well.
+impl FromStr for CharType
 {
-    fn from(raw: char) -> Self
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
     {
-        (raw as Char).into()
+        let s = match s
+        {
+            "LTR" => "L",
+            "RTL" => "R",
+            "BS" => "B",
+            "SS" => "S",
+            _ => s
+        };
+
+        CharType::ALL
+            .iter()
+            .find(|char_type| char_type.short_name() == s)
+            .copied()
+            .ok_or_else(|| Error::InvalidCharTypeName(s.to_owned()))
     }
 }
 
 impl CharType
 {
+    const ALL: [CharType; 23] = [
+        CharType::LeftToRight,
+        CharType::RightToLeft,
+        CharType::ArabicLetter,
+        CharType::EuropeanNumeral,
+        CharType::ArabicNumeral,
+        CharType::EuropeanNumberSeparator,
+        CharType::EuropeanNumberTerminator,
+        CharType::CommonSeparator,
+        CharType::NonSpacingMark,
+        CharType::BoundaryNeutral,
+        CharType::BlockSeparator,
+        CharType::SegmentSeparator,
+        CharType::WhiteSpace,
+        CharType::OtherNeutral,
+        CharType::LeftToRightEmbedding,
+        CharType::RightToLeftEmbedding,
+        CharType::LeftToRightOverride,
+        CharType::RightToLeftOverride,
+        CharType::PopDirectionalFlag,
+        CharType::LeftToRightIsolate,
+        CharType::RightToLeftIsolate,
+        CharType::FirstStongIsolate,
+        CharType::PopDirectionalIsolate,
+    ];
+
+    /// The raw fribidi bidi type value.
+    pub const fn as_raw(self) -> u32
+    {
+        self as u32
+    }
+
+    /// Convert a raw fribidi bidi type value, as returned by `as_raw`, back
+    /// to a CharType.  Values that are not bidi types give `InvalidCharType`.
+    /// To look up the type of a code point, use `from_code_point`.
+    pub fn from_raw(raw: u32) -> Result<CharType, Error>
+    {
+        match raw
+        {
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_LTR => Ok(CharType::LeftToRight),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_RTL => Ok(CharType::RightToLeft),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_AL => Ok(CharType::ArabicLetter),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_EN => Ok(CharType::EuropeanNumeral),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_AN => Ok(CharType::ArabicNumeral),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_ES => Ok(CharType::EuropeanNumberSeparator),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_ET => Ok(CharType::EuropeanNumberTerminator),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_CS => Ok(CharType::CommonSeparator),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_NSM => Ok(CharType::NonSpacingMark),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_BN => Ok(CharType::BoundaryNeutral),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_BS => Ok(CharType::BlockSeparator),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_SS => Ok(CharType::SegmentSeparator),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_WS => Ok(CharType::WhiteSpace),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_ON => Ok(CharType::OtherNeutral),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_LRE => Ok(CharType::LeftToRightEmbedding),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_RLE => Ok(CharType::RightToLeftEmbedding),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_LRO => Ok(CharType::LeftToRightOverride),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_RLO => Ok(CharType::RightToLeftOverride),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_PDF => Ok(CharType::PopDirectionalFlag),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_LRI => Ok(CharType::LeftToRightIsolate),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_RLI => Ok(CharType::RightToLeftIsolate),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_FSI => Ok(CharType::FirstStongIsolate),
+            fribidi_bindings::FriBidiCharType_FRIBIDI_TYPE_PDI => Ok(CharType::PopDirectionalIsolate),
+            _ => Err(Error::InvalidCharType(raw))
+        }
+    }
+
+    /// The bidi type of the code point ch, what converting a `Char` into a
+    /// CharType used to do.  Same as `into_chartype`.
+    pub fn from_code_point(ch: Char) -> CharType
+    {
+        Self::into_chartype(ch)
+    }
+
     /// same as `fribidi_get_bidi_type` - get character bidi type
     ///
     /// This function returns the bidi type of a character as defined in Table 3.7
@@ -73,221 +170,494 @@ impl CharType
     ///
     pub fn into_chartype(char: Char) -> CharType
     {
-        char.into()
+        let raw_type = unsafe {
+            fribidi_bindings::fribidi_get_bidi_type(char)
+        };
+
+        // fribidi only returns bidi types, anything else is taken as neutral
+        CharType::from_raw(raw_type).unwrap_or(CharType::OtherNeutral)
     }
 
     /// same as `fribidi_get_bidi_types` - get bidi types for an string of characters
     ///
     /// This function finds the bidi types of an string of characters.  See
     /// fribidi_get_bidi_type() for more information about the bidi types returned
-    /// by this function.
+    /// by this function.  UTF-16 text gets one type per code unit, see
+    /// `BidiInput::units_len`.
     ///
-    pub fn into_chartypes(chars: &Vec<Char>) -> Vec<CharType>
+    pub fn into_chartypes<T: BidiInput + ?Sized>(chars: &T) -> Vec<CharType>
     {
         chars
+            .to_u32str()
+            .as_slice()
             .iter()
-            .map(|&ch| (ch as Char).into())
+            .flat_map(|&ch| std::iter::repeat_n(Self::into_chartype(ch), chars.units_len(ch)))
             .collect()
     }
 
+    /// The Rust name of a character type, like "EuropeanNumberSeparator".
+    /// See `short_name` for the Unicode one.
+    pub fn name (char_type: CharType) -> String
+    {
+        format!("{:?}", char_type)
+    }
+
     /// same as `fribidi_get_bidi_type_name` - get bidi type name
     ///
     /// This function returns the bidi type name of a character type.
     ///
     /// The type names are the same as ones defined in Table 3.7 Bidirectional
     /// Character Types of the Unicode Bidirectional Algorithm available at
-    /// http://www.unicode.org/reports/tr9/#Bidirectional_Character_Types, with a
-    /// few modifications: L->LTR, R->RTL, B->BS, S->SS.
+    /// http://www.unicode.org/reports/tr9/#Bidirectional_Character_Types.
+    /// fribidi spells four of them differently, L->LTR, R->RTL, B->BS, S->SS;
+    /// these are turned back to the Unicode names.
     ///
-    pub fn name (char_type: CharType) -> String
+    pub fn short_name(&self) -> &'static str
     {
-        // unsafe {
-        //     let bidi_type_name = fribidi_bindings::fribidi_get_bidi_type_name(char_type as u32);
-        //     std::ffi::CStr::from_ptr(bidi_type_name).to_str().unwrap()
-        // }
+        let name = unsafe {
+            CStr::from_ptr(fribidi_bindings::fribidi_get_bidi_type_name(self.as_raw()))
+        };
 
-        format!("{:?}", char_type)
+        match name.to_str().unwrap_or("?")
+        {
+            "LTR" => "L",
+            "RTL" => "R",
+            "BS" => "B",
+            "SS" => "S",
+            name => name
+        }
     }
 
-    ///
-    /// These are the same macros inside `fribidi-bidi-types.h`
-    ///
+    //
+    // These are the same macros inside `fribidi-bidi-types.h`.  The
+    // `is_*_type` methods test a CharType, and can be used in const contexts.
+    //
+
+    /// Is right to left: RTL, AL, RLE, RLO?
+    pub const fn is_rtl_type(&self) -> bool
+    {
+        CharTypeSet::RTL.contains(*self)
+    }
+
+    /// Is arabic: AL, AN?
+    pub const fn is_arabic_type(&self) -> bool
+    {
+        CharTypeSet::ARABIC.contains(*self)
+    }
+
+    /// Is strong?
+    pub const fn is_strong_type(&self) -> bool
+    {
+        CharTypeSet::STRONG.contains(*self)
+    }
+
+    /// Is weak?
+    pub const fn is_weak_type(&self) -> bool
+    {
+        CharTypeSet::WEAK.contains(*self)
+    }
+
+    /// Is neutral?
+    pub const fn is_neutral_type(&self) -> bool
+    {
+        CharTypeSet::NEUTRAL.contains(*self)
+    }
+
+    /// Is sentinel?
+    pub const fn is_sentinel_type(&self) -> bool
+    {
+        CharTypeSet::SENTINEL.contains(*self)
+    }
+
+    /// Is letter: L, R, AL?
+    pub const fn is_letter_type(&self) -> bool
+    {
+        CharTypeSet::LETTER.contains(*self)
+    }
+
+    /// Is number: EN, AN?
+    pub const fn is_number_type(&self) -> bool
+    {
+        CharTypeSet::NUMBER.contains(*self)
+    }
+
+    /// Is number separator or terminator: ES, ET, CS?
+    pub const fn is_number_separator_or_terminator_type(&self) -> bool
+    {
+        CharTypeSet::NUMSEPTER.contains(*self)
+    }
+
+    /// Is space: BN, BS, SS, WS?
+    pub const fn is_space_type(&self) -> bool
+    {
+        CharTypeSet::SPACE.contains(*self)
+    }
+
+    /// Is explicit mark: LRE, RLE, LRO, RLO, PDF?
+    pub const fn is_explicit_type(&self) -> bool
+    {
+        CharTypeSet::EXPLICIT.contains(*self)
+    }
+
+    /// Is isolate mark: LRI, RLI, FSI, PDI?
+    pub const fn is_isolate_type(&self) -> bool
+    {
+        CharTypeSet::ISOLATE.contains(*self)
+    }
+
+    /// Is text separator: BS, SS?
+    pub const fn is_separator_type(&self) -> bool
+    {
+        CharTypeSet::SEPARATOR.contains(*self)
+    }
+
+    /// Is explicit override: LRO, RLO?
+    pub const fn is_override_type(&self) -> bool
+    {
+        CharTypeSet::OVERRIDE.contains(*self)
+    }
+
+    /// Is ES or CS?
+    pub const fn is_es_or_cs_type(&self) -> bool
+    {
+        CharTypeSet::ES.union(CharTypeSet::CS).contains(*self)
+    }
+
+    /// Is explicit or BN: LRE, RLE, LRO, RLO, PDF, BN?
+    pub const fn is_explicit_or_bn_type(&self) -> bool
+    {
+        CharTypeSet::EXPLICIT.union(CharTypeSet::BN).contains(*self)
+    }
+
+    /// Is explicit or BN or NSM: LRE, RLE, LRO, RLO, PDF, BN, NSM?
+    pub const fn is_explicit_or_bn_or_nsm_type(&self) -> bool
+    {
+        CharTypeSet::EXPLICIT.union(CharTypeSet::BN).union(CharTypeSet::NSM).contains(*self)
+    }
+
+    /// Is explicit or isolate or BN or NSM: LRE, RLE, LRO, RLO, PDF, LRI, RLI, FSI, PDI, BN, NSM?
+    pub const fn is_explicit_or_isolate_or_bn_or_nsm_type(&self) -> bool
+    {
+        CharTypeSet::EXPLICIT.union(CharTypeSet::ISOLATE).union(CharTypeSet::BN).union(CharTypeSet::NSM).contains(*self)
+    }
+
+    /// Is explicit or BN or WS: LRE, RLE, LRO, RLO, PDF, BN, WS?
+    pub const fn is_explicit_or_bn_or_ws_type(&self) -> bool
+    {
+        CharTypeSet::EXPLICIT.union(CharTypeSet::BN).union(CharTypeSet::WS).contains(*self)
+    }
+
+    /// Is explicit or separator or BN or WS: LRE, RLE, LRO, RLO, PDF, BS, SS, BN, WS?
+    pub const fn is_explicit_or_separator_or_bn_or_ws_type(&self) -> bool
+    {
+        CharTypeSet::EXPLICIT.union(CharTypeSet::SEPARATOR).union(CharTypeSet::BN).union(CharTypeSet::WS).contains(*self)
+    }
+
+    /// Is private-use type for application?
+    pub const fn is_private_type(&self) -> bool
+    {
+        CharTypeSet::PRIVATE.contains(*self)
+    }
+
+    /// Is left to right letter: L?
+    pub const fn is_left_to_right_letter_type(&self) -> bool
+    {
+        self.is_letter_type() && !self.is_rtl_type()
+    }
+
+    /// Is right to left letter: R, AL?
+    pub const fn is_right_to_left_letter_type(&self) -> bool
+    {
+        self.is_letter_type() && self.is_rtl_type()
+    }
+
+    /// Change numbers to RTL: EN,AN -> RTL.
+    pub const fn number_to_rtl(&self) -> CharType
+    {
+        match self.is_number_type()
+        {
+            true => CharType::RightToLeft,
+            false => *self
+        }
+    }
+
+    /// Override status of an explicit mark:
+    /// LRO->LTR, RLO->RTL, otherwise->ON.
+    pub const fn override_dir(&self) -> CharType
+    {
+        match self
+        {
+            CharType::LeftToRightOverride => CharType::LeftToRight,
+            CharType::RightToLeftOverride => CharType::RightToLeft,
+            _ => CharType::OtherNeutral
+        }
+    }
+
+    //
+    // The macros on raw bidi type values, as the earlier versions had them.
+    //
 
     /// Is right to left: RTL, AL, RLE, RLO?
+    #[deprecated(note = "use the `CharType::is_rtl_type` method")]
     pub fn is_rtl(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_RTL != 0
+        CharTypeSet::RTL.contains_raw(ch)
     }
 
     /// Is arabic: AL, AN?
+    #[deprecated(note = "use the `CharType::is_arabic_type` method")]
     pub fn is_arabic(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_ARABIC != 0
+        CharTypeSet::ARABIC.contains_raw(ch)
     }
 
+    /// Is strong?
+    #[deprecated(note = "use the `CharType::is_strong_type` method")]
     pub fn is_strong(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_STRONG != 0
+        CharTypeSet::STRONG.contains_raw(ch)
     }
 
+    /// Is weak?
+    #[deprecated(note = "use the `CharType::is_weak_type` method")]
     pub fn is_weak(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_WEAK != 0
+        CharTypeSet::WEAK.contains_raw(ch)
     }
 
+    /// Is neutral?
+    #[deprecated(note = "use the `CharType::is_neutral_type` method")]
     pub fn is_netural(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_NEUTRAL != 0
+        CharTypeSet::NEUTRAL.contains_raw(ch)
     }
 
+    /// Is sentinel?
+    #[deprecated(note = "use the `CharType::is_sentinel_type` method")]
     pub fn is_sentinel(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_SENTINEL != 0
+        CharTypeSet::SENTINEL.contains_raw(ch)
     }
 
     /// Is letter: L, R, AL?
+    #[deprecated(note = "use the `CharType::is_letter_type` method")]
     pub fn is_letter(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_LETTER != 0
+        CharTypeSet::LETTER.contains_raw(ch)
     }
 
     /// Is number: EN, AN?
+    #[deprecated(note = "use the `CharType::is_number_type` method")]
     pub fn is_number(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_NUMBER != 0
+        CharTypeSet::NUMBER.contains_raw(ch)
     }
 
     /// Is number separator or terminator: ES, ET, CS?
+    #[deprecated(note = "use the `CharType::is_number_separator_or_terminator_type` method")]
     pub fn is_number_separator_or_terminator(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_NUMSEPTER != 0
+        CharTypeSet::NUMSEPTER.contains_raw(ch)
     }
 
     /// Is space: BN, BS, SS, WS?
+    #[deprecated(note = "use the `CharType::is_space_type` method")]
     pub fn is_space(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_SPACE != 0
+        CharTypeSet::SPACE.contains_raw(ch)
     }
 
     /// Is explicit mark: LRE, RLE, LRO, RLO, PDF?
+    #[deprecated(note = "use the `CharType::is_explicit_type` method")]
     pub fn is_explicit(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_EXPLICIT != 0
+        CharTypeSet::EXPLICIT.contains_raw(ch)
     }
 
+    /// Is isolate mark: LRI, RLI, FSI, PDI?
+    #[deprecated(note = "use the `CharType::is_isolate_type` method")]
     pub fn is_isolate(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_ISOLATE != 0
+        CharTypeSet::ISOLATE.contains_raw(ch)
     }
 
-    /// Is text separator: BS, SS? 
+    /// Is text separator: BS, SS?
+    #[deprecated(note = "use the `CharType::is_separator_type` method")]
     pub fn is_separator(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_SEPARATOR != 0
+        CharTypeSet::SEPARATOR.contains_raw(ch)
     }
 
     /// Is explicit override: LRO, RLO?
+    #[deprecated(note = "use the `CharType::is_override_type` method")]
     pub fn is_override(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_OVERRIDE != 0
-    }
-
-    pub fn is_left_to_right_letter(ch: Char) -> bool
-    {
-        ch & (fribidi_bindings::FRIBIDI_MASK_LETTER | fribidi_bindings::FRIBIDI_MASK_RTL)
-            == fribidi_bindings::FRIBIDI_MASK_LETTER
-    }
-
-    pub fn is_right_to_left_letter(ch: Char) -> bool
-    {
-        ch & (fribidi_bindings::FRIBIDI_MASK_LETTER | fribidi_bindings::FRIBIDI_MASK_RTL)
-	        == (fribidi_bindings::FRIBIDI_MASK_LETTER | fribidi_bindings::FRIBIDI_MASK_RTL)
+        CharTypeSet::OVERRIDE.contains_raw(ch)
     }
 
+    /// Is ES or CS?
+    #[deprecated(note = "use the `CharType::is_es_or_cs_type` method")]
     pub fn is_es_or_cs(ch: Char) -> bool
     {
-        ch & (fribidi_bindings::FRIBIDI_MASK_ES | fribidi_bindings::FRIBIDI_MASK_CS) != 0
+        CharTypeSet::ES.union(CharTypeSet::CS).contains_raw(ch)
     }
 
     /// Is explicit or BN: LRE, RLE, LRO, RLO, PDF, BN?
+    #[deprecated(note = "use the `CharType::is_explicit_or_bn_type` method")]
     pub fn is_explicit_or_bn(ch: Char) -> bool
     {
-        ch & (fribidi_bindings::FRIBIDI_MASK_EXPLICIT | fribidi_bindings::FRIBIDI_MASK_BN) != 0
+        CharTypeSet::EXPLICIT.union(CharTypeSet::BN).contains_raw(ch)
     }
 
     /// Is explicit or BN or NSM: LRE, RLE, LRO, RLO, PDF, BN, NSM?
+    #[deprecated(note = "use the `CharType::is_explicit_or_bn_or_nsm_type` method")]
     pub fn is_explicit_or_bn_or_nsm(ch: Char) -> bool
     {
-        ch & (fribidi_bindings::FRIBIDI_MASK_EXPLICIT | fribidi_bindings::FRIBIDI_MASK_BN | fribidi_bindings::FRIBIDI_MASK_NSM) != 0
+        CharTypeSet::EXPLICIT.union(CharTypeSet::BN).union(CharTypeSet::NSM).contains_raw(ch)
     }
 
-    /// Is explicit or BN or NSM: LRE, RLE, LRO, RLO, PDF, BN, NSM?
+    /// Is explicit or isolate or BN or NSM: LRE, RLE, LRO, RLO, PDF, LRI, RLI, FSI, PDI, BN, NSM?
+    #[deprecated(note = "use the `CharType::is_explicit_or_isolate_or_bn_or_nsm_type` method")]
     pub fn is_explicit_or_isolate_or_bn_or_nsm(ch: Char) -> bool
     {
-        ch & (fribidi_bindings::FRIBIDI_MASK_EXPLICIT
-            | fribidi_bindings::FRIBIDI_MASK_ISOLATE
-            | fribidi_bindings::FRIBIDI_MASK_BN
-            | fribidi_bindings::FRIBIDI_MASK_NSM) != 0
+        CharTypeSet::EXPLICIT.union(CharTypeSet::ISOLATE).union(CharTypeSet::BN).union(CharTypeSet::NSM).contains_raw(ch)
     }
 
     /// Is explicit or BN or WS: LRE, RLE, LRO, RLO, PDF, BN, WS?
+    #[deprecated(note = "use the `CharType::is_explicit_or_bn_or_ws_type` method")]
     pub fn is_explicit_or_bn_or_ws(ch: Char) -> bool
     {
-        ch & (fribidi_bindings::FRIBIDI_MASK_EXPLICIT
-            | fribidi_bindings::FRIBIDI_MASK_BN
-            | fribidi_bindings::FRIBIDI_MASK_WS) != 0
+        CharTypeSet::EXPLICIT.union(CharTypeSet::BN).union(CharTypeSet::WS).contains_raw(ch)
     }
 
     /// Is explicit or separator or BN or WS: LRE, RLE, LRO, RLO, PDF, BS, SS, BN, WS?
+    #[deprecated(note = "use the `CharType::is_explicit_or_separator_or_bn_or_ws_type` method")]
     pub fn is_explicit_or_separator_or_bn_or_ws(ch: Char) -> bool
     {
-        ch & (fribidi_bindings::FRIBIDI_MASK_EXPLICIT
-            | fribidi_bindings::FRIBIDI_MASK_SEPARATOR
-            | fribidi_bindings::FRIBIDI_MASK_BN
-            | fribidi_bindings::FRIBIDI_MASK_WS) != 0
+        CharTypeSet::EXPLICIT.union(CharTypeSet::SEPARATOR).union(CharTypeSet::BN).union(CharTypeSet::WS).contains_raw(ch)
     }
 
     /// Is private-use type for application?
+    #[deprecated(note = "use the `CharType::is_private_type` method")]
     pub fn is_private(ch: Char) -> bool
     {
-        ch & fribidi_bindings::FRIBIDI_MASK_PRIVATE != 0
+        CharTypeSet::PRIVATE.contains_raw(ch)
     }
 
-    /// Change numbers to RTL: EN,AN -> RTL.
+    /// Is left to right letter: L?
+    #[deprecated(note = "use the `CharType::is_left_to_right_letter_type` method")]
+    pub fn is_left_to_right_letter(ch: Char) -> bool
+    {
+        ch & (CharTypeSet::LETTER.0 | CharTypeSet::RTL.0) == CharTypeSet::LETTER.0
+    }
+
+    /// Is right to left letter: R, AL?
+    #[deprecated(note = "use the `CharType::is_right_to_left_letter_type` method")]
+    pub fn is_right_to_left_letter(ch: Char) -> bool
+    {
+        ch & (CharTypeSet::LETTER.0 | CharTypeSet::RTL.0) == CharTypeSet::LETTER.0 | CharTypeSet::RTL.0
+    }
+
+    /// Change numbers to RTL: EN,AN -> RTL.  Values that are not bidi types
+    /// give ON.
+    #[deprecated(note = "use the `CharType::number_to_rtl` method")]
     pub fn into_right_to_left(ch: Char) -> CharType
     {
-        match Self::is_number(ch) {
-            true => CharType::RightToLeft,
-            false => ch.into()
-        }
+        CharType::from_raw(ch).map_or(CharType::OtherNeutral, |char_type| char_type.number_to_rtl())
     }
 
     /// Override status of an explicit mark:
-    /// LRO,LRE->LTR, RLO,RLE->RTL, otherwise->ON.
+    /// LRO->LTR, RLO->RTL, otherwise->ON.
+    #[deprecated(note = "use the `CharType::override_dir` method")]
     pub fn explicit_to_override_dir(ch: Char) -> CharType
     {
-        match Self::is_override(ch) {
-            true => LevelType::to_chartype(&LevelType::from_char(ch)),
-		    false => CharType::OtherNeutral
-        }
+        CharType::from_raw(ch).map_or(CharType::OtherNeutral, |char_type| char_type.override_dir())
+    }
+}
+
+/// A set of bidi type categories, as the `FRIBIDI_MASK_*` bits fribidi builds
+/// its bidi types from.  A type is in the set when it belongs to any of the
+/// categories, so `CharTypeSet::EXPLICIT | CharTypeSet::BN` holds LRE, RLE,
+/// LRO, RLO, PDF and BN.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CharTypeSet(pub u32);
+
+impl CharTypeSet
+{
+    pub const EMPTY: CharTypeSet = CharTypeSet(0);
+    pub const RTL: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_RTL);
+    pub const ARABIC: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_ARABIC);
+    pub const STRONG: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_STRONG);
+    pub const WEAK: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_WEAK);
+    pub const NEUTRAL: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_NEUTRAL);
+    pub const SENTINEL: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_SENTINEL);
+    pub const LETTER: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_LETTER);
+    pub const NUMBER: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_NUMBER);
+    pub const NUMSEPTER: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_NUMSEPTER);
+    pub const SPACE: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_SPACE);
+    pub const EXPLICIT: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_EXPLICIT);
+    pub const ISOLATE: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_ISOLATE);
+    pub const SEPARATOR: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_SEPARATOR);
+    pub const OVERRIDE: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_OVERRIDE);
+    pub const FIRST: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_FIRST);
+    pub const ES: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_ES);
+    pub const ET: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_ET);
+    pub const CS: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_CS);
+    pub const NSM: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_NSM);
+    pub const BN: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_BN);
+    pub const BS: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_BS);
+    pub const SS: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_SS);
+    pub const WS: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_WS);
+    pub const PRIVATE: CharTypeSet = CharTypeSet(fribidi_bindings::FRIBIDI_MASK_PRIVATE);
+
+    /// The categories of both sets, the same as `|` in const contexts.
+    pub const fn union(self, other: CharTypeSet) -> CharTypeSet
+    {
+        CharTypeSet(self.0 | other.0)
+    }
+
+    /// Does char_type belong to any category of the set?
+    pub const fn contains(self, char_type: CharType) -> bool
+    {
+        self.contains_raw(char_type.as_raw())
+    }
+
+    /// Does the raw fribidi bidi type value belong to any category of the set?
+    pub const fn contains_raw(self, raw: Char) -> bool
+    {
+        raw & self.0 != 0
+    }
+}
+
+impl BitOr for CharTypeSet
+{
+    type Output = CharTypeSet;
+
+    fn bitor(self, rhs: CharTypeSet) -> CharTypeSet
+    {
+        self.union(rhs)
     }
 }
 
 #[cfg(test)]
 mod test
 {
-    use widestring::U32String;
-    use crate::char::CharType;
+    use widestring::{U16String, U32String};
+    use crate::char::{CharType, CharTypeSet};
+    use crate::fribidi_bindings;
 
     #[test]
     fn test_type ()
     {
         let ch = 'غ';
-        let ch_type: Result<CharType, _> = ch.try_into();
-        // assert!(ch_type.is_ok());
+        let ch_type: CharType = ch.into();
         let gt = CharType::ArabicLetter;
         
-        assert_eq!(ch_type.unwrap(), gt);
+        assert_eq!(ch_type, gt);
+        assert_eq!(CharType::from_code_point(0x63a), gt);
+        assert_eq!(CharType::from_raw(CharType::PopDirectionalIsolate.as_raw()), Ok(CharType::PopDirectionalIsolate));
+        assert_eq!(CharType::from_raw(0x12345), Err(crate::error::Error::InvalidCharType(0x12345)));
     }
 
     #[test]
@@ -307,6 +677,19 @@ mod test
         ];
         
         assert_eq!(types, gt);
+
+        let text = U16String::from_str("غ!A西Б1٤");
+        assert_eq!(CharType::into_chartypes(&text), gt);
+
+        // both code units of a surrogate pair get the type of the character
+        let text = U16String::from_str("a😀א");
+        let gt = vec![
+            CharType::LeftToRight,
+            CharType::OtherNeutral,
+            CharType::OtherNeutral,
+            CharType::RightToLeft
+        ];
+        assert_eq!(CharType::into_chartypes(&text), gt);
     }
 
     #[test]
@@ -317,5 +700,67 @@ mod test
 
         let char_type_name = CharType::name(char_type);
         assert_eq!(char_type_name, gt);
+
+        assert_eq!(char_type.short_name(), "ES");
+        assert_eq!(CharType::LeftToRight.to_string(), "L");
+        assert_eq!(CharType::BlockSeparator.to_string(), "B");
+        assert_eq!(CharType::FirstStongIsolate.to_string(), "FSI");
+    }
+
+    #[test]
+    fn test_parse_bidi_type_name ()
+    {
+        assert_eq!("AL".parse::<CharType>(), Ok(CharType::ArabicLetter));
+        assert_eq!("S".parse::<CharType>(), Ok(CharType::SegmentSeparator));
+        assert_eq!("RTL".parse::<CharType>(), Ok(CharType::RightToLeft));
+        assert_eq!("XX".parse::<CharType>(), Err(crate::error::Error::InvalidCharTypeName("XX".to_owned())));
+
+        for char_type in CharType::ALL
+        {
+            assert_eq!(char_type.to_string().parse::<CharType>(), Ok(char_type));
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_predicates ()
+    {
+        const RTL: [bool; 2] = [CharType::ArabicLetter.is_rtl_type(), CharType::LeftToRight.is_rtl_type()];
+        assert_eq!(RTL, [true, false]);
+        const OVERRIDE: CharType = CharType::RightToLeftOverride.override_dir();
+        assert_eq!(OVERRIDE, CharType::RightToLeft);
+
+        assert!(CharType::OtherNeutral.is_neutral_type());
+        assert!(CharType::RightToLeftOverride.is_explicit_type());
+        assert!(CharType::FirstStongIsolate.is_isolate_type());
+        assert!(CharType::LeftToRight.is_left_to_right_letter_type());
+        assert!(!CharType::ArabicLetter.is_left_to_right_letter_type());
+        assert!(CharType::ArabicLetter.is_right_to_left_letter_type());
+        assert!(CharType::NonSpacingMark.is_explicit_or_bn_or_nsm_type());
+        assert!(!CharType::WhiteSpace.is_explicit_or_bn_or_nsm_type());
+        assert_eq!(CharType::ArabicNumeral.number_to_rtl(), CharType::RightToLeft);
+        assert_eq!(CharType::WhiteSpace.number_to_rtl(), CharType::WhiteSpace);
+        assert_eq!(CharType::LeftToRightOverride.override_dir(), CharType::LeftToRight);
+        assert_eq!(CharType::LeftToRightEmbedding.override_dir(), CharType::OtherNeutral);
+
+        let set = CharTypeSet::NUMBER | CharTypeSet::WS;
+        assert!(set.contains(CharType::EuropeanNumeral));
+        assert!(set.contains(CharType::WhiteSpace));
+        assert!(!set.contains(CharType::LeftToRight));
+        assert!(!CharTypeSet::EMPTY.contains(CharType::LeftToRight));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_raw_predicates ()
+    {
+        assert!(CharType::is_rtl(CharType::ArabicLetter.as_raw()));
+        assert!(CharType::is_netural(CharType::OtherNeutral.as_raw()));
+        assert_eq!(CharType::into_right_to_left(CharType::EuropeanNumeral.as_raw()), CharType::RightToLeft);
+        assert_eq!(CharType::into_right_to_left(CharType::WhiteSpace.as_raw()), CharType::WhiteSpace);
+        assert_eq!(CharType::explicit_to_override_dir(CharType::RightToLeftOverride.as_raw()), CharType::RightToLeft);
+        assert_eq!(CharType::explicit_to_override_dir(0x12345), CharType::OtherNeutral);
+        assert!(CharType::is_right_to_left_letter(CharType::RightToLeft.as_raw()));
+        assert!(!CharType::is_left_to_right_letter(CharType::RightToLeft.as_raw()));
+        assert!(CharType::is_explicit_or_bn(fribidi_bindings::FRIBIDI_MASK_BN));
+    }
+}
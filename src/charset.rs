@@ -0,0 +1,289 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use crate::fribidi_bindings;
+
+use widestring::{U32Str, U32String};
+
+use crate::error::Error;
+
+/// The bytes that follow `_` in the CapRTL escapes of explicit marks, and
+/// `__` for the underscore itself.
+const CAP_RTL_ESCAPES: &[u8] = b"><lrLRoiyfI_";
+
+/// The character sets fribidi can convert from and to Unicode.
+#[repr(u32)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CharSet
+{
+    Utf8      = fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8,
+    CapRtl    = fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL,
+    Iso8859_6 = fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_6,
+    Iso8859_8 = fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_8,
+    Cp1255    = fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_CP1255,
+    Cp1256    = fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256,
+}
+
+/// same as `fribidi_parse_charset` - parse character set name
+///
+/// The name is matched case-insensitively against the names fribidi knows,
+/// like "UTF-8", "CapRTL", "ISO8859-6" or "CP1255".
+impl FromStr for CharSet
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let name = CString::new(s).map_err(|_| Error::UnsupportedCharSet(s.to_owned()))?;
+        let char_set = unsafe {
+            fribidi_bindings::fribidi_parse_charset(name.as_ptr())
+        };
+
+        match char_set
+        {
+            fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8 => Ok(CharSet::Utf8),
+            fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL => Ok(CharSet::CapRtl),
+            fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_6 => Ok(CharSet::Iso8859_6),
+            fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_8 => Ok(CharSet::Iso8859_8),
+            fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_CP1255 => Ok(CharSet::Cp1255),
+            fribidi_bindings::FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256 => Ok(CharSet::Cp1256),
+            _ => Err(Error::UnsupportedCharSet(s.to_owned()))
+        }
+    }
+}
+
+fn c_str_to_string(raw: *const c_char) -> Option<String>
+{
+    match raw.is_null()
+    {
+        true => None,
+        false => Some(unsafe { CStr::from_ptr(raw) }.to_string_lossy().into_owned())
+    }
+}
+
+impl CharSet
+{
+    /// same as `fribidi_char_set_name` - get character set name
+    pub fn name(&self) -> String
+    {
+        let raw_name = unsafe {
+            fribidi_bindings::fribidi_char_set_name(*self as u32)
+        };
+
+        c_str_to_string(raw_name).unwrap_or_default()
+    }
+
+    /// same as `fribidi_char_set_title` - get character set title
+    pub fn title(&self) -> String
+    {
+        let raw_title = unsafe {
+            fribidi_bindings::fribidi_char_set_title(*self as u32)
+        };
+
+        c_str_to_string(raw_title).unwrap_or_default()
+    }
+
+    /// same as `fribidi_char_set_desc` - get character set description
+    ///
+    /// Returns: the description, or None if the character set has none.
+    ///
+    pub fn description(&self) -> Option<String>
+    {
+        let raw_desc = unsafe {
+            fribidi_bindings::fribidi_char_set_desc(*self as u32)
+        };
+
+        c_str_to_string(raw_desc)
+    }
+
+    /// Largest number of bytes a single character can be encoded to.
+    fn max_bytes_per_char(&self) -> usize
+    {
+        match self
+        {
+            CharSet::Utf8 => 4,
+            // explicit marks are written as an underscore escape
+            CharSet::CapRtl => 2,
+            _ => 1
+        }
+    }
+
+    /// Byte offset of the first truncated or invalid sequence of input, which
+    /// fribidi would read past or decode to a made-up character.
+    fn find_invalid(&self, input: &[u8]) -> Option<usize>
+    {
+        match self
+        {
+            CharSet::Utf8 => std::str::from_utf8(input).err().map(|error| error.valid_up_to()),
+            CharSet::CapRtl =>
+            {
+                let mut i = 0;
+                while i < input.len()
+                {
+                    match (input[i], input.get(i + 1))
+                    {
+                        (ch, _) if !ch.is_ascii() => return Some(i),
+                        // a trailing underscore is an escape cut by the end
+                        (b'_', None) => return Some(i),
+                        (b'_', Some(escape)) if CAP_RTL_ESCAPES.contains(escape) => i += 2,
+                        _ => i += 1
+                    }
+                }
+                None
+            },
+            _ => None
+        }
+    }
+
+    /// same as `fribidi_charset_to_unicode` - convert string to Unicode
+    ///
+    /// This function converts a string from a character set, to a Unicode
+    /// string.
+    ///
+    /// Returns: `InvalidEncoding` if the input holds a sequence that is not
+    /// valid in the character set or is cut by the end of the input, such as
+    /// malformed UTF-8, a non-ASCII byte in CapRTL or a trailing CapRTL `_`.
+    ///
+    pub fn decode(&self, input: &[u8]) -> Result<U32String, Error>
+    {
+        let len = Error::ffi_len(input.len())?;
+        if let Some(position) = self.find_invalid(input)
+        {
+            return Err(Error::InvalidEncoding(position));
+        }
+        // no character set produces more characters than its input bytes
+        let mut unicode_str: Vec<u32> = vec![0; input.len() + 1];
+
+        let unicode_len = unsafe {
+            fribidi_bindings::fribidi_charset_to_unicode(
+                *self as u32,
+                input.as_ptr() as *const c_char,
+                len,
+                unicode_str.as_mut_ptr()
+            )
+        };
+
+        if unicode_len < 0
+        {
+            return Err(Error::AllocationFailed);
+        }
+
+        unicode_str.truncate(unicode_len as usize);
+        Ok(U32String::from_vec(unicode_str))
+    }
+
+    /// same as `fribidi_unicode_to_charset` - convert string from Unicode
+    ///
+    /// This function converts a Unicode string to a string in another
+    /// character set.  Characters that cannot be represented in the character
+    /// set are replaced by fribidi, usually with '?'.
+    ///
+    pub fn encode(&self, input_str: &U32Str) -> Result<Vec<u8>, Error>
+    {
+        let len = Error::ffi_len(input_str.len())?;
+        // room for the NUL terminator fribidi appends
+        let mut encoded: Vec<u8> = vec![0; input_str.len() * self.max_bytes_per_char() + 1];
+
+        let encoded_len = unsafe {
+            fribidi_bindings::fribidi_unicode_to_charset(
+                *self as u32,
+                input_str.as_ptr(),
+                len,
+                encoded.as_mut_ptr() as *mut c_char
+            )
+        };
+
+        if encoded_len < 0
+        {
+            return Err(Error::AllocationFailed);
+        }
+
+        encoded.truncate(encoded_len as usize);
+        Ok(encoded)
+    }
+}
+
+/// Convert a string written in the CapRTL test notation to Unicode.
+///
+/// CapRTL is the notation of fribidi's own test suite: lowercase letters are
+/// left-to-right, uppercase letters are right-to-left, digits 6-9 are Arabic
+/// numerals and explicit marks are written as `_` escapes (`_>` is LRM, `_l`
+/// is LRE, `_o` is PDF, ...).  `CharSet::CapRtl.description()` lists the whole
+/// notation.
+///
+pub fn from_caprtl(input: &str) -> Result<U32String, Error>
+{
+    CharSet::CapRtl.decode(input.as_bytes())
+}
+
+/// Convert a Unicode string back to the CapRTL test notation.  See
+/// `from_caprtl`.
+pub fn to_caprtl(input_str: &U32Str) -> Result<String, Error>
+{
+    Ok(String::from_utf8_lossy(&CharSet::CapRtl.encode(input_str)?).into_owned())
+}
+
+#[cfg(test)]
+mod test
+{
+    use widestring::U32String;
+
+    use crate::charset::{CharSet, from_caprtl, to_caprtl};
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse_charset()
+    {
+        assert_eq!("cp1255".parse::<CharSet>(), Ok(CharSet::Cp1255));
+        assert_eq!("UTF-8".parse::<CharSet>(), Ok(CharSet::Utf8));
+        assert_eq!("EBCDIC".parse::<CharSet>(), Err(Error::UnsupportedCharSet("EBCDIC".to_owned())));
+
+        assert_eq!(CharSet::Cp1256.name(), "CP1256");
+        assert!(!CharSet::Cp1256.title().is_empty());
+        assert!(CharSet::CapRtl.description().is_some());
+    }
+
+    #[test]
+    fn test_decode_encode()
+    {
+        let hebrew = [0xf9, 0xec, 0xe5, 0xed];
+        let gt = U32String::from("שלום");
+
+        assert_eq!(CharSet::Cp1255.decode(&hebrew), Ok(gt.clone()));
+        assert_eq!(CharSet::Cp1255.encode(&gt), Ok(hebrew.to_vec()));
+
+        let text = U32String::from("سلام 1");
+        let utf8 = CharSet::Utf8.encode(&text).unwrap();
+        assert_eq!(utf8, "سلام 1".as_bytes());
+        assert_eq!(CharSet::Utf8.decode(&utf8), Ok(text));
+    }
+
+    #[test]
+    fn test_decode_invalid()
+    {
+        // U+0800 cut after its lead byte, the rest of the slice is not read
+        let utf8 = [0xe0, 0xa0, 0x80];
+        assert_eq!(CharSet::Utf8.decode(&utf8[..1]), Err(Error::InvalidEncoding(0)));
+        assert_eq!(CharSet::Utf8.decode(&[0x61, 0xf0, 0x9f]), Err(Error::InvalidEncoding(1)));
+        assert_eq!(CharSet::Utf8.decode(&[0x61, 0xff, 0x62]), Err(Error::InvalidEncoding(1)));
+
+        // a trailing underscore is the LRO escape "_L" cut short
+        assert_eq!(from_caprtl(&"ab_L"[..3]), Err(Error::InvalidEncoding(2)));
+        assert_eq!(from_caprtl("ab__"), Ok(U32String::from("ab_")));
+        assert_eq!(from_caprtl("a_b"), Ok(U32String::from("a_b")));
+        assert_eq!(from_caprtl("aé"), Err(Error::InvalidEncoding(1)));
+    }
+
+    #[test]
+    fn test_caprtl()
+    {
+        let text = from_caprtl("car _lis_o THE CAR 67").unwrap();
+        let char_types = crate::CharType::into_chartypes(text.as_vec());
+
+        assert_eq!(char_types[4], crate::CharType::LeftToRightEmbedding);
+        assert_eq!(char_types[9], crate::CharType::RightToLeft);
+        assert_eq!(char_types[17], crate::CharType::ArabicNumeral);
+        assert_eq!(to_caprtl(&text).unwrap(), "car _lis_o THE CAR 67");
+    }
+}
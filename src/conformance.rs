@@ -0,0 +1,308 @@
+//! Conformance with the reference tests of the Unicode Bidirectional
+//! Algorithm, BidiTest.txt and BidiCharacterTest.txt.
+//!
+//! The files are read from tests/data/unicode-<version>/, for the Unicode
+//! version of the linked library, where `tools/fetch_bidi_tests.py` puts
+//! them.  Every case goes through `get_paragraph_embedding_levels_ex` and
+//! `reorder_line`, and the failures are reported with their input, expected
+//! and actual levels and order.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use widestring::U32String;
+
+use crate::bracket::BracketType;
+use crate::char::CharType;
+use crate::flag::FriBidiFlags;
+use crate::paragraph::ParagraphType;
+use crate::version::UnicodeVersion;
+use crate::Fribidi;
+
+/// One paragraph of a test file, with one paragraph direction.
+#[derive(Debug, Clone)]
+struct Case
+{
+    /// Line of the test file the case comes from.
+    line: usize,
+    /// The characters of BidiCharacterTest.txt, BidiTest.txt only gives
+    /// their types.
+    chars: Option<Vec<u32>>,
+    types: Vec<CharType>,
+    direction: ParagraphType,
+    /// The resolved paragraph level, which BidiTest.txt does not give.
+    paragraph_level: Option<i8>,
+    /// The resolved levels, None for the characters removed by rule X9.
+    levels: Vec<Option<i8>>,
+    /// The logical indices in visual order, without the removed characters.
+    order: Vec<usize>,
+}
+
+/// A case and what the library made of it.
+#[derive(Debug, Clone)]
+struct Failure
+{
+    case: Case,
+    paragraph_level: i8,
+    levels: Vec<i8>,
+    order: Vec<usize>,
+}
+
+impl fmt::Display for Failure
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let types: Vec<String> = self.case.types.iter().map(ToString::to_string).collect();
+        writeln!(f, "line {}: {} ({:?} paragraph)", self.case.line, types.join(" "), self.case.direction)?;
+
+        if let Some(chars) = &self.case.chars
+        {
+            let chars: Vec<String> = chars.iter().map(|ch| format!("{:04X}", ch)).collect();
+            writeln!(f, "  chars:    {}", chars.join(" "))?;
+        }
+
+        let expected_levels: Vec<String> = self
+            .case
+            .levels
+            .iter()
+            .map(|level| level.map_or("x".to_owned(), |level| level.to_string()))
+            .collect();
+        let paragraph_level = self.case.paragraph_level.map_or("-".to_owned(), |level| level.to_string());
+        writeln!(
+            f,
+            "  expected: paragraph level {}, levels {}, order {}",
+            paragraph_level,
+            expected_levels.join(" "),
+            join(&self.case.order)
+        )?;
+        write!(
+            f,
+            "  actual:   paragraph level {}, levels {}, order {}",
+            self.paragraph_level,
+            join(&self.levels),
+            join(&self.order)
+        )
+    }
+}
+
+fn join<T: ToString>(values: &[T]) -> String
+{
+    values.iter().map(ToString::to_string).collect::<Vec<String>>().join(" ")
+}
+
+fn parse_levels(field: &str) -> Vec<Option<i8>>
+{
+    field.split_whitespace().map(|level| level.parse().ok()).collect()
+}
+
+fn parse_order(field: &str) -> Vec<usize>
+{
+    field.split_whitespace().map(|index| index.parse().unwrap()).collect()
+}
+
+/// The cases of BidiTest.txt, one per paragraph direction of the bitset of
+/// each line: 1 for auto, 2 for LTR and 4 for RTL.
+fn parse_bidi_test(text: &str) -> Vec<Case>
+{
+    let mut levels = Vec::new();
+    let mut order = Vec::new();
+    let mut cases = Vec::new();
+
+    for (index, line) in text.lines().enumerate()
+    {
+        let line = line.split('#').next().unwrap_or_default().trim();
+
+        if let Some(field) = line.strip_prefix("@Levels:")
+        {
+            levels = parse_levels(field);
+        }
+        else if let Some(field) = line.strip_prefix("@Reorder:")
+        {
+            order = parse_order(field);
+        }
+        else if let Some((types, directions)) = line.split_once(';')
+        {
+            let types: Vec<CharType> = types.split_whitespace().map(|name| name.parse().unwrap()).collect();
+            let directions: u32 = directions.trim().parse().unwrap();
+
+            for (bit, direction) in [
+                (1, ParagraphType::OtherNeutral),
+                (2, ParagraphType::LeftToRight),
+                (4, ParagraphType::RightToLeft),
+            ]
+            {
+                if directions & bit != 0
+                {
+                    cases.push(Case {
+                        line: index + 1,
+                        chars: None,
+                        types: types.clone(),
+                        direction,
+                        paragraph_level: None,
+                        levels: levels.clone(),
+                        order: order.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    cases
+}
+
+/// The cases of BidiCharacterTest.txt, whose fields are the code points, the
+/// paragraph direction (0 for LTR, 1 for RTL and 2 for auto), the resolved
+/// paragraph level, the resolved levels and the visual order.
+fn parse_bidi_character_test(text: &str) -> Vec<Case>
+{
+    text.lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty()
+            {
+                return None;
+            }
+
+            let fields: Vec<&str> = line.split(';').collect();
+            let chars: Vec<u32> = fields[0]
+                .split_whitespace()
+                .map(|ch| u32::from_str_radix(ch, 16).unwrap())
+                .collect();
+            let direction = match fields[1]
+            {
+                "0" => ParagraphType::LeftToRight,
+                "1" => ParagraphType::RightToLeft,
+                _ => ParagraphType::OtherNeutral
+            };
+
+            Some(Case {
+                line: index + 1,
+                types: CharType::into_chartypes(&chars),
+                chars: Some(chars),
+                direction,
+                paragraph_level: Some(fields[2].parse().unwrap()),
+                levels: parse_levels(fields[3]),
+                order: parse_order(fields[4]),
+            })
+        })
+        .collect()
+}
+
+/// Run a case the way fribidi's own conformance tests do, without NSM
+/// reordering nor mirroring, which the reference leaves to the renderer.
+/// Returns the failure, if any.
+fn run(case: &Case) -> Option<Failure>
+{
+    let bracket_types = case.chars.as_ref().map(|chars| BracketType::parse(chars, &case.types).unwrap());
+    let (mut levels, _, direction) =
+        Fribidi::get_paragraph_embedding_levels_ex(&case.types, bracket_types.as_deref(), case.direction).unwrap();
+
+    let mut visual_str = U32String::from_vec(case.chars.clone().unwrap_or_else(|| vec![0; case.types.len()]));
+    let (_, map) =
+        Fribidi::reorder_line(FriBidiFlags::EMPTY, &case.types, direction, &mut levels, &mut visual_str).unwrap();
+
+    let paragraph_level = match direction
+    {
+        ParagraphType::RightToLeft => 1,
+        _ => 0
+    };
+    let levels: Vec<i8> = levels.iter().map(|level| level.0).collect();
+    let order: Vec<usize> = map
+        .into_iter()
+        .map(|index| index as usize)
+        .filter(|&index| case.levels[index].is_some())
+        .collect();
+
+    let levels_match = case
+        .levels
+        .iter()
+        .zip(&levels)
+        .all(|(expected, &actual)| expected.is_none_or(|expected| expected == actual));
+
+    match levels_match && order == case.order && case.paragraph_level.is_none_or(|level| level == paragraph_level)
+    {
+        true => None,
+        false => Some(Failure { case: case.clone(), paragraph_level, levels, order })
+    }
+}
+
+/// Run the cases, panic with a report of the first failures if any.
+fn check(cases: &[Case])
+{
+    let failures: Vec<Failure> = cases.iter().filter_map(run).collect();
+    let report: Vec<String> = failures.iter().take(20).map(ToString::to_string).collect();
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases fail, the first ones:\n{}",
+        failures.len(),
+        cases.len(),
+        report.join("\n")
+    );
+}
+
+/// The test file of the Unicode version the backend is built for, which has
+/// to be in the tree.
+fn read_test_file(name: &str) -> String
+{
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("data")
+        .join(format!("unicode-{}", UnicodeVersion::COMPILED))
+        .join(name);
+
+    fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!("cannot read {}: {}, see tools/fetch_bidi_tests.py", path.display(), error)
+    })
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::conformance::{check, parse_bidi_character_test, parse_bidi_test, read_test_file, run};
+
+    #[test]
+    fn test_harness()
+    {
+        let bidi_test = "# a comment\n\
+                         @Levels:\tx 0\n\
+                         @Reorder:\t1\n\
+                         BN L; 3\n\
+                         \n\
+                         @Levels:\t1 2\n\
+                         @Reorder:\t1 0\n\
+                         R L; 5\n\
+                         @Levels:\t0 1\n\
+                         @Reorder:\t1 0\n\
+                         L R; 2\n";
+        let cases = parse_bidi_test(bidi_test);
+
+        assert_eq!(cases.len(), 5);
+        assert_eq!(cases.iter().map(|case| case.line).collect::<Vec<usize>>(), vec![4, 4, 8, 8, 11]);
+        assert_eq!(cases[0].levels, vec![None, Some(0)]);
+        check(&cases[..4]);
+
+        // L R in an LTR paragraph is shown as is
+        let failure = run(&cases[4]).unwrap();
+        assert_eq!(failure.order, vec![0, 1]);
+        assert!(failure.to_string().starts_with("line 11: L R (LeftToRight paragraph)\n"));
+
+        let bidi_character_test = "0061 0020 05D0;0;0;0 0 1;0 1 2\n\
+                                   05D0 0028 0061 0029;2;1;1 1 2 1;3 2 1 0\n";
+        check(&parse_bidi_character_test(bidi_character_test));
+    }
+
+    #[test]
+    fn test_bidi_test()
+    {
+        check(&parse_bidi_test(&read_test_file("BidiTest.txt")));
+    }
+
+    #[test]
+    fn test_bidi_character_test()
+    {
+        check(&parse_bidi_character_test(&read_test_file("BidiCharacterTest.txt")));
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::fribidi_bindings;
+
+use crate::level::LevelType;
+
+/// Errors returned by the safe fribidi wrappers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error
+{
+    /// fribidi failed to allocate its internal buffers.
+    AllocationFailed,
+    /// A list passed along the input string does not have the same length as
+    /// the string.
+    LengthMismatch
+    {
+        /// Name of the offending argument.
+        name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// An embedding level is negative or deeper than the algorithm can
+    /// resolve, that is FRIBIDI_BIDI_MAX_EXPLICIT_LEVEL plus one.
+    LevelOverflow(LevelType),
+    /// A raw value is not one of the fribidi bidi types.
+    InvalidCharType(u32),
+    /// A name that is not one of the UAX#9 bidi type short names.
+    InvalidCharTypeName(String),
+    /// A raw value is not one of the fribidi paragraph types.
+    InvalidParagraphType(u32),
+    /// A raw value is not one of the fribidi joining types.
+    InvalidJoiningType(u32),
+    /// A character set name fribidi does not know.
+    UnsupportedCharSet(String),
+    /// Text to decode holds a sequence that is invalid in its character set,
+    /// or cut by the end of the text; the value is its byte offset.
+    InvalidEncoding(usize),
+    /// A character range reaching past the end of the text.
+    InvalidRange
+    {
+        start: usize,
+        end: usize,
+        len: usize,
+    },
+    /// A position map entry that does not point into the string.
+    InvalidPosition
+    {
+        /// Name of the offending argument.
+        name: &'static str,
+        position: i32,
+        len: usize,
+    },
+    /// An input longer than fribidi can index, that is more than `i32::MAX`
+    /// characters.
+    InputTooLong(usize),
+}
+
+impl fmt::Display for Error
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Error::AllocationFailed => write!(f, "memory allocation failed"),
+            Error::LengthMismatch { name, expected, actual } =>
+                write!(f, "{} has length {}, expected {}", name, actual, expected),
+            Error::LevelOverflow(level) =>
+                write!(
+                    f,
+                    "embedding level {} is out of range 0..={}",
+                    level.0,
+                    fribidi_bindings::FRIBIDI_BIDI_MAX_EXPLICIT_LEVEL + 1
+                ),
+            Error::InvalidCharType(raw) => write!(f, "invalid bidi type value {:#x}", raw),
+            Error::InvalidCharTypeName(name) => write!(f, "unknown bidi type name {:?}", name),
+            Error::InvalidParagraphType(raw) => write!(f, "invalid paragraph type value {:#x}", raw),
+            Error::InvalidJoiningType(raw) => write!(f, "invalid joining type value {:#x}", raw),
+            Error::UnsupportedCharSet(name) => write!(f, "unsupported charset {:?}", name),
+            Error::InvalidEncoding(position) => write!(f, "invalid or truncated sequence at byte {}", position),
+            Error::InvalidRange { start, end, len } =>
+                write!(f, "range {}..{} is out of bounds for length {}", start, end, len),
+            Error::InvalidPosition { name, position, len } =>
+                write!(f, "{} holds position {}, out of bounds for length {}", name, position, len),
+            Error::InputTooLong(len) => write!(f, "input length {} exceeds {}", len, i32::MAX),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error
+{
+    /// Return `LengthMismatch` unless `actual` equals `expected`.
+    pub(crate) fn check_len(name: &'static str, expected: usize, actual: usize) -> Result<(), Error>
+    {
+        match expected == actual
+        {
+            true => Ok(()),
+            false => Err(Error::LengthMismatch { name, expected, actual })
+        }
+    }
+
+    /// Return `InvalidRange` unless `range` lies within `0..len`.
+    pub(crate) fn check_range(range: &Range<usize>, len: usize) -> Result<(), Error>
+    {
+        match range.start <= range.end && range.end <= len
+        {
+            true => Ok(()),
+            false => Err(Error::InvalidRange { start: range.start, end: range.end, len })
+        }
+    }
+
+    /// The length of an input as fribidi takes it, or `InputTooLong`.
+    pub(crate) fn ffi_len(len: usize) -> Result<i32, Error>
+    {
+        i32::try_from(len).map_err(|_| Error::InputTooLong(len))
+    }
+
+    /// Return `InvalidPosition` for the first position that is not an index
+    /// of a string of length `len`.
+    pub(crate) fn check_positions(name: &'static str, positions: &[i32], len: usize) -> Result<(), Error>
+    {
+        match positions.iter().find(|&&position| position < 0 || position as usize >= len)
+        {
+            Some(&position) => Err(Error::InvalidPosition { name, position, len }),
+            None => Ok(())
+        }
+    }
+
+    /// Return `LevelOverflow` for the first level that fribidi cannot have
+    /// resolved.
+    pub(crate) fn check_levels(embedding_levels: &[LevelType]) -> Result<(), Error>
+    {
+        match embedding_levels
+            .iter()
+            .find(|level| level.0 < 0 || level.0 as u32 > fribidi_bindings::FRIBIDI_BIDI_MAX_EXPLICIT_LEVEL + 1)
+        {
+            Some(&level) => Err(Error::LevelOverflow(level)),
+            None => Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::error::Error;
+    use crate::level::LevelType;
+
+    #[test]
+    fn test_checks()
+    {
+        assert_eq!(Error::ffi_len(7), Ok(7));
+        assert_eq!(Error::ffi_len(i32::MAX as usize), Ok(i32::MAX));
+        assert_eq!(Error::ffi_len(i32::MAX as usize + 1), Err(Error::InputTooLong(i32::MAX as usize + 1)));
+
+        assert_eq!(Error::check_len("map", 3, 2), Err(Error::LengthMismatch { name: "map", expected: 3, actual: 2 }));
+        assert_eq!(Error::check_range(&(2..4), 3), Err(Error::InvalidRange { start: 2, end: 4, len: 3 }));
+        assert_eq!(
+            Error::check_positions("map", &[0, -1, 2], 3),
+            Err(Error::InvalidPosition { name: "map", position: -1, len: 3 })
+        );
+        assert_eq!(
+            Error::check_positions("map", &[0, 3, 2], 3),
+            Err(Error::InvalidPosition { name: "map", position: 3, len: 3 })
+        );
+        assert_eq!(Error::check_levels(&[LevelType(0), LevelType(-1)]), Err(Error::LevelOverflow(LevelType(-1))));
+        assert_eq!(Error::check_levels(&[LevelType(127)]), Err(Error::LevelOverflow(LevelType(127))));
+    }
+}
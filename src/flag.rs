@@ -1,5 +1,13 @@
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+use crate::fribidi_bindings;
+
 /// Define option flags that various functions use. Each mask has
 /// only one bit set.
+///
+/// Flags combine with `|` into `FriBidiFlags`, which the functions taking
+/// flags accept along with a single `FriBidiFlag`.
 #[derive(PartialEq, PartialOrd, Debug, Clone, Copy)]
 #[repr(u32)]
 pub enum FriBidiFlag
@@ -16,4 +24,221 @@ pub enum FriBidiFlag
     Arabic = Self::ShapeArabPres as u32 | Self::ShapeArabLiga as u32
 }
 
-impl FriBidiFlag {}
\ No newline at end of file
+impl FriBidiFlag
+{
+    /// The single bit flags, in bit order.
+    const BITS: [FriBidiFlag; 8] = [
+        FriBidiFlag::ShapeMirroring,
+        FriBidiFlag::ReorderNsm,
+        FriBidiFlag::ShapeArabPres,
+        FriBidiFlag::ShapeArabLiga,
+        FriBidiFlag::ShapeArabConsole,
+        FriBidiFlag::RemoveBidirectional,
+        FriBidiFlag::RemoveJoining,
+        FriBidiFlag::RemoveSpecials,
+    ];
+}
+
+/// A combination of `FriBidiFlag`s, as the `FriBidiFlags` bit mask of the C
+/// API.
+///
+/// ```rust
+/// # use fribidi::flag::{FriBidiFlag, FriBidiFlags};
+/// let flags = FriBidiFlag::ShapeMirroring | FriBidiFlag::RemoveBidirectional;
+///
+/// assert!(flags.contains(FriBidiFlag::ShapeMirroring));
+/// assert!(!flags.contains(FriBidiFlags::DEFAULT));
+/// assert_eq!(format!("{:?}", flags), "FriBidiFlags(ShapeMirroring | RemoveBidirectional)");
+/// ```
+///
+#[repr(transparent)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct FriBidiFlags(u32);
+
+impl FriBidiFlags
+{
+    pub const EMPTY: FriBidiFlags = FriBidiFlags(0);
+    /// same as `FRIBIDI_FLAGS_DEFAULT`: ShapeMirroring, ReorderNsm and
+    /// RemoveSpecials.
+    pub const DEFAULT: FriBidiFlags = FriBidiFlags(fribidi_bindings::FRIBIDI_FLAGS_DEFAULT);
+    /// same as `FRIBIDI_FLAGS_ARABIC`: ShapeArabPres and ShapeArabLiga.
+    pub const ARABIC: FriBidiFlags = FriBidiFlags(fribidi_bindings::FRIBIDI_FLAGS_ARABIC);
+    /// Every flag fribidi knows.
+    pub const ALL: FriBidiFlags = FriBidiFlags(
+        fribidi_bindings::FRIBIDI_FLAG_SHAPE_MIRRORING
+            | fribidi_bindings::FRIBIDI_FLAG_REORDER_NSM
+            | fribidi_bindings::FRIBIDI_FLAG_SHAPE_ARAB_PRES
+            | fribidi_bindings::FRIBIDI_FLAG_SHAPE_ARAB_LIGA
+            | fribidi_bindings::FRIBIDI_FLAG_SHAPE_ARAB_CONSOLE
+            | fribidi_bindings::FRIBIDI_FLAG_REMOVE_BIDI
+            | fribidi_bindings::FRIBIDI_FLAG_REMOVE_JOINING
+            | fribidi_bindings::FRIBIDI_FLAG_REMOVE_SPECIALS
+    );
+
+    /// The raw bit mask, as the C functions take it.
+    pub const fn bits(self) -> u32
+    {
+        self.0
+    }
+
+    /// The flags of a raw bit mask, or None if it has bits fribidi does not
+    /// know.
+    pub const fn from_bits(bits: u32) -> Option<FriBidiFlags>
+    {
+        match bits & !Self::ALL.0
+        {
+            0 => Some(FriBidiFlags(bits)),
+            _ => None
+        }
+    }
+
+    /// The flags of a raw bit mask, dropping the bits fribidi does not know.
+    pub const fn from_bits_truncate(bits: u32) -> FriBidiFlags
+    {
+        FriBidiFlags(bits & Self::ALL.0)
+    }
+
+    pub const fn is_empty(self) -> bool
+    {
+        self.0 == 0
+    }
+
+    pub const fn union(self, other: FriBidiFlags) -> FriBidiFlags
+    {
+        FriBidiFlags(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: FriBidiFlags) -> FriBidiFlags
+    {
+        FriBidiFlags(self.0 & other.0)
+    }
+
+    /// Are all the flags of other set?
+    pub fn contains<F: Into<FriBidiFlags>>(self, other: F) -> bool
+    {
+        let other = other.into();
+        self.0 & other.0 == other.0
+    }
+
+    /// Is any of the flags of other set?
+    pub fn intersects<F: Into<FriBidiFlags>>(self, other: F) -> bool
+    {
+        self.0 & other.into().0 != 0
+    }
+
+    pub fn insert<F: Into<FriBidiFlags>>(&mut self, other: F)
+    {
+        self.0 |= other.into().0;
+    }
+
+    pub fn remove<F: Into<FriBidiFlags>>(&mut self, other: F)
+    {
+        self.0 &= !other.into().0;
+    }
+}
+
+/// Same as `FriBidiFlags::DEFAULT`, the flags fribidi uses when given none.
+impl Default for FriBidiFlags
+{
+    fn default() -> Self
+    {
+        FriBidiFlags::DEFAULT
+    }
+}
+
+impl From<FriBidiFlag> for FriBidiFlags
+{
+    fn from(flag: FriBidiFlag) -> Self
+    {
+        FriBidiFlags(flag as u32)
+    }
+}
+
+/// Lists the set flags, like `FriBidiFlags(ShapeMirroring | ReorderNsm)`.
+impl fmt::Debug for FriBidiFlags
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let names: Vec<String> = FriBidiFlag::BITS
+            .iter()
+            .filter(|&&flag| self.contains(flag))
+            .map(|flag| format!("{:?}", flag))
+            .collect();
+
+        write!(f, "FriBidiFlags({})", names.join(" | "))
+    }
+}
+
+impl<F: Into<FriBidiFlags>> BitOr<F> for FriBidiFlags
+{
+    type Output = FriBidiFlags;
+
+    fn bitor(self, rhs: F) -> FriBidiFlags
+    {
+        self.union(rhs.into())
+    }
+}
+
+impl<F: Into<FriBidiFlags>> BitOr<F> for FriBidiFlag
+{
+    type Output = FriBidiFlags;
+
+    fn bitor(self, rhs: F) -> FriBidiFlags
+    {
+        FriBidiFlags::from(self).union(rhs.into())
+    }
+}
+
+impl<F: Into<FriBidiFlags>> BitAnd<F> for FriBidiFlags
+{
+    type Output = FriBidiFlags;
+
+    fn bitand(self, rhs: F) -> FriBidiFlags
+    {
+        self.intersection(rhs.into())
+    }
+}
+
+impl<F: Into<FriBidiFlags>> BitOrAssign<F> for FriBidiFlags
+{
+    fn bitor_assign(&mut self, rhs: F)
+    {
+        self.insert(rhs);
+    }
+}
+
+impl<F: Into<FriBidiFlags>> BitAndAssign<F> for FriBidiFlags
+{
+    fn bitand_assign(&mut self, rhs: F)
+    {
+        self.0 &= rhs.into().0;
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::flag::{FriBidiFlag, FriBidiFlags};
+
+    #[test]
+    fn test_flags()
+    {
+        let mut flags = FriBidiFlag::ShapeMirroring | FriBidiFlag::ReorderNsm;
+        flags |= FriBidiFlag::RemoveSpecials;
+
+        assert_eq!(flags, FriBidiFlags::DEFAULT);
+        assert_eq!(FriBidiFlags::default(), FriBidiFlag::Default.into());
+        assert_eq!(FriBidiFlags::from(FriBidiFlag::Arabic), FriBidiFlags::ARABIC);
+        assert!(flags.intersects(FriBidiFlag::Default | FriBidiFlag::Arabic));
+        assert_eq!(flags & FriBidiFlag::ReorderNsm, FriBidiFlag::ReorderNsm.into());
+
+        flags.remove(FriBidiFlag::ReorderNsm);
+        assert!(!flags.contains(FriBidiFlag::ReorderNsm));
+        assert_eq!(format!("{:?}", flags), "FriBidiFlags(ShapeMirroring | RemoveSpecials)");
+        assert_eq!(format!("{:?}", FriBidiFlags::EMPTY), "FriBidiFlags()");
+
+        assert_eq!(FriBidiFlags::from_bits(0x0001_0001), Some(FriBidiFlag::ShapeMirroring | FriBidiFlag::RemoveBidirectional));
+        assert_eq!(FriBidiFlags::from_bits(0x8000_0001), None);
+        assert_eq!(FriBidiFlags::from_bits_truncate(0x8000_0001), FriBidiFlag::ShapeMirroring.into());
+    }
+}
@@ -0,0 +1,335 @@
+use std::borrow::Cow;
+
+use widestring::{U16Str, U16String, U32Str, U32String};
+
+use crate::error::Error;
+
+/// Text the bidi functions can work on.
+///
+/// fribidi works on UTF-32 strings.  This lets the safe functions take native
+/// Rust strings as well: they are converted once, and code point slices are
+/// borrowed as they are.
+pub trait BidiInput
+{
+    /// The text as a string of Unicode code points.
+    fn to_u32str(&self) -> Cow<'_, U32Str>;
+
+    /// Number of entries the code point ch of the text takes in the lists
+    /// that follow the text, like the bidi types of `CharType::into_chartypes`.
+    /// This is one, except for UTF-16 text whose lists have one entry per
+    /// code unit.
+    fn units_len(&self, _ch: u32) -> usize
+    {
+        1
+    }
+}
+
+impl BidiInput for str
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        Cow::Owned(U32String::from_str(self))
+    }
+}
+
+impl BidiInput for String
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        self.as_str().to_u32str()
+    }
+}
+
+impl BidiInput for [char]
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        Cow::Owned(U32String::from_chars(self))
+    }
+}
+
+impl BidiInput for Vec<char>
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        self.as_slice().to_u32str()
+    }
+}
+
+impl BidiInput for [u32]
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        Cow::Borrowed(U32Str::from_slice(self))
+    }
+}
+
+impl BidiInput for Vec<u32>
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        self.as_slice().to_u32str()
+    }
+}
+
+impl BidiInput for U32Str
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        Cow::Borrowed(self)
+    }
+}
+
+impl BidiInput for U32String
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        Cow::Borrowed(self.as_ustr())
+    }
+}
+
+/// UTF-16 text is decoded with its surrogate pairs joined into single code
+/// points.  Unpaired surrogates are kept as they are, so that the code point
+/// string has one entry per code unit of them and `to_u16string` gives them
+/// back unchanged.
+///
+/// The lists that follow the text have one entry per code unit, as the
+/// embedding levels of `Fribidi::logic_to_visual_utf16`: both units of a
+/// surrogate pair get the entry of its character.
+impl BidiInput for U16Str
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        let chars: Vec<u32> = char::decode_utf16(self.as_slice().iter().copied())
+            .map(|ch| match ch
+            {
+                Ok(ch) => ch as u32,
+                Err(error) => error.unpaired_surrogate() as u32
+            })
+            .collect();
+
+        Cow::Owned(U32String::from_vec(chars))
+    }
+
+    fn units_len(&self, ch: u32) -> usize
+    {
+        utf16_len(ch)
+    }
+}
+
+impl BidiInput for U16String
+{
+    fn to_u32str(&self) -> Cow<'_, U32Str>
+    {
+        self.as_ustr().to_u32str()
+    }
+
+    fn units_len(&self, ch: u32) -> usize
+    {
+        utf16_len(ch)
+    }
+}
+
+/// Number of entries of the lists that follow input, whose code points are
+/// chars; see `BidiInput::units_len`.
+pub(crate) fn units_count<T: BidiInput + ?Sized>(input: &T, chars: &U32Str) -> usize
+{
+    chars.as_slice().iter().map(|&ch| input.units_len(ch)).sum()
+}
+
+/// Spread a list with one entry per code point of chars over the units of
+/// input, repeating the entry of a character for each of its units.
+pub(crate) fn to_units<T: BidiInput + ?Sized, V: Copy>(input: &T, chars: &U32Str, values: &[V]) -> Vec<V>
+{
+    chars
+        .as_slice()
+        .iter()
+        .zip(values.iter())
+        .flat_map(|(&ch, &value)| std::iter::repeat_n(value, input.units_len(ch)))
+        .collect()
+}
+
+/// The reverse of `to_units`: keep the entry of the first unit of every
+/// character.  values must have `units_count` entries.
+pub(crate) fn from_units<T: BidiInput + ?Sized, V: Copy>(input: &T, chars: &U32Str, values: &[V]) -> Vec<V>
+{
+    let mut offset = 0;
+    chars
+        .as_slice()
+        .iter()
+        .map(|&ch| {
+            let value = values[offset];
+            offset += input.units_len(ch);
+            value
+        })
+        .collect()
+}
+
+/// Encode a code point string as UTF-16, the reverse of the `U16Str` input.
+/// Surrogates are written as they are, other values that are not Unicode
+/// scalar values become U+FFFD.
+pub(crate) fn to_u16string(input_str: &U32Str) -> U16String
+{
+    let mut units: Vec<u16> = Vec::with_capacity(input_str.len());
+    for &ch in input_str.as_slice()
+    {
+        match char::from_u32(ch)
+        {
+            Some(ch) => units.extend_from_slice(ch.encode_utf16(&mut [0; 2])),
+            None if (0xd800..=0xdfff).contains(&ch) => units.push(ch as u16),
+            None => units.push(char::REPLACEMENT_CHARACTER as u16)
+        }
+    }
+
+    U16String::from_vec(units)
+}
+
+/// Number of UTF-8 bytes a code point takes once converted to a `String`;
+/// values that are not Unicode scalar values become U+FFFD.
+pub(crate) fn utf8_len(ch: u32) -> usize
+{
+    char::from_u32(ch).map_or(char::REPLACEMENT_CHARACTER.len_utf8(), char::len_utf8)
+}
+
+/// Number of UTF-16 code units a code point takes once converted; values
+/// that are not Unicode scalar values become U+FFFD.
+pub(crate) fn utf16_len(ch: u32) -> usize
+{
+    char::from_u32(ch).map_or(char::REPLACEMENT_CHARACTER.len_utf16(), char::len_utf16)
+}
+
+/// A position map between two strings, in code points, UTF-8 bytes and
+/// UTF-16 code units.
+///
+/// `chars[i]` is the code point index in the target string of the code
+/// point `i` of the source string, as in the `Vec<i32>` maps filled by
+/// fribidi.  `utf8` and `utf16` are the same map for the UTF-8 and UTF-16
+/// encodings of both strings: they have one entry per code unit of the
+/// source string, and every code unit of a character maps to the first code
+/// unit of its counterpart.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PositionMap
+{
+    pub chars: Vec<usize>,
+    pub utf8: Vec<usize>,
+    pub utf16: Vec<usize>,
+}
+
+impl PositionMap
+{
+    /// Build the map from the code point map of source to target, which has
+    /// one entry per character of source.
+    ///
+    /// Returns: `LengthMismatch` if chars is not as long as source, and
+    /// `InvalidPosition` if one of its entries is not a position of target.
+    ///
+    pub fn new(chars: Vec<usize>, source: &U32Str, target: &U32Str) -> Result<PositionMap, Error>
+    {
+        Self::check_chars("chars", &chars, source, target)?;
+        Ok(Self::build(chars, source, target))
+    }
+
+    /// Build the visual to logical map of a line from the map returned by
+    /// `Fribidi::reorder_line`.
+    pub fn from_reorder_map(map: &[u32], logical_str: &U32Str, visual_str: &U32Str) -> Result<PositionMap, Error>
+    {
+        let chars: Vec<usize> = map.iter().map(|&position| position as usize).collect();
+        Self::check_chars("map", &chars, visual_str, logical_str)?;
+        Ok(Self::build(chars, visual_str, logical_str))
+    }
+
+    fn build(chars: Vec<usize>, source: &U32Str, target: &U32Str) -> PositionMap
+    {
+        let utf8 = Self::code_unit_map(&chars, source, target, utf8_len);
+        let utf16 = Self::code_unit_map(&chars, source, target, utf16_len);
+
+        PositionMap { chars, utf8, utf16 }
+    }
+
+    fn check_chars(name: &'static str, chars: &[usize], source: &U32Str, target: &U32Str) -> Result<(), Error>
+    {
+        Error::check_len(name, source.len(), chars.len())?;
+
+        match chars.iter().find(|&&position| position >= target.len())
+        {
+            Some(&position) => Err(Error::InvalidPosition {
+                name,
+                position: i32::try_from(position).unwrap_or(i32::MAX),
+                len: target.len(),
+            }),
+            None => Ok(())
+        }
+    }
+
+    fn code_unit_map(chars: &[usize], source: &U32Str, target: &U32Str, unit_len: fn(u32) -> usize) -> Vec<usize>
+    {
+        let mut target_offsets: Vec<usize> = Vec::with_capacity(target.len());
+        let mut offset = 0;
+        for &ch in target.as_slice()
+        {
+            target_offsets.push(offset);
+            offset += unit_len(ch);
+        }
+
+        let mut units: Vec<usize> = Vec::with_capacity(source.len());
+        for (&ch, &position) in source.as_slice().iter().zip(chars.iter())
+        {
+            units.extend(std::iter::repeat_n(target_offsets[position], unit_len(ch)));
+        }
+
+        units
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use widestring::{U16String, U32String};
+
+    use crate::error::Error;
+    use crate::input::{BidiInput, PositionMap, to_u16string};
+
+    #[test]
+    fn test_bidi_input()
+    {
+        let gt = U32String::from("abc אבג");
+
+        assert_eq!(*"abc אבג".to_u32str(), *gt);
+        assert_eq!(*"abc אבג".chars().collect::<Vec<char>>().to_u32str(), *gt);
+        assert_eq!(*gt.as_slice().to_u32str(), *gt);
+
+        // a surrogate pair is one code point, a lone surrogate is kept
+        let utf16 = U16String::from_vec(vec![0x61, 0xd83d, 0xde00, 0xdc00, 0x5d0]);
+        let chars = utf16.to_u32str();
+
+        assert_eq!(chars.as_slice(), [0x61, 0x1f600, 0xdc00, 0x5d0]);
+        assert_eq!(to_u16string(&chars), utf16);
+    }
+
+    #[test]
+    fn test_position_map()
+    {
+        let logical = U32String::from("aאב😀");
+        let visual = U32String::from("a😀בא");
+        let map = PositionMap::new(vec![0, 3, 2, 1], &logical, &visual).unwrap();
+
+        assert_eq!(map.chars, vec![0, 3, 2, 1]);
+        assert_eq!(map.utf8, vec![0, 7, 7, 5, 5, 1, 1, 1, 1]);
+        assert_eq!(map.utf16, vec![0, 4, 3, 1, 1]);
+
+        let map = PositionMap::from_reorder_map(&[0, 3, 2, 1], &logical, &visual).unwrap();
+
+        assert_eq!(map.chars, vec![0, 3, 2, 1]);
+        assert_eq!(map.utf8, vec![0, 5, 5, 5, 5, 3, 3, 1, 1]);
+        assert_eq!(map.utf16, vec![0, 3, 3, 2, 1]);
+
+        assert_eq!(
+            PositionMap::new(vec![0, 3, 2], &logical, &visual),
+            Err(Error::LengthMismatch { name: "chars", expected: 4, actual: 3 })
+        );
+        assert_eq!(
+            PositionMap::from_reorder_map(&[0, 4, 2, 1], &logical, &visual),
+            Err(Error::InvalidPosition { name: "map", position: 4, len: 4 })
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::ffi::CStr;
+use std::ops::{BitAnd, BitOr};
+
+use crate::fribidi_bindings;
+
+use crate::char::CharType;
+use crate::error::Error;
+use crate::input::{self, BidiInput};
+use crate::level::LevelType;
+
+pub type Joining = u8;
+
+/// The Arabic joining types as defined in Table 8-7 Primary Arabic Joining
+/// Classes of the Unicode standard.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum JoiningType
+{
+    NonJoining   = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_U as u8,
+    RightJoining = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_R as u8,
+    DualJoining  = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_D as u8,
+    JoinCausing  = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_C as u8,
+    Transparent  = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_T as u8,
+    LeftJoining  = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_L as u8,
+    Ignored      = fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_G as u8,
+}
+
+impl From<Joining> for JoiningType
+{
+    /// Values not known to fribidi are treated as `NonJoining`, the same as
+    /// characters missing from ArabicShaping.txt.
+    fn from(raw: Joining) -> Self
+    {
+        match raw as u32
+        {
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_R => JoiningType::RightJoining,
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_D => JoiningType::DualJoining,
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_C => JoiningType::JoinCausing,
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_T => JoiningType::Transparent,
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_L => JoiningType::LeftJoining,
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_G => JoiningType::Ignored,
+            _ => JoiningType::NonJoining
+        }
+    }
+}
+
+impl From<char> for JoiningType
+{
+    fn from(raw: char) -> Self
+    {
+        Self::from_char(raw)
+    }
+}
+
+/// Convert a raw fribidi joining type value, as returned by `as_raw`, back to
+/// a JoiningType.  Unlike `From<Joining>`, unknown values give
+/// `InvalidJoiningType`.
+impl TryFrom<u32> for JoiningType
+{
+    type Error = Error;
+
+    fn try_from(raw: u32) -> Result<Self, Self::Error>
+    {
+        match raw
+        {
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_U => Ok(JoiningType::NonJoining),
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_R => Ok(JoiningType::RightJoining),
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_D => Ok(JoiningType::DualJoining),
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_C => Ok(JoiningType::JoinCausing),
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_T => Ok(JoiningType::Transparent),
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_L => Ok(JoiningType::LeftJoining),
+            fribidi_bindings::_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_G => Ok(JoiningType::Ignored),
+            _ => Err(Error::InvalidJoiningType(raw))
+        }
+    }
+}
+
+impl JoiningType
+{
+    /// The raw fribidi joining type value.
+    pub const fn as_raw(self) -> Joining
+    {
+        self as Joining
+    }
+
+    /// same as `fribidi_get_joining_type` - get character joining type
+    ///
+    /// This function returns the joining type of a character as defined in Table
+    /// 8-7 Primary Arabic Joining Classes of the Unicode standard available at
+    /// http://www.unicode.org/versions/Unicode4.0.0/ch08.pdf#G7462, using data
+    /// provided in file ArabicShaping.txt and UnicodeData.txt of the Unicode
+    /// Character Database available at
+    /// http://www.unicode.org/Public/UNIDATA/ArabicShaping.txt and
+    /// http://www.unicode.org/Public/UNIDATA/UnicodeData.txt.
+    ///
+    pub fn from_char(ch: char) -> JoiningType
+    {
+        let raw_jtype = unsafe {
+            fribidi_bindings::fribidi_get_joining_type(ch as u32)
+        };
+
+        raw_jtype.into()
+    }
+
+    /// same as `fribidi_get_joining_types` - get joining types for an string of characters
+    ///
+    /// This function finds the joining types of an string of characters.  See
+    /// fribidi_get_joining_type() for more information about the joining types
+    /// returned by this function.  UTF-16 text gets one type per code unit,
+    /// see `BidiInput::units_len`.
+    ///
+    pub fn into_joiningtypes<T: BidiInput + ?Sized>(input: &T) -> Result<Vec<JoiningType>, Error>
+    {
+        let input_str = input.to_u32str();
+        let len = Error::ffi_len(input_str.len())?;
+        let mut raw_jtypes: Vec<Joining> = vec![0; input_str.len()];
+        unsafe {
+            fribidi_bindings::fribidi_get_joining_types(
+                input_str.as_ptr(),
+                len,
+                raw_jtypes.as_mut_ptr()
+            )
+        };
+
+        Ok(input::to_units(input, &input_str, &raw_jtypes)
+            .into_iter()
+            .map(JoiningType::from)
+            .collect())
+    }
+
+    /// same as `fribidi_get_joining_type_name` - get joining type name
+    ///
+    /// This function returns the joining type name of a joining type.  The
+    /// names are the same as ones defined in Table 8-7 Primary Arabic Joining
+    /// Classes of the Unicode standard: U, R, D, C, T, L and G.
+    ///
+    pub fn name(joining_type: JoiningType) -> String
+    {
+        unsafe {
+            let joining_type_name = fribidi_bindings::fribidi_get_joining_type_name(joining_type as Joining);
+            CStr::from_ptr(joining_type_name).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// The resolved Arabic properties of a character, as filled by
+/// `fribidi_join_arabic`.  This is a bit set of the `FRIBIDI_MASK_*` joining
+/// masks; a fresh value is simply the character joining type.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArabicProp(pub Joining);
+
+impl From<JoiningType> for ArabicProp
+{
+    fn from(joining_type: JoiningType) -> Self
+    {
+        Self(joining_type as Joining)
+    }
+}
+
+impl BitOr for ArabicProp
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self
+    {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for ArabicProp
+{
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self
+    {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl ArabicProp
+{
+    pub const JOINS_RIGHT: ArabicProp = ArabicProp(fribidi_bindings::FRIBIDI_MASK_JOINS_RIGHT as Joining);
+    pub const JOINS_LEFT: ArabicProp = ArabicProp(fribidi_bindings::FRIBIDI_MASK_JOINS_LEFT as Joining);
+    pub const ARAB_SHAPES: ArabicProp = ArabicProp(fribidi_bindings::FRIBIDI_MASK_ARAB_SHAPES as Joining);
+    pub const TRANSPARENT: ArabicProp = ArabicProp(fribidi_bindings::FRIBIDI_MASK_TRANSPARENT as Joining);
+    pub const IGNORED: ArabicProp = ArabicProp(fribidi_bindings::FRIBIDI_MASK_IGNORED as Joining);
+    pub const LIGATURED: ArabicProp = ArabicProp(fribidi_bindings::FRIBIDI_MASK_LIGATURED as Joining);
+
+    pub fn contains(&self, other: ArabicProp) -> bool
+    {
+        self.0 & other.0 == other.0
+    }
+
+    //
+    // These are the same macros inside `fribidi-joining-types.h`
+    //
+
+    /// Does the character join to the right?
+    pub fn joins_right(&self) -> bool
+    {
+        self.contains(Self::JOINS_RIGHT)
+    }
+
+    /// Does the character join to the left?
+    pub fn joins_left(&self) -> bool
+    {
+        self.contains(Self::JOINS_LEFT)
+    }
+
+    /// Does the character take Arabic presentation forms?
+    pub fn arab_shapes(&self) -> bool
+    {
+        self.contains(Self::ARAB_SHAPES)
+    }
+
+    pub fn is_transparent(&self) -> bool
+    {
+        self.contains(Self::TRANSPARENT)
+    }
+
+    pub fn is_ignored(&self) -> bool
+    {
+        self.contains(Self::IGNORED)
+    }
+
+    pub fn is_ligatured(&self) -> bool
+    {
+        self.contains(Self::LIGATURED)
+    }
+
+    /// same as `fribidi_join_arabic` - do Arabic joining
+    ///
+    /// This function does the Arabic joining algorithm.  Means, given Arabic
+    /// joining types of the characters of input_str (they are looked up
+    /// internally with fribidi_get_joining_types()), this function modifies
+    /// these properties to grasp the effect of neighboring characters. You
+    /// probably need this information later to do Arabic shaping.
+    ///
+    /// This function implements rules R1 to R7 inclusive (all rules) of the
+    /// Arabic Cursive Joining algorithm of the Unicode standard as available at
+    /// http://www.unicode.org/versions/Unicode4.0.0/ch08.pdf#G7462.  It also
+    /// interacts correctly with the bidirection algorithm as defined in Section
+    /// 3.5 Shaping of the Unicode Bidirectional Algorithm available at
+    /// http://www.unicode.org/reports/tr9/#Shaping.
+    ///
+    /// Returns: the resolved Arabic properties, one per character, or per code
+    /// unit of UTF-16 text like char_types and embedding_levels.
+    ///
+    pub fn join_arabic<T: BidiInput + ?Sized>(
+        input: &T,
+        char_types: &[CharType],
+        embedding_levels: &[LevelType]
+    ) -> Result<Vec<ArabicProp>, Error>
+    {
+        let input_str = input.to_u32str();
+        let len = Error::ffi_len(input_str.len())?;
+        let units = input::units_count(input, &input_str);
+        Error::check_len("char_types", units, char_types.len())?;
+        Error::check_len("embedding_levels", units, embedding_levels.len())?;
+        Error::check_levels(embedding_levels)?;
+        let char_types = input::from_units(input, &input_str, char_types);
+        let embedding_levels = input::from_units(input, &input_str, embedding_levels);
+
+        let mut ar_props: Vec<Joining> = JoiningType::into_joiningtypes(&*input_str)?
+            .into_iter()
+            .map(|joining_type| joining_type as Joining)
+            .collect();
+
+        unsafe {
+            fribidi_bindings::fribidi_join_arabic(
+                char_types.as_ptr() as *const u32,
+                len,
+                embedding_levels.as_ptr() as *const i8,
+                ar_props.as_mut_ptr()
+            )
+        };
+
+        Ok(input::to_units(input, &input_str, &ar_props).into_iter().map(ArabicProp).collect())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use widestring::{U16String, U32String};
+
+    use crate::char::CharType;
+    use crate::error::Error;
+    use crate::level::LevelType;
+    use crate::joining::{ArabicProp, JoiningType};
+
+    #[test]
+    fn test_get_joining_types()
+    {
+        let text = U32String::from("سلام a");
+        let joining_types = JoiningType::into_joiningtypes(&text).unwrap();
+        let gt = vec![
+            JoiningType::DualJoining,
+            JoiningType::DualJoining,
+            JoiningType::RightJoining,
+            JoiningType::DualJoining,
+            JoiningType::NonJoining,
+            JoiningType::NonJoining
+        ];
+
+        assert_eq!(joining_types, gt);
+        assert_eq!(JoiningType::name(JoiningType::Transparent), "T");
+        assert_eq!(JoiningType::try_from(JoiningType::LeftJoining.as_raw() as u32), Ok(JoiningType::LeftJoining));
+        assert_eq!(JoiningType::try_from(0x100_u32), Err(Error::InvalidJoiningType(0x100)));
+    }
+
+    #[test]
+    fn test_join_arabic()
+    {
+        let text = U32String::from("سلام");
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let embedding_levels = vec![LevelType(1); text.len()];
+
+        let ar_props = ArabicProp::join_arabic(&text, &char_types, &embedding_levels).unwrap();
+        let gt = vec![
+            ArabicProp::JOINS_LEFT | ArabicProp::ARAB_SHAPES,
+            ArabicProp::JOINS_RIGHT | ArabicProp::JOINS_LEFT | ArabicProp::ARAB_SHAPES,
+            ArabicProp::JOINS_RIGHT | ArabicProp::ARAB_SHAPES,
+            ArabicProp::ARAB_SHAPES
+        ];
+
+        assert_eq!(ar_props, gt);
+        assert_eq!(
+            ArabicProp::join_arabic(&text, &char_types, &[LevelType(1), LevelType(1), LevelType(-1), LevelType(1)]),
+            Err(Error::LevelOverflow(LevelType(-1)))
+        );
+
+        // a surrogate pair between the letters, one property per code unit
+        let text = U16String::from_str("س😀ل");
+        let char_types = CharType::into_chartypes(&text);
+        let ar_props = ArabicProp::join_arabic(&text, &char_types, &[LevelType(1); 4]).unwrap();
+
+        assert_eq!(ar_props, vec![ArabicProp::ARAB_SHAPES, ArabicProp(0), ArabicProp(0), ArabicProp::ARAB_SHAPES]);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{CharType, char::Char};
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 pub struct LevelType(pub i8);
 
 impl From<i8> for LevelType
@@ -46,7 +46,7 @@ impl LevelType
     /// 1 for FRIBIDI_TYPE_RTL and FRIBIDI_TYPE_AL.
     pub fn from_char(chartype: Char) -> Self
     {
-        if chartype as u32 & 1 != 0
+        if chartype & 1 != 0
         {
             Self(1)
         }
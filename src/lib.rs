@@ -1,6 +1,10 @@
 //! # Getting started
 //!
 //! ```rust,no_run
+//! # use widestring::U32String;
+//! # use fribidi::Fribidi;
+//! # use fribidi::level::LevelType;
+//! # use fribidi::paragraph::ParagraphType;
 //! let text = U32String::from("چرمهين");
 //! 
 //! let gt = U32String::from("ﻦﻴﻬﻣﺮﭼ");
@@ -27,11 +31,24 @@
 //! assert_eq!(embedding_levels, gt_embedding_levels);
 //! ```
 
+use std::ops::Range;
 use std::ptr::{null_mut, null};
 
-use widestring::{U32String, u32str, U32Str};
+use widestring::{U16Str, U16String, U32String, u32str, U32Str};
 
+#[cfg(not(any(feature = "fribidi-sys", feature = "pure-rust")))]
+compile_error!("enable either the `fribidi-sys` feature, to link libfribidi, or the `pure-rust` one");
+
+// fribidi-sys only comes along with pure-rust to check it in differential-tests
+#[cfg(all(feature = "fribidi-sys", feature = "pure-rust", not(feature = "differential-tests")))]
+compile_error!("the `fribidi-sys` and `pure-rust` backends exclude each other, enable `pure-rust` without the default features");
+
+#[cfg(all(feature = "fribidi-sys", not(feature = "pure-rust")))]
 use fribidi_sys::fribidi_bindings;
+#[cfg(feature = "pure-rust")]
+mod native;
+#[cfg(feature = "pure-rust")]
+use native as fribidi_bindings;
 
 pub mod bracket;
 use bracket::BracketType;
@@ -42,7 +59,57 @@ use level::LevelType;
 pub mod paragraph;
 use paragraph::ParagraphType;
 pub mod flag;
-use flag::FriBidiFlag;
+use flag::FriBidiFlags;
+pub mod joining;
+use joining::ArabicProp;
+pub mod mirror;
+pub mod charset;
+pub mod error;
+pub use error::Error;
+pub mod text;
+pub mod run;
+pub mod input;
+use input::{BidiInput, PositionMap, to_u16string};
+
+pub mod scratch;
+
+pub mod log2vis;
+
+pub mod version;
+
+#[cfg(test)]
+mod conformance;
+
+/// The result of `Fribidi::logic_to_visual_string`, or of
+/// `Fribidi::logic_to_visual_utf16` with a `U16String` text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualString<S = String>
+{
+    /// The visual string.
+    pub text: S,
+    /// Maximum level found plus one.
+    pub max_level: LevelType,
+    /// The embedding levels, in logical order.
+    pub embedding_levels: Vec<LevelType>,
+    /// Where each logical character ends up in the visual string.
+    pub logic_to_visual: PositionMap,
+    /// Where each visual character comes from in the logical string.
+    pub visual_to_logic: PositionMap,
+}
+
+impl<S> VisualString<S>
+{
+    fn with_text<T>(self, text: T) -> VisualString<T>
+    {
+        VisualString {
+            text,
+            max_level: self.max_level,
+            embedding_levels: self.embedding_levels,
+            logic_to_visual: self.logic_to_visual,
+            visual_to_logic: self.visual_to_logic
+        }
+    }
+}
 
 pub struct Fribidi;
 impl Fribidi
@@ -71,28 +138,50 @@ impl Fribidi
     ///
     pub fn remove_bidirectional_marks<'a>(
         input_str: &'a mut U32String,
-        positions_to_this: Option<&mut Vec<i32>>,
-        position_from_this_list: Option<&mut Vec<i32>>,
-        embedding_levels: Option<&mut Vec<LevelType>>
-    ) -> Result<&'a U32String, String>
+        positions_to_this: Option<&mut [i32]>,
+        position_from_this_list: Option<&mut [i32]>,
+        embedding_levels: Option<&mut [LevelType]>
+    ) -> Result<&'a U32String, Error>
     {
+        let len = Error::ffi_len(input_str.len())?;
+        if let Some(positions) = positions_to_this.as_ref()
+        {
+            Error::check_len("positions_to_this", input_str.len(), positions.len())?;
+            // fribidi only reads it to build the missing position_from_this_list,
+            // otherwise it is filled from position_from_this_list
+            if position_from_this_list.is_none()
+            {
+                Error::check_positions("positions_to_this", positions, input_str.len())?;
+            }
+        }
+        if let Some(positions) = position_from_this_list.as_ref()
+        {
+            Error::check_len("position_from_this_list", input_str.len(), positions.len())?;
+            Error::check_positions("position_from_this_list", positions, input_str.len())?;
+        }
+        if let Some(levels) = embedding_levels.as_ref()
+        {
+            Error::check_len("embedding_levels", input_str.len(), levels.len())?;
+        }
+
         let result_string_len = unsafe {
             fribidi_bindings::fribidi_remove_bidi_marks(
                 input_str.as_mut_ptr(),
-                input_str.len() as i32,
+                len,
                 if let Some(positions) = positions_to_this {positions.as_mut_ptr()} else {null_mut()},
                 if let Some(positions) = position_from_this_list {positions.as_mut_ptr()} else {null_mut()},
                 if let Some(levels) = embedding_levels {levels.as_mut_ptr() as *mut i8} else {null_mut()}
             )
         };
 
-        input_str.replace_range(result_string_len as usize.., u32str!(""));
-
-        match result_string_len
+        if result_string_len < 0
         {
-            -1 => Err("memory allocation failed".to_owned()),
-            _ => Ok(input_str)
+            return Err(Error::AllocationFailed);
         }
+
+        input_str.replace_range(result_string_len as usize.., u32str!(""));
+
+        Ok(input_str)
     }
 
     /// same_as `fribidi_log`2vis - get visual string
@@ -103,30 +192,76 @@ impl Fribidi
     /// list of embedding levels as defined by the algorithm.
     ///
     /// If NULL is passed as any of the the lists, the list is ignored and not
-    /// filled.
+    /// filled.  `log2vis::Log2Vis` allocates the lists it is asked for.
     ///
     /// Note that this function handles one-line paragraphs. For multi-
     /// paragraph texts it is necessary to first split the text into
     /// separate paragraphs and then carry over the resolved pbase_dir
-    /// between the subsequent invocations.
+    /// between the subsequent invocations.  `text::BidiText` does that.
     ///
     /// Returns: visual result string and the maximum level found plus one, or zero if any error occurred
     /// (memory allocation failure most probably).
     ///
-    pub fn logic_to_visual(
-        input_str: &U32String,
+    pub fn logic_to_visual<T: BidiInput + ?Sized>(
+        input_str: &T,
+        paragraph_direction: ParagraphType,
+        positions_logic_to_visual: Option<&mut [i32]>,
+        positions_visual_to_logic: Option<&mut [i32]>,
+        embedding_levels: Option<&mut [LevelType]>
+    ) -> Result<(U32String, i8), Error>
+    {
+        let input_str = input_str.to_u32str();
+        let mut visual_str = std::iter::repeat_n(" ", input_str.len()).collect::<U32String>();
+
+        let maximum_level = Self::logic_to_visual_into(
+            &input_str,
+            paragraph_direction,
+            visual_str.as_mut_slice(),
+            positions_logic_to_visual,
+            positions_visual_to_logic,
+            embedding_levels
+        )?;
+
+        Ok((visual_str, maximum_level.0))
+    }
+
+    /// `logic_to_visual` writing into caller buffers
+    ///
+    /// Same as `logic_to_visual`, without any allocation on the Rust side:
+    /// the visual string is written to visual_str, and the lists that are not
+    /// None are filled.  All of them must have the length of input_str.
+    /// fribidi still allocates its own work buffers.
+    ///
+    /// Returns: the maximum level found plus one.
+    ///
+    pub fn logic_to_visual_into(
+        input_str: &U32Str,
         paragraph_direction: ParagraphType,
-        positions_logic_to_visual: Option<&mut Vec<i32>>,
-        positions_visual_to_logic: Option<&mut Vec<i32>>,
-        embedding_levels: Option<&mut Vec<LevelType>>
-    ) -> Result<(U32String, i8), String>
+        visual_str: &mut [u32],
+        positions_logic_to_visual: Option<&mut [i32]>,
+        positions_visual_to_logic: Option<&mut [i32]>,
+        embedding_levels: Option<&mut [LevelType]>
+    ) -> Result<LevelType, Error>
     {
-        let mut visual_str = std::iter::repeat(" ").take(input_str.len()).collect::<U32String>();
+        let len = Error::ffi_len(input_str.len())?;
+        Error::check_len("visual_str", input_str.len(), visual_str.len())?;
+        if let Some(positions) = positions_logic_to_visual.as_ref()
+        {
+            Error::check_len("positions_logic_to_visual", input_str.len(), positions.len())?;
+        }
+        if let Some(positions) = positions_visual_to_logic.as_ref()
+        {
+            Error::check_len("positions_visual_to_logic", input_str.len(), positions.len())?;
+        }
+        if let Some(levels) = embedding_levels.as_ref()
+        {
+            Error::check_len("embedding_levels", input_str.len(), levels.len())?;
+        }
 
         let maximum_level = unsafe {
             fribidi_bindings::fribidi_log2vis(
                 input_str.as_ptr(),
-                input_str.len() as i32,
+                len,
                 &mut (paragraph_direction as u32),
                 visual_str.as_mut_ptr(),
                 if let Some(positions) = positions_logic_to_visual {positions.as_mut_ptr()} else {null_mut()},
@@ -137,11 +272,136 @@ impl Fribidi
 
         match maximum_level
         {
-            0 => Err("memory allocation failed".to_owned()),
-            _ => Ok((visual_str, maximum_level))
+            0 => Err(Error::AllocationFailed),
+            _ => Ok(LevelType(maximum_level))
         }
     }
 
+    /// `logic_to_visual` returning owned Rust values
+    ///
+    /// Same as `logic_to_visual`, with the visual string as a `String` and the
+    /// position maps in character indices, UTF-8 byte offsets and UTF-16 code
+    /// unit offsets.
+    ///
+    /// ```rust
+    /// # use fribidi::Fribidi;
+    /// # use fribidi::paragraph::ParagraphType;
+    /// let visual = Fribidi::logic_to_visual_string("abc אבג", ParagraphType::LeftToRight).unwrap();
+    ///
+    /// assert_eq!(visual.text, "abc גבא");
+    /// assert_eq!(visual.logic_to_visual.chars, vec![0, 1, 2, 3, 6, 5, 4]);
+    /// assert_eq!(visual.logic_to_visual.utf8, vec![0, 1, 2, 3, 8, 8, 6, 6, 4, 4]);
+    /// assert_eq!(visual.logic_to_visual.utf16, vec![0, 1, 2, 3, 6, 5, 4]);
+    /// ```
+    ///
+    pub fn logic_to_visual_string<T: BidiInput + ?Sized>(
+        input_str: &T,
+        paragraph_direction: ParagraphType
+    ) -> Result<VisualString, Error>
+    {
+        let visual = Self::logic_to_visual_owned(&input_str.to_u32str(), paragraph_direction)?;
+        let text = visual.text.to_string_lossy();
+
+        Ok(visual.with_text(text))
+    }
+
+    fn logic_to_visual_owned(
+        logical_str: &U32Str,
+        paragraph_direction: ParagraphType
+    ) -> Result<VisualString<U32String>, Error>
+    {
+        let mut positions_logic_to_visual: Vec<i32> = vec![0; logical_str.len()];
+        let mut positions_visual_to_logic: Vec<i32> = vec![0; logical_str.len()];
+        let mut embedding_levels: Vec<LevelType> = vec![LevelType(0); logical_str.len()];
+
+        let (visual_str, max_level) = Self::logic_to_visual(
+            logical_str,
+            paragraph_direction,
+            Some(&mut positions_logic_to_visual),
+            Some(&mut positions_visual_to_logic),
+            Some(&mut embedding_levels)
+        )?;
+
+        let logic_to_visual = PositionMap::new(
+            positions_logic_to_visual.into_iter().map(|position| position as usize).collect(),
+            logical_str,
+            &visual_str
+        )?;
+        let visual_to_logic = PositionMap::new(
+            positions_visual_to_logic.into_iter().map(|position| position as usize).collect(),
+            &visual_str,
+            logical_str
+        )?;
+
+        Ok(VisualString {
+            text: visual_str,
+            max_level: LevelType(max_level),
+            embedding_levels,
+            logic_to_visual,
+            visual_to_logic
+        })
+    }
+
+    /// `logic_to_visual` for UTF-16 text
+    ///
+    /// Same as `logic_to_visual_string`, for text that comes as UTF-16 and
+    /// should stay so.  Surrogate pairs are reordered as single characters and
+    /// unpaired surrogates are passed through; the position maps are to be
+    /// read in their `utf16` code unit offsets, and there is one embedding
+    /// level per code unit.
+    ///
+    /// ```rust
+    /// # use widestring::U16String;
+    /// # use fribidi::Fribidi;
+    /// # use fribidi::paragraph::ParagraphType;
+    /// let text = U16String::from_str("a😀 אב");
+    /// let visual = Fribidi::logic_to_visual_utf16(&text, ParagraphType::LeftToRight).unwrap();
+    ///
+    /// assert_eq!(visual.text, U16String::from_str("a😀 בא"));
+    /// assert_eq!(visual.logic_to_visual.utf16, vec![0, 1, 1, 3, 5, 4]);
+    /// ```
+    ///
+    pub fn logic_to_visual_utf16(
+        input_str: &U16Str,
+        paragraph_direction: ParagraphType
+    ) -> Result<VisualString<U16String>, Error>
+    {
+        let logical_str = input_str.to_u32str();
+        let mut visual = Self::logic_to_visual_owned(&logical_str, paragraph_direction)?;
+
+        visual.embedding_levels = input::to_units(input_str, &logical_str, &visual.embedding_levels);
+
+        let text = to_u16string(&visual.text);
+        Ok(visual.with_text(text))
+    }
+
+    /// `logic_to_visual` for strings written in the CapRTL test notation
+    ///
+    /// The input is converted with `charset::from_caprtl`, reordered and
+    /// shaped like `logic_to_visual` does, and converted back to CapRTL.  This
+    /// keeps bidi tests readable without spelling out Arabic or Hebrew text.
+    ///
+    /// ```rust
+    /// # use fribidi::Fribidi;
+    /// # use fribidi::paragraph::ParagraphType;
+    /// let visual = Fribidi::logic_to_visual_caprtl("car is THE CAR", ParagraphType::LeftToRight).unwrap();
+    /// assert_eq!(visual, "car is RAC EHT");
+    ///
+    /// let visual = Fribidi::logic_to_visual_caprtl("CAR is the car", ParagraphType::OtherNeutral).unwrap();
+    /// assert_eq!(visual, "is the car RAC");
+    /// ```
+    ///
+    pub fn logic_to_visual_caprtl(
+        input_str: &str,
+        paragraph_direction: ParagraphType
+    ) -> Result<String, Error>
+    {
+        let logical_str = charset::from_caprtl(input_str)?;
+        let (visual_str, _) = Self::logic_to_visual(&logical_str, paragraph_direction, None, None, None)?;
+
+        charset::to_caprtl(&visual_str)
+    }
+
     /// same_as `fribidi_get_par_embedding_levels_ex` - get bidi embedding levels of a paragraph
     ///
     /// This function finds the bidi embedding levels of a single paragraph,
@@ -158,40 +418,72 @@ impl Fribidi
     ///     0: the result embedding levels
     ///     1: Maximum level found plus one, or zero if any error occurred
     ///        (memory allocation failure most probably).
-    ///     2: the result ParagraphType
+    ///     2: the resolved ParagraphType, LeftToRight or RightToLeft
     ///
     // FRIBIDI_ENTRY FriBidiLevel
     pub fn get_paragraph_embedding_levels_ex (
-        char_types: &Vec<CharType>,
-        bracket_types: Option<&Vec<BracketType>>,
+        char_types: &[CharType],
+        bracket_types: Option<&[BracketType]>,
         paragraph_direction: ParagraphType
-    ) -> Result<(Vec<LevelType>, LevelType, ParagraphType), String>
+    ) -> Result<(Vec<LevelType>, LevelType, ParagraphType), Error>
+    {
+        let mut res: Vec<LevelType> = vec![LevelType(0); char_types.len()];
+
+        let (max_embedding_level, paragraph_direction) = Self::get_paragraph_embedding_levels_into(
+            char_types,
+            bracket_types,
+            paragraph_direction,
+            &mut res
+        )?;
+
+        Ok((res, max_embedding_level, paragraph_direction))
+    }
+
+    /// `get_paragraph_embedding_levels_ex` writing into a caller buffer
+    ///
+    /// Same as `get_paragraph_embedding_levels_ex`, with the levels written to
+    /// embedding_levels, which must have the length of char_types.
+    ///
+    /// Returns: the maximum level found plus one and the resolved
+    /// ParagraphType.
+    ///
+    pub fn get_paragraph_embedding_levels_into(
+        char_types: &[CharType],
+        bracket_types: Option<&[BracketType]>,
+        paragraph_direction: ParagraphType,
+        embedding_levels: &mut [LevelType]
+    ) -> Result<(LevelType, ParagraphType), Error>
     {
-        if bracket_types.is_some()
+        let len = Error::ffi_len(char_types.len())?;
+        if let Some(types) = bracket_types
         {
-            if char_types.len() != bracket_types.map_or(char_types.len(), |types| types.len())
-            {
-                return Err("char_types length must equals bracket_types length".to_owned());
-            }
+            Error::check_len("bracket_types", char_types.len(), types.len())?;
         }
+        Error::check_len("embedding_levels", char_types.len(), embedding_levels.len())?;
 
-        let mut res: Vec<LevelType> = vec![LevelType(0); char_types.len()];
-        let paragraph_direction: ParagraphType = paragraph_direction;
+        let mut raw_paragraph_direction = paragraph_direction as u32;
 
         let max_embedding_level = unsafe {
             fribidi_bindings::fribidi_get_par_embedding_levels_ex (
                 char_types.as_ptr() as *const u32,
                 if let Some(types) = bracket_types { types.as_ptr() as *const u32 } else { null() },
-                char_types.len() as i32,
-                &mut (paragraph_direction as u32),
-                res.as_mut_ptr() as *mut i8
+                len,
+                &mut raw_paragraph_direction,
+                embedding_levels.as_mut_ptr() as *mut i8
             )
         };
 
+        // fribidi resolves the base direction to either LTR or RTL
+        let paragraph_direction = match raw_paragraph_direction
+        {
+            fribidi_bindings::FriBidiParType_FRIBIDI_PAR_RTL => ParagraphType::RightToLeft,
+            _ => ParagraphType::LeftToRight
+        };
+
         match max_embedding_level
         {
-            0 => Err("memory allocation failed".to_owned()),
-            _ => Ok((res, LevelType(max_embedding_level), paragraph_direction))
+            0 => Err(Error::AllocationFailed),
+            _ => Ok((LevelType(max_embedding_level), paragraph_direction))
         }
     }
 
@@ -224,44 +516,245 @@ impl Fribidi
     ///
     /// Returns: Maximum level found in this line plus one, or zero if any error
     /// occurred (memory allocation failure most probably) and the reordered result line.
+    /// `PositionMap::from_reorder_map` turns the map into UTF-8 and UTF-16
+    /// offsets.
     ///
-    pub fn reorder_line(
-        flags: FriBidiFlag,                             // reorder flags
-        chartypes: &Vec<CharType>,	                    // input list of bidi types as returned by fribidi_get_bidi_types()
+    pub fn reorder_line<F: Into<FriBidiFlags>>(
+        flags: F,                                       // reorder flags
+        chartypes: &[CharType],	                    // input list of bidi types as returned by fribidi_get_bidi_types()
         base_dir: ParagraphType,	                    // resolved paragraph base direction
-        embedding_levels: Option<&mut Vec<LevelType>>,	// input list of embedding levels, as returned by fribidi_get_par_embedding_levels
+        embedding_levels: &mut [LevelType],	            // input list of embedding levels, as returned by fribidi_get_par_embedding_levels
         visual_str: &mut U32Str,	                    // visual string to reorder
-    ) -> Result<(LevelType, Vec<u32>), &'static str>    // a map of string indices which is reordered to reflect where each glyph ends up.
+    ) -> Result<(LevelType, Vec<u32>), Error>           // a map of string indices which is reordered to reflect where each glyph ends up.
+    {
+        let mut res_map: Vec<i32> = vec![0; visual_str.len()];
+
+        let max_level = Self::reorder_line_into(
+            flags,
+            chartypes,
+            base_dir,
+            embedding_levels,
+            visual_str,
+            &mut res_map
+        )?;
+
+        Ok((max_level, res_map.into_iter().map(|position| position as u32).collect()))
+    }
+
+    /// `reorder_line` writing into a caller buffer
+    ///
+    /// Same as `reorder_line`, with the map written to map, which must have
+    /// the length of visual_str.  map does not need to be initialized: it is
+    /// reset to the identity before reordering.
+    ///
+    /// Returns: Maximum level found in this line plus one.
+    ///
+    pub fn reorder_line_into<F: Into<FriBidiFlags>>(
+        flags: F,
+        chartypes: &[CharType],
+        base_dir: ParagraphType,
+        embedding_levels: &mut [LevelType],
+        visual_str: &mut U32Str,
+        map: &mut [i32]
+    ) -> Result<LevelType, Error>
     {
-        let embedding_levels_len = embedding_levels.as_ref().map_or(visual_str.len(), |levels| levels.len());
-        if chartypes.len() != embedding_levels_len || chartypes.len() != visual_str.len()
+        let len = Error::ffi_len(visual_str.len())?;
+        Error::check_len("chartypes", visual_str.len(), chartypes.len())?;
+        Error::check_len("embedding_levels", visual_str.len(), embedding_levels.len())?;
+        Error::check_len("map", visual_str.len(), map.len())?;
+        Error::check_levels(embedding_levels)?;
+
+        for (index, position) in map.iter_mut().enumerate()
         {
-            return Err("chartypes.len() != embedding_levels.len() != visual_str.len()");
+            *position = index as i32;
         }
 
-        // let mut res: Vec<usize> = vec![1; chartypes.len()];
-        let mut res_map: Vec<u32> = (0..visual_str.len() as u32).collect();
-
         let max_level = unsafe {
             fribidi_bindings::fribidi_reorder_line(
-                flags as u32,
+                flags.into().bits(),
                 chartypes.as_ptr() as *const u32,
-                visual_str.len() as i32,
+                len,
                 0,
                 base_dir as u32,
-                // if let Some(levels) = embedding_levels { levels.as_mut_ptr() as *mut i8 } else { null_mut() },
-                embedding_levels.map_or(null_mut(), |levels| levels.as_mut_ptr() as *mut i8),
+                embedding_levels.as_mut_ptr() as *mut i8,
                 visual_str.as_mut_ptr(),
-                res_map.as_mut_ptr() as *mut i32
+                map.as_mut_ptr()
             )
         };
 
         match max_level
         {
-            0 => Err("memory allocation failed"),
-            _ => Ok((max_level.into(), res_map))
+            0 => Err(Error::AllocationFailed),
+            _ => Ok(max_level.into())
         }
     }
+
+    /// same_as `fribidi_reorder_line` with an offset - reorder one line of a paragraph
+    ///
+    /// This is `reorder_line` for text whose line breaks are already known.
+    /// chartypes, embedding_levels, visual_str and map span the whole
+    /// paragraph, with embedding_levels as returned once by
+    /// `get_paragraph_embedding_levels_ex`; only the characters in line are
+    /// reordered, and only their entries of embedding_levels and map change.
+    /// Call it once per line.
+    ///
+    /// Part 4 of rule L1 is applied to the end of line: trailing white space
+    /// of the line gets the paragraph embedding level in embedding_levels.
+    ///
+    /// map should be initialized to something sensible, like an identity
+    /// mapping.  Its entries of line are reordered to reflect where each
+    /// character of the line ends up, so they keep holding paragraph indices.
+    ///
+    /// Returns: Maximum level found in this line plus one.
+    ///
+    pub fn reorder_line_range<F: Into<FriBidiFlags>>(
+        flags: F,
+        chartypes: &[CharType],
+        line: Range<usize>,
+        base_dir: ParagraphType,
+        embedding_levels: &mut [LevelType],
+        visual_str: &mut U32Str,
+        map: Option<&mut [i32]>
+    ) -> Result<LevelType, Error>
+    {
+        // line lies within visual_str, so its bounds fit in an i32 as well
+        Error::ffi_len(visual_str.len())?;
+        Error::check_len("chartypes", visual_str.len(), chartypes.len())?;
+        Error::check_len("embedding_levels", visual_str.len(), embedding_levels.len())?;
+        if let Some(map) = map.as_ref()
+        {
+            Error::check_len("map", visual_str.len(), map.len())?;
+        }
+        Error::check_range(&line, visual_str.len())?;
+        Error::check_levels(&embedding_levels[line.clone()])?;
+
+        let max_level = unsafe {
+            fribidi_bindings::fribidi_reorder_line(
+                flags.into().bits(),
+                chartypes.as_ptr() as *const u32,
+                line.len() as i32,
+                line.start as i32,
+                base_dir as u32,
+                embedding_levels.as_mut_ptr() as *mut i8,
+                visual_str.as_mut_ptr(),
+                map.map_or(null_mut(), |map| map.as_mut_ptr())
+            )
+        };
+
+        match max_level
+        {
+            0 => Err(Error::AllocationFailed),
+            _ => Ok(max_level.into())
+        }
+    }
+
+    /// same as `fribidi_shape_arabic` - do Arabic shaping
+    ///
+    /// The actual shaping that is done depends on the flags set.  Only flags
+    /// starting with ShapeArab affect this function.  Currently these are:
+    ///
+    /// * ShapeArabPres: Whether to shape Arabic characters to their
+    ///   presentation form glyphs.
+    /// * ShapeArabLiga: Whether to form mandatory Arabic ligatures.
+    /// * ShapeArabConsole: Whether to form extra ligatures that make the text
+    ///   fit better on a monospace console.
+    ///
+    /// Of the above, ShapeArabConsole is only used in special cases, but the
+    /// rest are recommended in any environment that doesn't have other means
+    /// for doing Arabic shaping.
+    ///
+    /// The first character of a formed ligature is replaced by U+FEFF and its
+    /// Arabic property gets the LIGATURED bit; use `Fribidi::shape` to drop
+    /// those placeholders.
+    ///
+    pub fn shape_arabic<F: Into<FriBidiFlags>>(
+        flags: F,
+        embedding_levels: &[LevelType],
+        ar_props: &mut [ArabicProp],
+        input_str: &mut U32Str
+    ) -> Result<(), Error>
+    {
+        let len = Error::ffi_len(input_str.len())?;
+        Error::check_len("embedding_levels", input_str.len(), embedding_levels.len())?;
+        Error::check_len("ar_props", input_str.len(), ar_props.len())?;
+        Error::check_levels(embedding_levels)?;
+
+        unsafe {
+            fribidi_bindings::fribidi_shape_arabic(
+                flags.into().bits(),
+                embedding_levels.as_ptr() as *const i8,
+                len,
+                ar_props.as_mut_ptr() as *mut u8,
+                input_str.as_mut_ptr()
+            )
+        };
+
+        Ok(())
+    }
+
+    /// same as `fribidi_shape` - do bidi-aware shaping
+    ///
+    /// This function does all shaping work that depends on the resolved
+    /// embedding levels of the characters.  Currently it does mirroring and
+    /// Arabic shaping, but the list may grow later.  Mirroring is done if
+    /// ShapeMirroring is set in flags, Arabic shaping as described for
+    /// `Fribidi::shape_arabic`.
+    ///
+    /// The ar_props are the Arabic properties as returned by
+    /// `ArabicProp::join_arabic`.
+    ///
+    /// After shaping, the placeholders left behind by formed ligatures are
+    /// removed from input_str, and their Arabic properties from ar_props, so
+    /// that both keep the same length.
+    ///
+    /// Returns: a map from the positions of the string before shaping to the
+    /// positions after the ligature removal.  A position pointing to a removed
+    /// character is filled with -1.  Use it to compact any other array of the
+    /// string, like embedding_levels.
+    ///
+    pub fn shape<F: Into<FriBidiFlags>>(
+        flags: F,
+        embedding_levels: &[LevelType],
+        ar_props: &mut Vec<ArabicProp>,
+        input_str: &mut U32String
+    ) -> Result<Vec<i32>, Error>
+    {
+        let len = Error::ffi_len(input_str.len())?;
+        Error::check_len("embedding_levels", input_str.len(), embedding_levels.len())?;
+        Error::check_len("ar_props", input_str.len(), ar_props.len())?;
+        Error::check_levels(embedding_levels)?;
+
+        unsafe {
+            fribidi_bindings::fribidi_shape(
+                flags.into().bits(),
+                embedding_levels.as_ptr() as *const i8,
+                len,
+                ar_props.as_mut_ptr() as *mut u8,
+                input_str.as_mut_ptr()
+            )
+        };
+
+        let mut positions: Vec<i32> = Vec::with_capacity(input_str.len());
+        let mut shaped_str = U32String::with_capacity(input_str.len());
+        let mut shaped_props: Vec<ArabicProp> = Vec::with_capacity(ar_props.len());
+        for (&ch, &ar_prop) in input_str.as_slice().iter().zip(ar_props.iter())
+        {
+            if ar_prop.is_ligatured() && ch == fribidi_bindings::FRIBIDI_CHAR_FILL
+            {
+                positions.push(-1);
+            }
+            else
+            {
+                positions.push(shaped_str.len() as i32);
+                shaped_str.push_slice([ch]);
+                shaped_props.push(ar_prop);
+            }
+        }
+        *input_str = shaped_str;
+        *ar_props = shaped_props;
+
+        Ok(positions)
+    }
   
 }
 
@@ -274,13 +767,12 @@ mod test
     use crate::BracketType;
     use crate::flag::FriBidiFlag;
 
-    use super::{Fribidi, ParagraphType, CharType, LevelType};
+    use super::{Fribidi, ParagraphType, CharType, LevelType, ArabicProp, Error};
 
     #[test]
     fn test_remove_bidi_marks()
     {
-        #[allow(text_direction_codepoint_in_literal)]
-        let mut text = U32String::from("أحمد‫ خالد");
+        let mut text = U32String::from("أحمد\u{202b} خالد");
         let gt = U32String::from("أحمد خالد");
 
         let mut positions_to_this :Vec<i32> = vec![0; text.len()];
@@ -295,6 +787,60 @@ mod test
         assert_eq!(position_from_this_list, vec![0, 1, 2, 3, 5, 6, 7, 8, 9, 9]);
     }
 
+    #[test]
+    fn test_rejected_buffers()
+    {
+        let mut text = U32String::from("abc\u{202b}");
+        let mut positions: Vec<i32> = vec![0, 1, 2];
+
+        assert_eq!(
+            Fribidi::remove_bidirectional_marks(&mut text, Some(&mut positions), None, None),
+            Err(Error::LengthMismatch { name: "positions_to_this", expected: 4, actual: 3 })
+        );
+
+        let mut positions: Vec<i32> = vec![0, 1, 2, 4];
+        assert_eq!(
+            Fribidi::remove_bidirectional_marks(&mut text, None, Some(&mut positions), None),
+            Err(Error::InvalidPosition { name: "position_from_this_list", position: 4, len: 4 })
+        );
+
+        // positions_to_this is only an output when position_from_this_list is given
+        let mut positions_to_this: Vec<i32> = vec![-7; 4];
+        let mut position_from_this_list: Vec<i32> = vec![0, 1, 2, 3];
+        assert_eq!(
+            Fribidi::remove_bidirectional_marks(
+                &mut text.clone(),
+                Some(&mut positions_to_this),
+                Some(&mut position_from_this_list),
+                None
+            ),
+            Ok(&U32String::from("abc"))
+        );
+        assert_eq!(positions_to_this, vec![0, 1, 2, 3]);
+        assert_eq!(
+            Fribidi::remove_bidirectional_marks(&mut text, Some(&mut [-7; 4]), None, None),
+            Err(Error::InvalidPosition { name: "positions_to_this", position: -7, len: 4 })
+        );
+
+        let mut embedding_levels = vec![LevelType(0); 3];
+        assert_eq!(
+            Fribidi::logic_to_visual(&text, ParagraphType::LeftToRight, None, None, Some(&mut embedding_levels)),
+            Err(Error::LengthMismatch { name: "embedding_levels", expected: 4, actual: 3 })
+        );
+
+        let char_types = CharType::into_chartypes("abc");
+        let mut line = U32String::from("abc");
+        assert_eq!(
+            Fribidi::reorder_line(FriBidiFlag::Default, &char_types, ParagraphType::LeftToRight, &mut [], &mut line),
+            Err(Error::LengthMismatch { name: "embedding_levels", expected: 3, actual: 0 })
+        );
+
+        assert_eq!(
+            BracketType::parse(&text, &char_types),
+            Err(Error::LengthMismatch { name: "char_types", expected: 4, actual: 3 })
+        );
+    }
+
     #[test]
     fn test_log2vis()
     {
@@ -327,8 +873,8 @@ mod test
     {
         let text = U32String::from("(أحمد خالد 比 توفـــــيق boieng 1997)");
         let char_types = CharType::into_chartypes(text.as_vec());
-        let bracket_types = BracketType::parse(&text, &char_types);
-        let paragraph_dir = ParagraphType::direction(&char_types);
+        let bracket_types = BracketType::parse(&text, &char_types).unwrap();
+        let paragraph_dir = ParagraphType::direction(&char_types).unwrap();
 
         let res = Fribidi::get_paragraph_embedding_levels_ex(
             &char_types,
@@ -359,8 +905,8 @@ mod test
         let gt_map = vec![35, 34, 33, 32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 5, 4, 3, 2, 1, 0];
 
         let char_types = CharType::into_chartypes(text.as_vec());
-        let paragraph_direction = ParagraphType::direction(&char_types);
-        let bracket_types = BracketType::parse(&text, &char_types);
+        let paragraph_direction = ParagraphType::direction(&char_types).unwrap();
+        let bracket_types = BracketType::parse(&text, &char_types).unwrap();
 
         let embedding_levels = Fribidi::get_paragraph_embedding_levels_ex(
             &char_types,
@@ -373,7 +919,7 @@ mod test
             FriBidiFlag::Default,
             &char_types,
             paragraph_direction,
-            Some(&mut embedding_levels),
+            &mut embedding_levels,
             &mut text
         );
 
@@ -382,4 +928,154 @@ mod test
         assert_eq!(text, gt);
         assert_eq!(res_map, gt_map);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_shape()
+    {
+        let mut text = U32String::from("سلام");
+        let gt = U32String::from("ﺳﻼﻡ");
+        let gt_positions = vec![0, -1, 1, 2];
+
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let embedding_levels = vec![LevelType(1); text.len()];
+        let mut ar_props = ArabicProp::join_arabic(&text, &char_types, &embedding_levels).unwrap();
+
+        let positions = Fribidi::shape(
+            FriBidiFlag::Arabic,
+            &embedding_levels,
+            &mut ar_props,
+            &mut text
+        ).unwrap();
+
+        assert_eq!(text, gt);
+        assert_eq!(positions, gt_positions);
+        assert_eq!(ar_props.len(), text.len());
+
+        // mirroring and shaping in one go
+        let mut text = U32String::from("(سلام)");
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let embedding_levels = vec![LevelType(1); text.len()];
+        let mut ar_props = ArabicProp::join_arabic(&text, &char_types, &embedding_levels).unwrap();
+
+        Fribidi::shape(
+            FriBidiFlag::ShapeMirroring | FriBidiFlag::Arabic,
+            &embedding_levels,
+            &mut ar_props,
+            &mut text
+        ).unwrap();
+
+        assert_eq!(text, U32String::from(")ﺳﻼﻡ("));
+    }
+
+    #[test]
+    fn test_shape_lam_alef()
+    {
+        // LAM, ALEF and a BEH, the LAM-ALEF ligature takes the place of the ALEF
+        let mut text = U32String::from("\u{644}\u{627}\u{628}");
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let mut embedding_levels = vec![LevelType(1); text.len()];
+        let mut ar_props = ArabicProp::join_arabic(&text, &char_types, &embedding_levels).unwrap();
+
+        let positions = Fribidi::shape(
+            FriBidiFlag::Arabic,
+            &embedding_levels,
+            &mut ar_props,
+            &mut text
+        ).unwrap();
+
+        assert_eq!(text, U32String::from("\u{FEFB}\u{FE8F}"));
+        assert_eq!(positions, vec![-1, 0, 1]);
+        assert_eq!(ar_props.len(), 2);
+        assert!(ar_props.iter().all(|ar_prop| !ar_prop.is_ligatured()));
+
+        // the positions compact the levels the same way
+        embedding_levels = positions
+            .iter()
+            .zip(&embedding_levels)
+            .filter(|(&position, _)| position >= 0)
+            .map(|(_, &level)| level)
+            .collect();
+        assert_eq!(embedding_levels.len(), text.len());
+    }
+
+    #[test]
+    fn test_reorder_line_range()
+    {
+        let mut text = U32String::from("abc אבג דהו");
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let (mut embedding_levels, _, base_dir) = Fribidi::get_paragraph_embedding_levels_ex(
+            &char_types,
+            None,
+            ParagraphType::LeftToRight
+        ).unwrap();
+        let mut map: Vec<i32> = (0..text.len() as i32).collect();
+
+        assert_eq!(embedding_levels[7], LevelType(1));
+
+        for line in [0..8, 8..11]
+        {
+            Fribidi::reorder_line_range(
+                FriBidiFlag::Default,
+                &char_types,
+                line,
+                base_dir,
+                &mut embedding_levels,
+                &mut text,
+                Some(&mut map)
+            ).unwrap();
+        }
+
+        assert_eq!(text, U32String::from("abc גבא והד"));
+        assert_eq!(map, vec![0, 1, 2, 3, 6, 5, 4, 7, 10, 9, 8]);
+        assert_eq!(embedding_levels[7], LevelType(0));
+    }
+
+    #[test]
+    fn test_into_variants()
+    {
+        let text = U32String::from("abc אבג");
+        let char_types = CharType::into_chartypes(&text);
+        let mut visual_str = [0; 7];
+        let mut embedding_levels = [LevelType(0); 7];
+        let mut map = [0; 7];
+
+        let max_level = Fribidi::logic_to_visual_into(
+            &text,
+            ParagraphType::LeftToRight,
+            &mut visual_str,
+            Some(&mut map),
+            None,
+            None
+        ).unwrap();
+
+        assert_eq!(max_level, LevelType(2));
+        assert_eq!(visual_str, *U32String::from("abc גבא").as_slice());
+        assert_eq!(map, [0, 1, 2, 3, 6, 5, 4]);
+
+        let (max_level, direction) = Fribidi::get_paragraph_embedding_levels_into(
+            &char_types,
+            None,
+            ParagraphType::OtherNeutral,
+            &mut embedding_levels
+        ).unwrap();
+
+        assert_eq!((max_level, direction), (LevelType(2), ParagraphType::LeftToRight));
+        assert_eq!(embedding_levels, [0, 0, 0, 0, 1, 1, 1].map(LevelType));
+
+        let mut line = text.clone();
+        Fribidi::reorder_line_into(
+            FriBidiFlag::Default,
+            &char_types,
+            direction,
+            &mut embedding_levels,
+            &mut line,
+            &mut map
+        ).unwrap();
+
+        assert_eq!(map, [0, 1, 2, 3, 6, 5, 4]);
+        assert_eq!(
+            Fribidi::logic_to_visual_into(&text, ParagraphType::LeftToRight, &mut visual_str[..6], None, None, None),
+            Err(Error::LengthMismatch { name: "visual_str", expected: 7, actual: 6 })
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+
+use widestring::{U32Str, U32String};
+
+use crate::error::Error;
+use crate::input::BidiInput;
+use crate::level::LevelType;
+use crate::paragraph::ParagraphType;
+use crate::Fribidi;
+
+/// A `Fribidi::logic_to_visual` call, with the lists to compute picked up
+/// front.
+///
+/// The lists are allocated by `run` with the length of the input, so they can
+/// never be too short for fribidi to fill.
+///
+/// ```rust
+/// # use fribidi::log2vis::Log2Vis;
+/// # use fribidi::paragraph::ParagraphType;
+/// let result = Log2Vis::new("abc אבג", ParagraphType::LeftToRight)
+///     .with_l2v_map()
+///     .with_levels()
+///     .run()
+///     .unwrap();
+///
+/// assert_eq!(result.visual_str.to_string_lossy(), "abc גבא");
+/// assert_eq!(result.positions_logic_to_visual, Some(vec![0, 1, 2, 3, 6, 5, 4]));
+/// assert_eq!(result.positions_visual_to_logic, None);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct Log2Vis<'a>
+{
+    input_str: Cow<'a, U32Str>,
+    paragraph_direction: ParagraphType,
+    l2v_map: bool,
+    v2l_map: bool,
+    levels: bool,
+}
+
+/// The owned results of `Log2Vis::run`.  The lists that were not asked for
+/// are None.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Log2VisResult
+{
+    pub visual_str: U32String,
+    /// Maximum level found plus one.
+    pub max_level: LevelType,
+    pub positions_logic_to_visual: Option<Vec<i32>>,
+    pub positions_visual_to_logic: Option<Vec<i32>>,
+    pub embedding_levels: Option<Vec<LevelType>>,
+}
+
+impl<'a> Log2Vis<'a>
+{
+    pub fn new<T: BidiInput + ?Sized>(input_str: &'a T, paragraph_direction: ParagraphType) -> Log2Vis<'a>
+    {
+        Log2Vis {
+            input_str: input_str.to_u32str(),
+            paragraph_direction,
+            l2v_map: false,
+            v2l_map: false,
+            levels: false,
+        }
+    }
+
+    /// Compute the logical to visual position map.
+    pub fn with_l2v_map(mut self) -> Self
+    {
+        self.l2v_map = true;
+        self
+    }
+
+    /// Compute the visual to logical position map.
+    pub fn with_v2l_map(mut self) -> Self
+    {
+        self.v2l_map = true;
+        self
+    }
+
+    /// Compute the embedding levels.
+    pub fn with_levels(mut self) -> Self
+    {
+        self.levels = true;
+        self
+    }
+
+    pub fn run(&self) -> Result<Log2VisResult, Error>
+    {
+        let len = self.input_str.len();
+        let mut positions_logic_to_visual = self.l2v_map.then(|| vec![0; len]);
+        let mut positions_visual_to_logic = self.v2l_map.then(|| vec![0; len]);
+        let mut embedding_levels = self.levels.then(|| vec![LevelType(0); len]);
+
+        let (visual_str, max_level) = Fribidi::logic_to_visual(
+            &*self.input_str,
+            self.paragraph_direction,
+            positions_logic_to_visual.as_deref_mut(),
+            positions_visual_to_logic.as_deref_mut(),
+            embedding_levels.as_deref_mut()
+        )?;
+
+        Ok(Log2VisResult {
+            visual_str,
+            max_level: LevelType(max_level),
+            positions_logic_to_visual,
+            positions_visual_to_logic,
+            embedding_levels,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use widestring::U32String;
+
+    use crate::level::LevelType;
+    use crate::log2vis::Log2Vis;
+    use crate::paragraph::ParagraphType;
+
+    #[test]
+    fn test_log2vis_builder()
+    {
+        let text = U32String::from("אבג abc");
+        let result = Log2Vis::new(&text, ParagraphType::OtherNeutral)
+            .with_l2v_map()
+            .with_v2l_map()
+            .with_levels()
+            .run()
+            .unwrap();
+
+        assert_eq!(result.visual_str, U32String::from("abc גבא"));
+        assert_eq!(result.max_level, LevelType(3));
+        assert_eq!(result.positions_logic_to_visual, Some(vec![6, 5, 4, 3, 0, 1, 2]));
+        assert_eq!(result.positions_visual_to_logic, Some(vec![4, 5, 6, 3, 2, 1, 0]));
+        assert_eq!(result.embedding_levels, Some([1, 1, 1, 1, 2, 2, 2].map(LevelType).to_vec()));
+
+        let result = Log2Vis::new(&text, ParagraphType::OtherNeutral).run().unwrap();
+        assert_eq!(result.embedding_levels, None);
+    }
+}
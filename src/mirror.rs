@@ -0,0 +1,137 @@
+use crate::fribidi_bindings;
+
+use widestring::U32Str;
+
+use crate::error::Error;
+use crate::level::LevelType;
+
+/// same as `fribidi_get_mirror_char` - get mirrored character
+///
+/// This function finds the mirrored equivalent of a character as defined in
+/// the file BidiMirroring.txt of the Unicode Character Database available at
+/// http://www.unicode.org/Public/UNIDATA/BidiMirroring.txt.
+///
+/// Returns: the mirrored character if the input character has a mirrored
+/// equivalent, None otherwise.
+///
+pub fn mirror_char(ch: char) -> Option<char>
+{
+    let mut mirrored_ch = ch as u32;
+    let found = unsafe {
+        fribidi_bindings::fribidi_get_mirror_char(ch as u32, &mut mirrored_ch)
+    };
+
+    match found
+    {
+        0 => None,
+        _ => char::from_u32(mirrored_ch)
+    }
+}
+
+/// same as `fribidi_shape_mirroring` - do mirroring shaping
+///
+/// This functions replaces mirroring characters on right-to-left embeddings in
+/// string with their mirrored equivalent as returned by `mirror_char`.
+///
+/// This function implements rule L4 of the Unicode Bidirectional Algorithm
+/// available at http://www.unicode.org/reports/tr9/#L4.
+///
+pub fn shape_mirroring(embedding_levels: &[LevelType], input_str: &mut U32Str) -> Result<(), Error>
+{
+    let len = Error::ffi_len(input_str.len())?;
+    Error::check_len("embedding_levels", input_str.len(), embedding_levels.len())?;
+    Error::check_levels(embedding_levels)?;
+
+    unsafe {
+        fribidi_bindings::fribidi_shape_mirroring(
+            embedding_levels.as_ptr() as *const i8,
+            len,
+            input_str.as_mut_ptr()
+        )
+    };
+
+    Ok(())
+}
+
+/// Iterator over every (character, mirrored character) pair known to fribidi,
+/// in code point order.  Returned by `mirror_pairs`.
+#[derive(Debug, Clone)]
+pub struct MirrorPairs
+{
+    next: u32
+}
+
+impl Iterator for MirrorPairs
+{
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        while self.next <= char::MAX as u32
+        {
+            let ch = char::from_u32(self.next);
+            self.next += 1;
+
+            if let Some(pair) = ch.and_then(|ch| mirror_char(ch).map(|mirrored_ch| (ch, mirrored_ch)))
+            {
+                return Some(pair);
+            }
+        }
+
+        None
+    }
+}
+
+/// Walk the whole Unicode range and yield all the mirror pairs of
+/// BidiMirroring.txt, as compiled into the linked fribidi.  Handy for
+/// generating lookup tables.
+pub fn mirror_pairs() -> MirrorPairs
+{
+    MirrorPairs { next: 0 }
+}
+
+#[cfg(test)]
+mod test
+{
+    use widestring::U32String;
+
+    use crate::error::Error;
+    use crate::level::LevelType;
+    use crate::mirror::{mirror_char, mirror_pairs, shape_mirroring};
+
+    #[test]
+    fn test_mirror_char()
+    {
+        assert_eq!(mirror_char('('), Some(')'));
+        assert_eq!(mirror_char('«'), Some('»'));
+        assert_eq!(mirror_char('a'), None);
+    }
+
+    #[test]
+    fn test_shape_mirroring()
+    {
+        let mut text = U32String::from("(a)[b]");
+        let embedding_levels: Vec<LevelType> = [1, 1, 1, 0, 0, 0]
+            .iter()
+            .map(|&level| LevelType(level))
+            .collect();
+
+        shape_mirroring(&embedding_levels, &mut text).unwrap();
+
+        assert_eq!(text, U32String::from(")a([b]"));
+        assert_eq!(
+            shape_mirroring(&embedding_levels[1..], &mut text),
+            Err(Error::LengthMismatch { name: "embedding_levels", expected: 6, actual: 5 })
+        );
+    }
+
+    #[test]
+    fn test_mirror_pairs()
+    {
+        let pairs: Vec<(char, char)> = mirror_pairs().collect();
+
+        assert_eq!(pairs[0], ('(', ')'));
+        assert!(pairs.contains(&('»', '«')));
+        assert!(pairs.iter().all(|&(ch, mirrored_ch)| ch != mirrored_ch));
+    }
+}
@@ -0,0 +1,196 @@
+//! Arabic joining and shaping, and mirroring, after fribidi-joining.c,
+//! fribidi-arabic.c and fribidi-mirroring.c.
+
+use super::*;
+
+const SENTINEL_LEVEL: FriBidiLevel = -1;
+
+fn level_is_rtl(level: FriBidiLevel) -> bool
+{
+    level & 1 != 0
+}
+
+fn joins_preceding_mask(level: FriBidiLevel) -> u8
+{
+    match level_is_rtl(level)
+    {
+        true => FRIBIDI_MASK_JOINS_RIGHT as u8,
+        false => FRIBIDI_MASK_JOINS_LEFT as u8
+    }
+}
+
+fn joins_following_mask(level: FriBidiLevel) -> u8
+{
+    match level_is_rtl(level)
+    {
+        true => FRIBIDI_MASK_JOINS_LEFT as u8,
+        false => FRIBIDI_MASK_JOINS_RIGHT as u8
+    }
+}
+
+fn is_join_skipped(ar_prop: u8) -> bool
+{
+    ar_prop as u32 & (FRIBIDI_MASK_TRANSPARENT | FRIBIDI_MASK_IGNORED) != 0
+}
+
+fn is_joining_type_g(ar_prop: u8) -> bool
+{
+    ar_prop as u32 & (FRIBIDI_MASK_TRANSPARENT | FRIBIDI_MASK_IGNORED) == FRIBIDI_MASK_IGNORED
+}
+
+/// Work out how the characters join, from their joining types in ar_props.
+pub(super) fn join_arabic(bidi_types: &[FriBidiCharType], embedding_levels: &[FriBidiLevel], ar_props: &mut [u8])
+{
+    // explicit and BN characters join with anything
+    let consistent_level = |i: usize| match bidi_types[i] & (FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_BN)
+    {
+        0 => embedding_levels[i],
+        _ => SENTINEL_LEVEL
+    };
+    let levels_match = |a: FriBidiLevel, b: FriBidiLevel| a == b || a == SENTINEL_LEVEL || b == SENTINEL_LEVEL;
+
+    let mut saved = 0;
+    let mut saved_level = SENTINEL_LEVEL;
+    let mut saved_shapes = false;
+    let mut saved_joins_following_mask = 0;
+    let mut joins = false;
+
+    for i in 0..ar_props.len()
+    {
+        if is_joining_type_g(ar_props[i])
+        {
+            continue;
+        }
+
+        let mut disjoin = false;
+        let shapes = ar_props[i] as u32 & FRIBIDI_MASK_ARAB_SHAPES != 0;
+        let level = consistent_level(i);
+
+        if joins && !levels_match(saved_level, level)
+        {
+            disjoin = true;
+            joins = false;
+        }
+
+        if !is_join_skipped(ar_props[i])
+        {
+            let joins_preceding_mask = joins_preceding_mask(level);
+
+            if !joins
+            {
+                if shapes
+                {
+                    ar_props[i] &= !joins_preceding_mask;
+                }
+            }
+            else if ar_props[i] & joins_preceding_mask == 0
+            {
+                disjoin = true;
+            }
+            else
+            {
+                // let the skipped characters in between join too, so that
+                // marks can sit on a tatweel
+                for ar_prop in ar_props[saved + 1..i].iter_mut()
+                {
+                    *ar_prop |= joins_preceding_mask | saved_joins_following_mask;
+                }
+            }
+        }
+
+        if disjoin && saved_shapes
+        {
+            ar_props[saved] &= !saved_joins_following_mask;
+        }
+
+        if !is_join_skipped(ar_props[i])
+        {
+            saved = i;
+            saved_level = level;
+            saved_shapes = shapes;
+            saved_joins_following_mask = joins_following_mask(level);
+            joins = ar_props[i] & saved_joins_following_mask == saved_joins_following_mask;
+        }
+    }
+
+    if joins && saved_shapes
+    {
+        ar_props[saved] &= !saved_joins_following_mask;
+    }
+}
+
+/// Replace the characters by the presentation form of their joining.
+fn shape_joining(table: &[(u32, [u32; 4])], ar_props: &[u8], str: &mut [FriBidiChar])
+{
+    for (ch, &ar_prop) in str.iter_mut().zip(ar_props)
+    {
+        if ar_prop as u32 & FRIBIDI_MASK_ARAB_SHAPES != 0
+        {
+            let shape = (ar_prop as u32 & (FRIBIDI_MASK_JOINS_RIGHT | FRIBIDI_MASK_JOINS_LEFT)) as usize;
+            if let Some(forms) = pair_value(table, *ch)
+            {
+                *ch = forms[shape];
+            }
+        }
+    }
+}
+
+/// Replace the pairs of RTL characters of a ligature by FILL and the
+/// ligature.
+fn shape_ligature(
+    table: &[(u32, u32, u32)],
+    embedding_levels: &[FriBidiLevel],
+    ar_props: &mut [u8],
+    str: &mut [FriBidiChar],
+)
+{
+    for i in 0..str.len().saturating_sub(1)
+    {
+        if !level_is_rtl(embedding_levels[i]) || embedding_levels[i] != embedding_levels[i + 1]
+        {
+            continue;
+        }
+
+        let (first, second) = (str[i], str[i + 1]);
+        if let Ok(index) = table.binary_search_by_key(&(first, second), |&(first, second, _)| (first, second))
+        {
+            str[i] = FRIBIDI_CHAR_FILL;
+            ar_props[i] |= FRIBIDI_MASK_LIGATURED as u8;
+            str[i + 1] = table[index].2;
+        }
+    }
+}
+
+pub(super) fn shape_arabic(flags: FriBidiFlags, embedding_levels: &[FriBidiLevel], ar_props: &mut [u8], str: &mut [FriBidiChar])
+{
+    if flags & FRIBIDI_FLAG_SHAPE_ARAB_PRES != 0
+    {
+        shape_joining(&tables::ARABIC_SHAPING_PRES, ar_props, str);
+    }
+
+    if flags & FRIBIDI_FLAG_SHAPE_ARAB_LIGA != 0
+    {
+        shape_ligature(&tables::LIGATURES_MANDATORY, embedding_levels, ar_props, str);
+    }
+
+    if flags & FRIBIDI_FLAG_SHAPE_ARAB_CONSOLE != 0
+    {
+        shape_ligature(&tables::LIGATURES_CONSOLE, embedding_levels, ar_props, str);
+        shape_joining(&tables::ARABIC_SHAPING_NSM, ar_props, str);
+    }
+}
+
+/// Replace the characters at RTL levels by their mirror.
+pub(super) fn shape_mirroring(embedding_levels: &[FriBidiLevel], str: &mut [FriBidiChar])
+{
+    for (ch, &level) in str.iter_mut().zip(embedding_levels)
+    {
+        if level_is_rtl(level)
+        {
+            if let Some(mirror) = pair_value(&tables::MIRRORS, *ch)
+            {
+                *ch = mirror;
+            }
+        }
+    }
+}
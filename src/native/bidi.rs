@@ -0,0 +1,1045 @@
+//! The Unicode Bidirectional Algorithm, after fribidi-bidi.c.
+//!
+//! As in fribidi the paragraph is resolved as a doubly linked list of runs of
+//! characters sharing type and level, here kept in a `Vec` and linked by
+//! index.  Runs are never freed, merged runs just leave the list.
+
+use std::cmp::max;
+use std::ops::Range;
+
+use super::*;
+
+const TYPE_LTR: u32 = FriBidiCharType_FRIBIDI_TYPE_LTR;
+const TYPE_RTL: u32 = FriBidiCharType_FRIBIDI_TYPE_RTL;
+const TYPE_AL: u32 = FriBidiCharType_FRIBIDI_TYPE_AL;
+const TYPE_EN: u32 = FriBidiCharType_FRIBIDI_TYPE_EN;
+const TYPE_AN: u32 = FriBidiCharType_FRIBIDI_TYPE_AN;
+const TYPE_ES: u32 = FriBidiCharType_FRIBIDI_TYPE_ES;
+const TYPE_ET: u32 = FriBidiCharType_FRIBIDI_TYPE_ET;
+const TYPE_CS: u32 = FriBidiCharType_FRIBIDI_TYPE_CS;
+const TYPE_NSM: u32 = FriBidiCharType_FRIBIDI_TYPE_NSM;
+const TYPE_BS: u32 = FriBidiCharType_FRIBIDI_TYPE_BS;
+const TYPE_ON: u32 = FriBidiCharType_FRIBIDI_TYPE_ON;
+const TYPE_PDF: u32 = FriBidiCharType_FRIBIDI_TYPE_PDF;
+const TYPE_LRI: u32 = FriBidiCharType_FRIBIDI_TYPE_LRI;
+const TYPE_RLI: u32 = FriBidiCharType_FRIBIDI_TYPE_RLI;
+const TYPE_PDI: u32 = FriBidiCharType_FRIBIDI_TYPE_PDI;
+
+const MAX_EXPLICIT_LEVEL: i32 = FRIBIDI_BIDI_MAX_EXPLICIT_LEVEL as i32;
+const MAX_RESOLVED_LEVELS: usize = FRIBIDI_BIDI_MAX_EXPLICIT_LEVEL as usize + 2;
+const MAX_NESTED_BRACKET_PAIRS: usize = FRIBIDI_BIDI_MAX_NESTED_BRACKET_PAIRS as usize;
+/// The level of the list sentinels and of the removed explicit runs.
+const SENTINEL_LEVEL: i32 = -1;
+
+/// The sentinel heading the run list.
+const HEAD: usize = 0;
+/// The sentinel standing for what lies outside the paragraph.
+const OUTSIDE: usize = 1;
+
+fn is_strong(t: u32) -> bool
+{
+    t & FRIBIDI_MASK_STRONG != 0
+}
+
+fn is_neutral(t: u32) -> bool
+{
+    t & FRIBIDI_MASK_NEUTRAL != 0
+}
+
+fn is_letter(t: u32) -> bool
+{
+    t & FRIBIDI_MASK_LETTER != 0
+}
+
+fn is_number(t: u32) -> bool
+{
+    t & FRIBIDI_MASK_NUMBER != 0
+}
+
+fn is_isolate(t: u32) -> bool
+{
+    t & FRIBIDI_MASK_ISOLATE != 0
+}
+
+fn is_explicit_or_bn(t: u32) -> bool
+{
+    t & (FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_BN) != 0
+}
+
+fn dir_to_level(t: u32) -> i32
+{
+    (t & 1) as i32
+}
+
+fn level_to_dir(level: i32) -> u32
+{
+    match level & 1
+    {
+        0 => TYPE_LTR,
+        _ => TYPE_RTL
+    }
+}
+
+fn level_is_rtl(level: i32) -> i32
+{
+    level & 1
+}
+
+fn explicit_to_override_dir(t: u32) -> u32
+{
+    match t & FRIBIDI_MASK_OVERRIDE
+    {
+        0 => TYPE_ON,
+        _ => level_to_dir(dir_to_level(t))
+    }
+}
+
+/// AN and EN count as RTL.
+fn number_as_rtl(t: u32) -> u32
+{
+    match is_number(t)
+    {
+        true => TYPE_RTL,
+        false => t
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Run
+{
+    pos: usize,
+    len: usize,
+    char_type: u32,
+    level: i32,
+    isolate_level: i32,
+    bracket_type: u32,
+    prev: usize,
+    next: usize,
+    prev_isolate: Option<usize>,
+    next_isolate: Option<usize>,
+}
+
+impl Run
+{
+    fn new(pos: usize, char_type: u32, bracket_type: u32) -> Run
+    {
+        Run {
+            pos,
+            len: 1,
+            char_type,
+            level: 0,
+            isolate_level: 0,
+            bracket_type,
+            prev: HEAD,
+            next: HEAD,
+            prev_isolate: None,
+            next_isolate: None,
+        }
+    }
+
+    fn sentinel() -> Run
+    {
+        Run { level: SENTINEL_LEVEL, isolate_level: SENTINEL_LEVEL, ..Run::new(0, FRIBIDI_TYPE_SENTINEL, 0) }
+    }
+}
+
+struct RunList
+{
+    runs: Vec<Run>,
+}
+
+impl RunList
+{
+    /// Runs of the characters of the same type; brackets and isolate
+    /// initiators and terminators get a run of their own.
+    fn encode(bidi_types: &[u32], bracket_types: Option<&[u32]>) -> RunList
+    {
+        let mut list = RunList { runs: vec![Run::sentinel(), Run::sentinel()] };
+
+        for (pos, &char_type) in bidi_types.iter().enumerate()
+        {
+            let bracket_type = bracket_types.map_or(0, |bracket_types| bracket_types[pos]);
+            let last = list.runs[HEAD].prev;
+
+            if last != HEAD
+                && list.runs[last].char_type == char_type
+                && bracket_type == 0
+                && list.runs[last].bracket_type == 0
+                && !is_isolate(char_type)
+            {
+                list.runs[last].len += 1;
+                continue;
+            }
+
+            let run = list.runs.len();
+            list.runs.push(Run { prev: last, ..Run::new(pos, char_type, bracket_type) });
+            list.runs[last].next = run;
+            list.runs[HEAD].prev = run;
+        }
+
+        list
+    }
+
+    fn first(&self) -> usize
+    {
+        self.runs[HEAD].next
+    }
+
+    fn unlink(&mut self, run: usize)
+    {
+        let Run { prev, next, .. } = self.runs[run];
+        self.runs[prev].next = next;
+        self.runs[next].prev = prev;
+    }
+
+    /// Merge run into the one before it, and return that one.
+    fn merge_with_prev(&mut self, second: usize) -> usize
+    {
+        let first = self.runs[second].prev;
+        self.unlink(second);
+        self.runs[first].len += self.runs[second].len;
+
+        if let Some(next_isolate) = self.runs[second].next_isolate
+        {
+            self.runs[next_isolate].prev_isolate = Some(first);
+        }
+        self.runs[first].next_isolate = self.runs[second].next_isolate;
+
+        first
+    }
+
+    /// Merge the neighbouring runs of the same type and level; with
+    /// neutrals, the neighbouring neutral runs of the same level.
+    fn compact(&mut self, neutrals: bool)
+    {
+        let mut run = self.first();
+        while self.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+        {
+            let prev = &self.runs[self.runs[run].prev];
+            let this = &self.runs[run];
+
+            if prev.level == this.level
+                && prev.bracket_type == 0
+                && this.bracket_type == 0
+                && (prev.char_type == this.char_type
+                    || (neutrals && is_neutral(prev.char_type) && is_neutral(this.char_type)))
+            {
+                run = self.merge_with_prev(run);
+            }
+            run = self.runs[run].next;
+        }
+    }
+
+    /// The run before or after run at its isolate level, skipping the
+    /// deeper isolates and, forward, the isolate terminators.  OUTSIDE
+    /// past the ends of the paragraph.
+    fn adjacent(&self, run: usize, forward: bool) -> usize
+    {
+        let link = |run: usize| match forward
+        {
+            true => self.runs[run].next_isolate,
+            false => self.runs[run].prev_isolate
+        };
+
+        let mut adjacent = link(run).unwrap_or(OUTSIDE);
+        while self.runs[adjacent].char_type != FRIBIDI_TYPE_SENTINEL
+            && (self.runs[adjacent].isolate_level > self.runs[run].isolate_level
+                || (forward && self.runs[adjacent].char_type == TYPE_PDI))
+        {
+            adjacent = link(adjacent).unwrap_or(OUTSIDE);
+        }
+
+        adjacent
+    }
+
+    /// The type of the adjacent run, or the direction of the higher of both
+    /// levels when they differ.
+    fn type_or_sor(&self, run: usize, adjacent: usize) -> u32
+    {
+        let (run, adjacent) = (&self.runs[run], &self.runs[adjacent]);
+        match adjacent.level == run.level
+        {
+            true => adjacent.char_type,
+            false => level_to_dir(max(adjacent.level, run.level))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Status
+{
+    level: i32,
+    override_dir: u32,
+    isolate: bool,
+    isolate_level: i32,
+}
+
+/// The directional status stack of X1 to X8.
+struct Explicit
+{
+    level: i32,
+    override_dir: u32,
+    isolate: bool,
+    isolate_level: i32,
+    new_level: i32,
+    new_override: u32,
+    stack: Vec<Status>,
+    over_pushed: i32,
+    first_interval: i32,
+    isolate_overflow: i32,
+}
+
+impl Explicit
+{
+    fn push(&mut self)
+    {
+        if self.over_pushed == 0 && self.isolate_overflow == 0 && self.new_level <= MAX_EXPLICIT_LEVEL
+        {
+            if self.level == MAX_EXPLICIT_LEVEL - 1
+            {
+                self.first_interval = self.over_pushed;
+            }
+            self.stack.push(Status {
+                level: self.level,
+                override_dir: self.override_dir,
+                isolate: self.isolate,
+                isolate_level: self.isolate_level,
+            });
+            self.level = self.new_level;
+            self.override_dir = self.new_override;
+        }
+        else if self.isolate_overflow == 0
+        {
+            self.over_pushed += 1;
+        }
+    }
+
+    fn pop(&mut self)
+    {
+        if self.stack.is_empty()
+        {
+            return;
+        }
+
+        if self.over_pushed > self.first_interval
+        {
+            self.over_pushed -= 1;
+        }
+        else
+        {
+            if self.over_pushed == self.first_interval
+            {
+                self.first_interval = 0;
+            }
+            if let Some(status) = self.stack.pop()
+            {
+                self.level = status.level;
+                self.override_dir = status.override_dir;
+                self.isolate = status.isolate;
+                self.isolate_level = status.isolate_level;
+            }
+        }
+    }
+}
+
+/// The direction of the first letter, isolates included as fribidi 1.0.8
+/// does.
+pub(super) fn get_par_direction(bidi_types: &[u32]) -> FriBidiParType
+{
+    match bidi_types.iter().find(|&&t| is_letter(t))
+    {
+        Some(&t) => level_to_dir(dir_to_level(t)),
+        None => FriBidiParType_FRIBIDI_PAR_ON
+    }
+}
+
+/// Resolve the embedding levels of a paragraph, return the highest level
+/// plus one.
+pub(super) fn get_par_embedding_levels(
+    bidi_types: &[u32],
+    bracket_types: Option<&[u32]>,
+    pbase_dir: &mut FriBidiParType,
+    embedding_levels: &mut [FriBidiLevel],
+) -> FriBidiLevel
+{
+    if bidi_types.is_empty()
+    {
+        return 1;
+    }
+
+    let mut list = RunList::encode(bidi_types, bracket_types);
+
+    // P2. P3. Search for the first strong character outside isolates and
+    // use its direction as the base direction.
+    let mut base_level = dir_to_level(*pbase_dir);
+    if !is_strong(*pbase_dir)
+    {
+        let mut valid_isolate_count = 0;
+        let mut run = list.first();
+        while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+        {
+            let t = list.runs[run].char_type;
+            if t == TYPE_PDI
+            {
+                if valid_isolate_count > 0
+                {
+                    valid_isolate_count -= 1;
+                }
+            }
+            else if is_isolate(t)
+            {
+                valid_isolate_count += 1;
+            }
+            else if valid_isolate_count == 0 && is_letter(t)
+            {
+                base_level = dir_to_level(t);
+                *pbase_dir = level_to_dir(base_level);
+                break;
+            }
+            run = list.runs[run].next;
+        }
+    }
+    let base_dir = level_to_dir(base_level);
+
+    // X1. Begin by setting the current embedding level to the paragraph
+    // embedding level.
+    let mut explicit = Explicit {
+        level: base_level,
+        override_dir: TYPE_ON,
+        isolate: false,
+        isolate_level: 0,
+        new_level: 0,
+        new_override: TYPE_ON,
+        stack: Vec::with_capacity(MAX_RESOLVED_LEVELS),
+        over_pushed: 0,
+        first_interval: 0,
+        isolate_overflow: 0,
+    };
+    let mut valid_isolate_count = 0;
+    let mut removed = vec![false; bidi_types.len()];
+
+    // X2. - X9.
+    let mut run = list.first();
+    while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+    {
+        let next = list.runs[run].next;
+        let this_type = list.runs[run].char_type;
+        list.runs[run].isolate_level = explicit.isolate_level;
+
+        if is_explicit_or_bn(this_type)
+        {
+            if is_strong(this_type)
+            {
+                // LRE, RLE, LRO, RLO
+                explicit.new_override = explicit_to_override_dir(this_type);
+                for _ in 0..list.runs[run].len
+                {
+                    explicit.new_level =
+                        ((explicit.level + dir_to_level(this_type) + 2) & !1) - dir_to_level(this_type);
+                    explicit.isolate = false;
+                    explicit.push();
+                }
+            }
+            else if this_type == TYPE_PDF
+            {
+                for _ in 0..list.runs[run].len
+                {
+                    if explicit.stack.last().is_some_and(|status| status.isolate)
+                    {
+                        break;
+                    }
+                    explicit.pop();
+                }
+            }
+
+            // X9. Remove all RLE, LRE, RLO, LRO, PDF, and BN codes.
+            list.runs[run].level = SENTINEL_LEVEL;
+            let Run { pos, len, .. } = list.runs[run];
+            removed[pos..pos + len].fill(true);
+            list.unlink(run);
+        }
+        else if this_type == TYPE_PDI
+        {
+            if explicit.isolate_overflow > 0
+            {
+                explicit.isolate_overflow -= 1;
+                list.runs[run].level = explicit.level;
+            }
+            else if valid_isolate_count > 0
+            {
+                // pop the embeddings of the isolate, then the isolate
+                while explicit.stack.last().is_some_and(|status| !status.isolate)
+                {
+                    explicit.pop();
+                }
+                explicit.over_pushed = 0;
+                explicit.pop();
+                explicit.isolate_level -= 1;
+                valid_isolate_count -= 1;
+                list.runs[run].level = explicit.level;
+                list.runs[run].isolate_level = explicit.isolate_level;
+            }
+            else
+            {
+                // an unmatched PDI is just a neutral
+                list.runs[run].char_type = TYPE_ON;
+                list.runs[run].level = explicit.level;
+            }
+        }
+        else if is_isolate(this_type)
+        {
+            explicit.new_override = TYPE_ON;
+            explicit.isolate = true;
+
+            let level = explicit.level;
+            explicit.new_level = match this_type
+            {
+                TYPE_LRI => level + 2 - (level % 2),
+                TYPE_RLI => level + 1 + (level % 2),
+                _ =>
+                {
+                    // FSI takes the direction of its first strong character
+                    let mut isolate_count = 0;
+                    let mut fsi_base_level = 0;
+                    let mut fsi_run = list.runs[run].next;
+                    while list.runs[fsi_run].char_type != FRIBIDI_TYPE_SENTINEL
+                    {
+                        let t = list.runs[fsi_run].char_type;
+                        if t == TYPE_PDI
+                        {
+                            isolate_count -= 1;
+                            if valid_isolate_count < 0
+                            {
+                                break;
+                            }
+                        }
+                        else if is_isolate(t)
+                        {
+                            isolate_count += 1;
+                        }
+                        else if isolate_count == 0 && is_letter(t)
+                        {
+                            fsi_base_level = dir_to_level(t);
+                            break;
+                        }
+                        fsi_run = list.runs[fsi_run].next;
+                    }
+
+                    match fsi_base_level
+                    {
+                        0 => level + 2 - (level % 2),
+                        _ => level + 1 + (level % 2)
+                    }
+                }
+            };
+
+            list.runs[run].level = explicit.level;
+            list.runs[run].isolate_level = explicit.isolate_level;
+            if explicit.isolate_level < MAX_EXPLICIT_LEVEL - 1
+            {
+                explicit.isolate_level += 1;
+            }
+            if !is_neutral(explicit.override_dir)
+            {
+                list.runs[run].char_type = explicit.override_dir;
+            }
+
+            if explicit.new_level <= MAX_EXPLICIT_LEVEL
+            {
+                valid_isolate_count += 1;
+                explicit.push();
+                explicit.level = explicit.new_level;
+            }
+            else
+            {
+                explicit.isolate_overflow += 1;
+            }
+        }
+        else if this_type == TYPE_BS
+        {
+            break;
+        }
+        else
+        {
+            list.runs[run].level = explicit.level;
+            if !is_neutral(explicit.override_dir)
+            {
+                list.runs[run].char_type = explicit.override_dir;
+            }
+        }
+
+        run = next;
+    }
+
+    // Link the runs of every isolate level.
+    {
+        let mut last_at_level: [Option<usize>; MAX_RESOLVED_LEVELS] = [None; MAX_RESOLVED_LEVELS];
+        let mut run = list.first();
+        while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+        {
+            let isolate_level = list.runs[run].isolate_level as usize;
+            if let Some(last) = last_at_level[isolate_level]
+            {
+                list.runs[last].next_isolate = Some(run);
+                list.runs[run].prev_isolate = Some(last);
+            }
+            last_at_level[isolate_level] = Some(run);
+            run = list.runs[run].next;
+        }
+    }
+
+    // X10. The remaining rules are applied to each run of characters at the
+    // same level.
+    list.compact(false);
+
+    // W1. - W2.
+    // Only the paragraph level starts with a strong type, as in fribidi an
+    // isolate only gets one from a run before it.
+    let mut last_strong_stack = [0; MAX_RESOLVED_LEVELS];
+    last_strong_stack[0] = base_dir;
+    let mut max_isolate_level = 0;
+    let mut run = list.first();
+    while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+    {
+        let prev_run = list.adjacent(run, false);
+        let next_run = list.adjacent(run, true);
+        let this_type = list.runs[run].char_type;
+        let isolate_level = list.runs[run].isolate_level as usize;
+        max_isolate_level = max(max_isolate_level, isolate_level);
+
+        let prev_type = list.type_or_sor(run, prev_run);
+        let next_type = list.type_or_sor(run, next_run);
+
+        if is_strong(prev_type)
+        {
+            last_strong_stack[isolate_level] = prev_type;
+        }
+
+        // W1. NSM takes the type of the previous character, ON after an
+        // isolate initiator or terminator.
+        if this_type == TYPE_NSM
+        {
+            if is_isolate(list.runs[list.runs[run].prev].char_type)
+            {
+                list.runs[run].char_type = TYPE_ON;
+            }
+
+            if list.runs[prev_run].level == list.runs[run].level
+            {
+                if prev_run == list.runs[run].prev
+                {
+                    run = list.merge_with_prev(run);
+                }
+            }
+            else
+            {
+                list.runs[run].char_type = prev_type;
+            }
+
+            let next = list.runs[run].next;
+            if prev_type == next_type && list.runs[run].level == list.runs[next].level && next_run == next
+            {
+                run = list.merge_with_prev(next);
+            }
+
+            run = list.runs[run].next;
+            continue;
+        }
+
+        // W2. EN after AL is AN.
+        if this_type == TYPE_EN && last_strong_stack[isolate_level] == TYPE_AL
+        {
+            list.runs[run].char_type = TYPE_AN;
+
+            // the following NSM will take AN too
+            if next_type == TYPE_NSM
+            {
+                list.runs[next_run].char_type = TYPE_AN;
+            }
+        }
+
+        run = list.runs[run].next;
+    }
+
+    // W3. - W7.
+    last_strong_stack[0] = base_dir;
+    let mut w4 = true;
+    let mut prev_type_orig = TYPE_ON;
+    let mut run = list.first();
+    while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+    {
+        let prev_run = list.adjacent(run, false);
+        let next_run = list.adjacent(run, true);
+        let this_type = list.runs[run].char_type;
+        let isolate_level = list.runs[run].isolate_level as usize;
+
+        let prev_type = list.type_or_sor(run, prev_run);
+        let next_type = list.type_or_sor(run, next_run);
+
+        if is_strong(prev_type)
+        {
+            last_strong_stack[isolate_level] = prev_type;
+        }
+
+        // W3. AL is R.
+        if this_type == TYPE_AL
+        {
+            list.runs[run].char_type = TYPE_RTL;
+            w4 = true;
+            prev_type_orig = TYPE_ON;
+            run = list.runs[run].next;
+            continue;
+        }
+
+        // W4. A single ES between two EN is EN, a single CS between two
+        // numbers of the same type is of that type.
+        if w4
+            && list.runs[run].len == 1
+            && (this_type == TYPE_ES || this_type == TYPE_CS)
+            && prev_type_orig & FRIBIDI_MASK_NUMBER != 0
+            && prev_type_orig == next_type
+            && (prev_type_orig == TYPE_EN || this_type == TYPE_CS)
+        {
+            list.runs[run].char_type = prev_type;
+            w4 = false;
+            prev_type_orig = list.type_or_sor(list.runs[run].next, run);
+            run = list.runs[run].next;
+            continue;
+        }
+        w4 = true;
+
+        // W5. ET next to EN is EN.
+        if this_type == TYPE_ET && (prev_type_orig == TYPE_EN || next_type == TYPE_EN)
+        {
+            list.runs[run].char_type = TYPE_EN;
+            w4 = false;
+        }
+
+        // W6. Otherwise separators and terminators are ON.
+        if list.runs[run].char_type & FRIBIDI_MASK_NUMSEPTER != 0
+        {
+            list.runs[run].char_type = TYPE_ON;
+        }
+
+        // W7. EN after L is L.
+        if list.runs[run].char_type == TYPE_EN && last_strong_stack[isolate_level] == TYPE_LTR
+        {
+            list.runs[run].char_type = TYPE_LTR;
+            let next = list.runs[run].next;
+            prev_type_orig = match list.runs[run].level == list.runs[next].level
+            {
+                true => TYPE_EN,
+                false => TYPE_ON
+            };
+        }
+        else
+        {
+            prev_type_orig = list.type_or_sor(list.runs[run].next, run);
+        }
+
+        run = list.runs[run].next;
+    }
+
+    list.compact(true);
+
+    // N0. Paired brackets, BD16 finds the pairs.
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    {
+        let mut bracket_stacks: Vec<Vec<usize>> = vec![Vec::new(); max_isolate_level + 1];
+        let mut last_level = list.runs[list.first()].level;
+        let mut last_isolate_level = list.runs[list.first()].isolate_level;
+
+        let mut run = list.first();
+        'runs: while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+        {
+            let Run { level, isolate_level, bracket_type, .. } = list.runs[run];
+            let stack = &mut bracket_stacks[isolate_level as usize];
+
+            // a new level run starts at this isolate level
+            if level != last_level && last_isolate_level == isolate_level
+            {
+                stack.clear();
+            }
+
+            if bracket_type != 0 && list.runs[run].char_type == TYPE_ON
+            {
+                if bracket_type & FRIBIDI_BRACKET_OPEN_MASK != 0
+                {
+                    if stack.len() == MAX_NESTED_BRACKET_PAIRS
+                    {
+                        break 'runs;
+                    }
+                    stack.push(run);
+                }
+                else
+                {
+                    let id = bracket_type & FRIBIDI_BRACKET_ID_MASK;
+                    if let Some(index) = stack
+                        .iter()
+                        .rposition(|&open| list.runs[open].bracket_type & FRIBIDI_BRACKET_ID_MASK == id)
+                    {
+                        pairs.push((stack[index], run));
+                        stack.truncate(index);
+                    }
+                }
+            }
+
+            last_level = level;
+            last_isolate_level = isolate_level;
+            run = list.runs[run].next;
+        }
+    }
+    pairs.sort_by_key(|&(open, _)| list.runs[open].pos);
+
+    for &(open, close) in pairs.iter()
+    {
+        let embedding_level = list.runs[open].level;
+        let isolate_level = list.runs[open].isolate_level;
+        let strong_level = |list: &RunList, run: usize| {
+            let Run { char_type, level, .. } = list.runs[run];
+            let this_type = number_as_rtl(char_type);
+            (this_type, level + (level_is_rtl(level) ^ dir_to_level(this_type)))
+        };
+        let mut found = false;
+
+        // N0b. A strong type of the embedding direction inside the brackets.
+        let mut run = open;
+        while run != close
+        {
+            let (this_type, this_level) = strong_level(&list, run);
+            if is_strong(this_type) && this_level == embedding_level
+            {
+                let direction = level_to_dir(this_level);
+                list.runs[open].char_type = direction;
+                list.runs[close].char_type = direction;
+                found = true;
+                break;
+            }
+            run = list.runs[run].next;
+        }
+
+        // N0c. Any other strong type inside the brackets, take the direction
+        // of the strong type before them.
+        if !found
+        {
+            let mut prec_strong_level = embedding_level;
+            let mut run = list.runs[open].prev;
+            while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+            {
+                let (this_type, this_level) = strong_level(&list, run);
+                if is_strong(this_type) && list.runs[run].isolate_level == isolate_level
+                {
+                    prec_strong_level = this_level;
+                    break;
+                }
+                run = list.runs[run].prev;
+            }
+
+            let mut run = open;
+            while run != close
+            {
+                let (this_type, _) = strong_level(&list, run);
+                if is_strong(this_type) && list.runs[run].isolate_level == isolate_level
+                {
+                    let direction = level_to_dir(prec_strong_level);
+                    list.runs[open].char_type = direction;
+                    list.runs[close].char_type = direction;
+                    break;
+                }
+                run = list.runs[run].next;
+            }
+        }
+    }
+
+    // the brackets are resolved, let them merge
+    for run in list.runs.iter_mut()
+    {
+        run.bracket_type = 0;
+    }
+    list.compact(true);
+
+    // N1. - N2.
+    let mut run = list.first();
+    while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+    {
+        let this_type = list.runs[run].char_type;
+        if is_neutral(this_type)
+        {
+            let prev_type = number_as_rtl(list.type_or_sor(run, list.adjacent(run, false)));
+            let next_type = number_as_rtl(list.type_or_sor(run, list.adjacent(run, true)));
+
+            list.runs[run].char_type = match prev_type == next_type
+            {
+                true => prev_type,
+                false => level_to_dir(list.runs[run].level)
+            };
+        }
+        run = list.runs[run].next;
+    }
+    list.compact(false);
+
+    // I1. - I2.
+    let mut max_level = base_level;
+    let mut run = list.first();
+    while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+    {
+        let Run { char_type, level, .. } = list.runs[run];
+        let level = match is_number(char_type)
+        {
+            true => (level + 2) & !1,
+            false => level + (level_is_rtl(level) ^ dir_to_level(char_type))
+        };
+        list.runs[run].level = level;
+        max_level = max(max_level, level);
+        run = list.runs[run].next;
+    }
+    list.compact(false);
+
+    // The runs left cover the characters X9 did not remove, in order; the
+    // removed ones take the level of the character before them.
+    {
+        let mut levels = Vec::with_capacity(bidi_types.len());
+        let mut run = list.first();
+        while list.runs[run].char_type != FRIBIDI_TYPE_SENTINEL
+        {
+            levels.extend(std::iter::repeat_n(list.runs[run].level, list.runs[run].len));
+            run = list.runs[run].next;
+        }
+
+        let mut levels = levels.into_iter();
+        let mut previous = base_level;
+        for (level, &removed) in embedding_levels.iter_mut().zip(removed.iter())
+        {
+            if !removed
+            {
+                previous = levels.next().unwrap_or(base_level);
+            }
+            *level = previous as FriBidiLevel;
+        }
+    }
+
+    // L1. Reset the separators, and the whitespace, explicit and isolate
+    // characters before them and at the end of the line, to the paragraph
+    // level.
+    {
+        let mut in_sequence = true;
+        let mut end = bidi_types.len();
+        for j in (0..=bidi_types.len()).rev()
+        {
+            let char_type = match j
+            {
+                0 => TYPE_ON,
+                _ => bidi_types[j - 1]
+            };
+
+            if !in_sequence && char_type & FRIBIDI_MASK_SEPARATOR != 0
+            {
+                in_sequence = true;
+                end = j;
+            }
+            else if in_sequence
+                && char_type
+                    & (FRIBIDI_MASK_EXPLICIT
+                        | FRIBIDI_MASK_SEPARATOR
+                        | FRIBIDI_MASK_BN
+                        | FRIBIDI_MASK_WS
+                        | FRIBIDI_MASK_ISOLATE)
+                    == 0
+            {
+                in_sequence = false;
+                embedding_levels[j..end].fill(base_level as FriBidiLevel);
+            }
+        }
+    }
+
+    (max_level + 1) as FriBidiLevel
+}
+
+/// Reorder the characters of line to visual order, return the highest level
+/// plus one.
+pub(super) fn reorder_line(
+    flags: FriBidiFlags,
+    bidi_types: &[u32],
+    line: Range<usize>,
+    base_dir: FriBidiParType,
+    embedding_levels: &mut [FriBidiLevel],
+    mut visual_str: Option<&mut [FriBidiChar]>,
+    mut map: Option<&mut [FriBidiStrIndex]>,
+) -> FriBidiLevel
+{
+    if line.is_empty()
+    {
+        return 1;
+    }
+
+    let mut reverse = |range: Range<usize>| {
+        if let Some(visual_str) = visual_str.as_deref_mut()
+        {
+            visual_str[range.clone()].reverse();
+        }
+        if let Some(map) = map.as_deref_mut()
+        {
+            map[range].reverse();
+        }
+    };
+
+    // L1. Reset the whitespace at the end of the line.
+    for i in line.clone().rev()
+    {
+        if bidi_types[i] & (FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_BN | FRIBIDI_MASK_WS) == 0
+        {
+            break;
+        }
+        embedding_levels[i] = dir_to_level(base_dir) as FriBidiLevel;
+    }
+
+    // L3. Keep the NSMs after their base character.
+    if flags & FRIBIDI_FLAG_REORDER_NSM != 0
+    {
+        let mut i = line.end;
+        while i > line.start
+        {
+            i -= 1;
+            let level = embedding_levels[i];
+            if level_is_rtl(level as i32) != 0 && bidi_types[i] == TYPE_NSM
+            {
+                let seq_end = i;
+                while i > line.start
+                    && (is_explicit_or_bn(bidi_types[i - 1]) || bidi_types[i - 1] == TYPE_NSM)
+                    && embedding_levels[i - 1] == level
+                {
+                    i -= 1;
+                }
+                if i > line.start && embedding_levels[i - 1] == level
+                {
+                    i -= 1;
+                }
+                reverse(i..seq_end + 1);
+            }
+        }
+    }
+
+    let max_level = embedding_levels[line.clone()].iter().copied().max().unwrap_or(0).max(0);
+
+    // L2. Reverse every sequence at a level or higher, from the highest
+    // level down to the lowest odd one.
+    for level in (1..=max_level).rev()
+    {
+        let mut i = line.end;
+        while i > line.start
+        {
+            i -= 1;
+            if embedding_levels[i] >= level
+            {
+                let seq_end = i + 1;
+                while i > line.start && embedding_levels[i - 1] >= level
+                {
+                    i -= 1;
+                }
+                reverse(i..seq_end);
+            }
+        }
+    }
+
+    max_level + 1
+}
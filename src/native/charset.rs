@@ -0,0 +1,207 @@
+//! Character set conversions, after the fribidi-char-sets*.c files.
+
+use super::*;
+
+const CAP_RTL_ESCAPES: [(u8, FriBidiChar); 12] = [
+    (b'>', FRIBIDI_CHAR_LRM),
+    (b'<', FRIBIDI_CHAR_RLM),
+    (b'l', FRIBIDI_CHAR_LRE),
+    (b'r', FRIBIDI_CHAR_RLE),
+    (b'L', FRIBIDI_CHAR_LRO),
+    (b'R', FRIBIDI_CHAR_RLO),
+    (b'o', FRIBIDI_CHAR_PDF),
+    (b'i', FRIBIDI_CHAR_LRI),
+    (b'y', FRIBIDI_CHAR_RLI),
+    (b'f', FRIBIDI_CHAR_FSI),
+    (b'I', FRIBIDI_CHAR_PDI),
+    (b'_', b'_' as FriBidiChar),
+];
+
+/// The decoding and encoding tables of a single byte character set.
+type SingleByteTables = (&'static [u32; 256], &'static [(u32, u8)]);
+
+fn single_byte_tables(char_set: FriBidiCharSet) -> Option<SingleByteTables>
+{
+    match char_set
+    {
+        FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_6 =>
+            Some((&tables::ISO8859_6_TO_UNICODE, &tables::ISO8859_6_FROM_UNICODE)),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_8 =>
+            Some((&tables::ISO8859_8_TO_UNICODE, &tables::ISO8859_8_FROM_UNICODE)),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CP1255 => Some((&tables::CP1255_TO_UNICODE, &tables::CP1255_FROM_UNICODE)),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256 => Some((&tables::CP1256_TO_UNICODE, &tables::CP1256_FROM_UNICODE)),
+        _ => None
+    }
+}
+
+/// Decode s into us, return the number of characters.
+pub(super) fn to_unicode(char_set: FriBidiCharSet, s: &[u8], us: &mut [FriBidiChar]) -> usize
+{
+    // like the C decoders, read a truncated sequence as if NUL terminated
+    let byte = |i: usize| s.get(i).copied().unwrap_or(0) as u32;
+    let mut decoded: Vec<FriBidiChar> = Vec::with_capacity(s.len());
+
+    match char_set
+    {
+        FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8 =>
+        {
+            let mut i = 0;
+            while i < s.len()
+            {
+                let (ch, len) = match byte(i)
+                {
+                    lead @ 0..=0x7f => (lead, 1),
+                    lead @ 0x80..=0xdf => (((lead & 0x1f) << 6) + (byte(i + 1) & 0x3f), 2),
+                    lead @ 0xe0..=0xef =>
+                        (((lead & 0x0f) << 12) + ((byte(i + 1) & 0x3f) << 6) + (byte(i + 2) & 0x3f), 3),
+                    lead => (
+                        ((lead & 0x07) << 18)
+                            + ((byte(i + 1) & 0x3f) << 12)
+                            + ((byte(i + 2) & 0x3f) << 6)
+                            + (byte(i + 3) & 0x3f),
+                        4
+                    )
+                };
+                decoded.push(ch);
+                i += len;
+            }
+        },
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL =>
+        {
+            let mut i = 0;
+            while i < s.len()
+            {
+                let ch = s[i];
+                if ch == b'_'
+                {
+                    // an unknown escape is a plain '_' followed by the next byte
+                    match CAP_RTL_ESCAPES.iter().find(|&&(escape, _)| escape as u32 == byte(i + 1))
+                    {
+                        Some(&(_, escaped)) =>
+                        {
+                            decoded.push(escaped);
+                            i += 1;
+                        },
+                        None => decoded.push(b'_' as FriBidiChar)
+                    }
+                }
+                else
+                {
+                    decoded.push(tables::CAP_RTL_TO_UNICODE.get(ch as usize).copied().unwrap_or(b'?' as FriBidiChar));
+                }
+                i += 1;
+            }
+        },
+        _ => match single_byte_tables(char_set)
+        {
+            Some((to_unicode, _)) => decoded.extend(s.iter().map(|&ch| to_unicode[ch as usize])),
+            None => return 0
+        }
+    }
+
+    let len = decoded.len().min(us.len());
+    us[..len].copy_from_slice(&decoded[..len]);
+    len
+}
+
+/// Encode us, without the NUL terminator.
+pub(super) fn from_unicode(char_set: FriBidiCharSet, us: &[FriBidiChar]) -> Vec<u8>
+{
+    let mut s: Vec<u8> = Vec::with_capacity(us.len());
+
+    match char_set
+    {
+        FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8 =>
+        {
+            for &ch in us
+            {
+                match ch
+                {
+                    0..=0x7f => s.push(ch as u8),
+                    0x80..=0x7ff => s.extend([0xc0 | (ch >> 6) as u8, 0x80 | (ch & 0x3f) as u8]),
+                    0x800..=0xffff => s.extend([
+                        0xe0 | (ch >> 12) as u8,
+                        0x80 | ((ch >> 6) & 0x3f) as u8,
+                        0x80 | (ch & 0x3f) as u8,
+                    ]),
+                    0x10000..=0x10ffff => s.extend([
+                        0xf0 | (ch >> 18) as u8,
+                        0x80 | ((ch >> 12) & 0x3f) as u8,
+                        0x80 | ((ch >> 6) & 0x3f) as u8,
+                        0x80 | (ch & 0x3f) as u8,
+                    ]),
+                    _ => {}
+                }
+            }
+        },
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL =>
+        {
+            for &ch in us
+            {
+                match CAP_RTL_ESCAPES.iter().find(|&&(_, escaped)| escaped == ch)
+                {
+                    Some(&(escape, _)) => s.extend([b'_', escape]),
+                    None => s.push(pair_value(&tables::CAP_RTL_FROM_UNICODE, ch).unwrap_or(b'?'))
+                }
+            }
+        },
+        _ =>
+        {
+            if let Some((_, from_unicode)) = single_byte_tables(char_set)
+            {
+                s.extend(us.iter().map(|&ch| pair_value(from_unicode, ch).unwrap_or(b'?')));
+            }
+        }
+    }
+
+    s
+}
+
+/// The character set named name, in any case, or 0.
+pub(super) fn parse(name: &[u8]) -> FriBidiCharSet
+{
+    (FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8..=FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256)
+        .find(|&char_set| {
+            self::name(char_set).is_some_and(|char_set_name| {
+                char_set_name.trim_end_matches('\0').as_bytes().eq_ignore_ascii_case(name)
+            })
+        })
+        .unwrap_or(0)
+}
+
+pub(super) fn name(char_set: FriBidiCharSet) -> Option<&'static str>
+{
+    match char_set
+    {
+        FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8 => Some("UTF-8\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL => Some("CapRTL\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_6 => Some("ISO8859-6\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_8 => Some("ISO8859-8\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CP1255 => Some("CP1255\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256 => Some("CP1256\0"),
+        _ => None
+    }
+}
+
+pub(super) fn title(char_set: FriBidiCharSet) -> Option<&'static str>
+{
+    match char_set
+    {
+        FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8 => Some("UTF-8 (Unicode)\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL => Some("CapRTL (Test)\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_6 => Some("ISO8859-6 (Arabic)\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_8 => Some("ISO8859-8 (Hebrew)\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CP1255 => Some("CP1255 (MS Hebrew/Yiddish)\0"),
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256 => Some("CP1256 (MS Arabic)\0"),
+        _ => None
+    }
+}
+
+pub(super) fn desc(char_set: FriBidiCharSet) -> Option<&'static str>
+{
+    match char_set
+    {
+        FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL => Some(tables::CAP_RTL_DESCRIPTION),
+        _ => None
+    }
+}
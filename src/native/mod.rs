@@ -0,0 +1,978 @@
+//! The `pure-rust` backend: a native implementation of the part of the
+//! fribidi C API this crate uses.
+//!
+//! It stands in for `fribidi_sys::fribidi_bindings` when the `pure-rust`
+//! feature is enabled, with the same names, values and signatures, so the
+//! safe wrappers do not change from one backend to the other.  The algorithms
+//! follow GNU FriBidi 1.0.8 step by step, and the Unicode data of `tables` is
+//! generated from it by `tools/gen_native_tables.py`, so it is Unicode
+//! 10.0.0.  The checked-in bindings are for fribidi 1.0.12 and Unicode 14.0.0:
+//! the results only match those of a linked fribidi 1.0.8, which the tests
+//! of the `differential-tests` feature compare them with.
+#![allow(non_upper_case_globals)]
+
+mod arabic;
+mod bidi;
+mod charset;
+mod tables;
+
+use std::os::raw::{c_char, c_int};
+use std::slice;
+
+pub type FriBidiChar = u32;
+pub type FriBidiStrIndex = c_int;
+pub type FriBidiCharType = u32;
+pub type FriBidiParType = u32;
+pub type FriBidiBracketType = u32;
+pub type FriBidiLevel = i8;
+pub type FriBidiFlags = u32;
+pub type FriBidiJoiningType = u8;
+pub type FriBidiArabicProp = u8;
+pub type FriBidiCharSet = u32;
+
+pub const FRIBIDI_MAJOR_VERSION: u32 = 1;
+pub const FRIBIDI_MINOR_VERSION: u32 = 0;
+pub const FRIBIDI_MICRO_VERSION: u32 = 8;
+pub const FRIBIDI_INTERFACE_VERSION: u32 = 4;
+pub const FRIBIDI_UNICODE_MAJOR_VERSION: u32 = 10;
+pub const FRIBIDI_UNICODE_MINOR_VERSION: u32 = 0;
+pub const FRIBIDI_UNICODE_MICRO_VERSION: u32 = 0;
+
+/// Laid out like the one of fribidi.c, for the release the tables follow.
+pub static mut fribidi_version_info: *const c_char = c"(GNU FriBidi) 1.0.8\n\
+interface version 4,\n\
+Unicode Character Database version 10.0.0,\n\
+Native Rust implementation of the fribidi crate.\n"
+    .as_ptr();
+pub static mut fribidi_unicode_version: *const c_char = c"10.0.0".as_ptr();
+
+pub const FRIBIDI_BIDI_MAX_EXPLICIT_LEVEL: u32 = 125;
+pub const FRIBIDI_BIDI_MAX_NESTED_BRACKET_PAIRS: u32 = 63;
+
+pub const FRIBIDI_CHAR_LRM: u32 = 0x200e;
+pub const FRIBIDI_CHAR_RLM: u32 = 0x200f;
+pub const FRIBIDI_CHAR_LRE: u32 = 0x202a;
+pub const FRIBIDI_CHAR_RLE: u32 = 0x202b;
+pub const FRIBIDI_CHAR_PDF: u32 = 0x202c;
+pub const FRIBIDI_CHAR_LRO: u32 = 0x202d;
+pub const FRIBIDI_CHAR_RLO: u32 = 0x202e;
+pub const FRIBIDI_CHAR_LRI: u32 = 0x2066;
+pub const FRIBIDI_CHAR_RLI: u32 = 0x2067;
+pub const FRIBIDI_CHAR_FSI: u32 = 0x2068;
+pub const FRIBIDI_CHAR_PDI: u32 = 0x2069;
+pub const FRIBIDI_CHAR_FILL: u32 = 0xfeff;
+
+pub const FRIBIDI_FLAG_SHAPE_MIRRORING: u32 = 0x00000001;
+pub const FRIBIDI_FLAG_REORDER_NSM: u32 = 0x00000002;
+pub const FRIBIDI_FLAG_SHAPE_ARAB_PRES: u32 = 0x00000100;
+pub const FRIBIDI_FLAG_SHAPE_ARAB_LIGA: u32 = 0x00000200;
+pub const FRIBIDI_FLAG_SHAPE_ARAB_CONSOLE: u32 = 0x00000400;
+pub const FRIBIDI_FLAG_REMOVE_BIDI: u32 = 0x00010000;
+pub const FRIBIDI_FLAG_REMOVE_JOINING: u32 = 0x00020000;
+pub const FRIBIDI_FLAG_REMOVE_SPECIALS: u32 = 0x00040000;
+pub const FRIBIDI_FLAGS_DEFAULT: u32 =
+    FRIBIDI_FLAG_SHAPE_MIRRORING | FRIBIDI_FLAG_REORDER_NSM | FRIBIDI_FLAG_REMOVE_SPECIALS;
+pub const FRIBIDI_FLAGS_ARABIC: u32 = FRIBIDI_FLAG_SHAPE_ARAB_PRES | FRIBIDI_FLAG_SHAPE_ARAB_LIGA;
+
+pub const FRIBIDI_MASK_RTL: u32 = 0x00000001;
+pub const FRIBIDI_MASK_ARABIC: u32 = 0x00000002;
+pub const FRIBIDI_MASK_STRONG: u32 = 0x00000010;
+pub const FRIBIDI_MASK_WEAK: u32 = 0x00000020;
+pub const FRIBIDI_MASK_NEUTRAL: u32 = 0x00000040;
+pub const FRIBIDI_MASK_SENTINEL: u32 = 0x00000080;
+pub const FRIBIDI_MASK_LETTER: u32 = 0x00000100;
+pub const FRIBIDI_MASK_NUMBER: u32 = 0x00000200;
+pub const FRIBIDI_MASK_NUMSEPTER: u32 = 0x00000400;
+pub const FRIBIDI_MASK_SPACE: u32 = 0x00000800;
+pub const FRIBIDI_MASK_EXPLICIT: u32 = 0x00001000;
+pub const FRIBIDI_MASK_SEPARATOR: u32 = 0x00002000;
+pub const FRIBIDI_MASK_OVERRIDE: u32 = 0x00004000;
+pub const FRIBIDI_MASK_ISOLATE: u32 = 0x00008000;
+pub const FRIBIDI_MASK_ES: u32 = 0x00010000;
+pub const FRIBIDI_MASK_ET: u32 = 0x00020000;
+pub const FRIBIDI_MASK_CS: u32 = 0x00040000;
+pub const FRIBIDI_MASK_NSM: u32 = 0x00080000;
+pub const FRIBIDI_MASK_BN: u32 = 0x00100000;
+pub const FRIBIDI_MASK_BS: u32 = 0x00200000;
+pub const FRIBIDI_MASK_SS: u32 = 0x00400000;
+pub const FRIBIDI_MASK_WS: u32 = 0x00800000;
+pub const FRIBIDI_MASK_PRIVATE: u32 = 0x01000000;
+pub const FRIBIDI_MASK_FIRST: u32 = 0x02000000;
+
+pub const FriBidiCharType_FRIBIDI_TYPE_LTR: FriBidiCharType = FRIBIDI_MASK_STRONG | FRIBIDI_MASK_LETTER;
+pub const FriBidiCharType_FRIBIDI_TYPE_RTL: FriBidiCharType =
+    FRIBIDI_MASK_STRONG | FRIBIDI_MASK_LETTER | FRIBIDI_MASK_RTL;
+pub const FriBidiCharType_FRIBIDI_TYPE_AL: FriBidiCharType =
+    FRIBIDI_MASK_STRONG | FRIBIDI_MASK_LETTER | FRIBIDI_MASK_RTL | FRIBIDI_MASK_ARABIC;
+pub const FriBidiCharType_FRIBIDI_TYPE_EN: FriBidiCharType = FRIBIDI_MASK_WEAK | FRIBIDI_MASK_NUMBER;
+pub const FriBidiCharType_FRIBIDI_TYPE_AN: FriBidiCharType =
+    FRIBIDI_MASK_WEAK | FRIBIDI_MASK_NUMBER | FRIBIDI_MASK_ARABIC;
+pub const FriBidiCharType_FRIBIDI_TYPE_ES: FriBidiCharType =
+    FRIBIDI_MASK_WEAK | FRIBIDI_MASK_NUMSEPTER | FRIBIDI_MASK_ES;
+pub const FriBidiCharType_FRIBIDI_TYPE_ET: FriBidiCharType =
+    FRIBIDI_MASK_WEAK | FRIBIDI_MASK_NUMSEPTER | FRIBIDI_MASK_ET;
+pub const FriBidiCharType_FRIBIDI_TYPE_CS: FriBidiCharType =
+    FRIBIDI_MASK_WEAK | FRIBIDI_MASK_NUMSEPTER | FRIBIDI_MASK_CS;
+pub const FriBidiCharType_FRIBIDI_TYPE_NSM: FriBidiCharType = FRIBIDI_MASK_WEAK | FRIBIDI_MASK_NSM;
+pub const FriBidiCharType_FRIBIDI_TYPE_BN: FriBidiCharType =
+    FRIBIDI_MASK_WEAK | FRIBIDI_MASK_SPACE | FRIBIDI_MASK_BN;
+pub const FriBidiCharType_FRIBIDI_TYPE_BS: FriBidiCharType =
+    FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_SPACE | FRIBIDI_MASK_SEPARATOR | FRIBIDI_MASK_BS;
+pub const FriBidiCharType_FRIBIDI_TYPE_SS: FriBidiCharType =
+    FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_SPACE | FRIBIDI_MASK_SEPARATOR | FRIBIDI_MASK_SS;
+pub const FriBidiCharType_FRIBIDI_TYPE_WS: FriBidiCharType =
+    FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_SPACE | FRIBIDI_MASK_WS;
+pub const FriBidiCharType_FRIBIDI_TYPE_ON: FriBidiCharType = FRIBIDI_MASK_NEUTRAL;
+pub const FriBidiCharType_FRIBIDI_TYPE_LRE: FriBidiCharType = FRIBIDI_MASK_STRONG | FRIBIDI_MASK_EXPLICIT;
+pub const FriBidiCharType_FRIBIDI_TYPE_RLE: FriBidiCharType =
+    FRIBIDI_MASK_STRONG | FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_RTL;
+pub const FriBidiCharType_FRIBIDI_TYPE_LRO: FriBidiCharType =
+    FRIBIDI_MASK_STRONG | FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_OVERRIDE;
+pub const FriBidiCharType_FRIBIDI_TYPE_RLO: FriBidiCharType =
+    FRIBIDI_MASK_STRONG | FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_OVERRIDE | FRIBIDI_MASK_RTL;
+pub const FriBidiCharType_FRIBIDI_TYPE_PDF: FriBidiCharType = FRIBIDI_MASK_WEAK | FRIBIDI_MASK_EXPLICIT;
+pub const FriBidiCharType_FRIBIDI_TYPE_LRI: FriBidiCharType = FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_ISOLATE;
+pub const FriBidiCharType_FRIBIDI_TYPE_RLI: FriBidiCharType =
+    FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_ISOLATE | FRIBIDI_MASK_RTL;
+pub const FriBidiCharType_FRIBIDI_TYPE_FSI: FriBidiCharType =
+    FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_ISOLATE | FRIBIDI_MASK_FIRST;
+pub const FriBidiCharType_FRIBIDI_TYPE_PDI: FriBidiCharType =
+    FRIBIDI_MASK_NEUTRAL | FRIBIDI_MASK_WEAK | FRIBIDI_MASK_ISOLATE;
+const FRIBIDI_TYPE_WLTR: FriBidiCharType = FRIBIDI_MASK_WEAK;
+const FRIBIDI_TYPE_WRTL: FriBidiCharType = FRIBIDI_MASK_WEAK | FRIBIDI_MASK_RTL;
+const FRIBIDI_TYPE_SENTINEL: FriBidiCharType = FRIBIDI_MASK_SENTINEL;
+
+pub const FriBidiParType_FRIBIDI_PAR_LTR: FriBidiParType = FriBidiCharType_FRIBIDI_TYPE_LTR;
+pub const FriBidiParType_FRIBIDI_PAR_RTL: FriBidiParType = FriBidiCharType_FRIBIDI_TYPE_RTL;
+pub const FriBidiParType_FRIBIDI_PAR_ON: FriBidiParType = FriBidiCharType_FRIBIDI_TYPE_ON;
+pub const FriBidiParType_FRIBIDI_PAR_WLTR: FriBidiParType = FRIBIDI_TYPE_WLTR;
+pub const FriBidiParType_FRIBIDI_PAR_WRTL: FriBidiParType = FRIBIDI_TYPE_WRTL;
+
+pub const FRIBIDI_BRACKET_OPEN_MASK: u32 = 0x80000000;
+pub const FRIBIDI_BRACKET_ID_MASK: u32 = 0x7fffffff;
+
+pub const FRIBIDI_MASK_JOINS_RIGHT: u32 = 0x01;
+pub const FRIBIDI_MASK_JOINS_LEFT: u32 = 0x02;
+pub const FRIBIDI_MASK_ARAB_SHAPES: u32 = 0x04;
+pub const FRIBIDI_MASK_TRANSPARENT: u32 = 0x08;
+pub const FRIBIDI_MASK_IGNORED: u32 = 0x10;
+pub const FRIBIDI_MASK_LIGATURED: u32 = 0x20;
+
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_U: u32 = 0;
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_R: u32 = FRIBIDI_MASK_JOINS_RIGHT | FRIBIDI_MASK_ARAB_SHAPES;
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_D: u32 =
+    FRIBIDI_MASK_JOINS_RIGHT | FRIBIDI_MASK_JOINS_LEFT | FRIBIDI_MASK_ARAB_SHAPES;
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_C: u32 = FRIBIDI_MASK_JOINS_RIGHT | FRIBIDI_MASK_JOINS_LEFT;
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_L: u32 = FRIBIDI_MASK_JOINS_LEFT | FRIBIDI_MASK_ARAB_SHAPES;
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_T: u32 = FRIBIDI_MASK_TRANSPARENT | FRIBIDI_MASK_ARAB_SHAPES;
+pub const _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_G: u32 = FRIBIDI_MASK_IGNORED;
+
+pub const FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8: FriBidiCharSet = 1;
+pub const FriBidiCharSet_FRIBIDI_CHAR_SET_CAP_RTL: FriBidiCharSet = 2;
+pub const FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_6: FriBidiCharSet = 3;
+pub const FriBidiCharSet_FRIBIDI_CHAR_SET_ISO8859_8: FriBidiCharSet = 4;
+pub const FriBidiCharSet_FRIBIDI_CHAR_SET_CP1255: FriBidiCharSet = 5;
+pub const FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256: FriBidiCharSet = 6;
+
+/// Look ch up in a table of (first, last, value) ranges.
+fn range_value<T: Copy>(table: &[(u32, u32, T)], ch: u32) -> Option<T>
+{
+    table
+        .binary_search_by(|&(first, last, _)| match last < ch
+        {
+            true => std::cmp::Ordering::Less,
+            false if first > ch => std::cmp::Ordering::Greater,
+            false => std::cmp::Ordering::Equal
+        })
+        .ok()
+        .map(|index| table[index].2)
+}
+
+/// Look key up in a table of (key, value) pairs sorted by key.
+fn pair_value<T: Copy>(table: &[(u32, T)], key: u32) -> Option<T>
+{
+    table
+        .binary_search_by_key(&key, |&(table_key, _)| table_key)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// The len elements at ptr, or nothing for a null pointer.
+unsafe fn input<'a, T>(ptr: *const T, len: FriBidiStrIndex) -> &'a [T]
+{
+    match ptr.is_null() || len <= 0
+    {
+        true => &[],
+        false => slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+/// The len elements at ptr, or None for a null pointer.
+unsafe fn output<'a, T>(ptr: *mut T, len: FriBidiStrIndex) -> Option<&'a mut [T]>
+{
+    match ptr.is_null()
+    {
+        true => None,
+        false => Some(slice::from_raw_parts_mut(ptr, len.max(0) as usize))
+    }
+}
+
+/// A NUL terminated string as the C API returns them.
+fn c_str(s: &'static str) -> *const c_char
+{
+    debug_assert!(s.ends_with('\0'));
+    s.as_ptr() as *const c_char
+}
+
+fn get_bidi_type(ch: FriBidiChar) -> FriBidiCharType
+{
+    range_value(&tables::BIDI_TYPES, ch).unwrap_or(FriBidiCharType_FRIBIDI_TYPE_LTR)
+}
+
+fn get_joining_type(ch: FriBidiChar) -> FriBidiJoiningType
+{
+    range_value(&tables::JOINING_TYPES, ch).unwrap_or(_FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_U as u8)
+}
+
+pub unsafe fn fribidi_get_bidi_type(ch: FriBidiChar) -> FriBidiCharType
+{
+    get_bidi_type(ch)
+}
+
+pub unsafe fn fribidi_get_bidi_types(str: *const FriBidiChar, len: FriBidiStrIndex, btypes: *mut FriBidiCharType)
+{
+    if let Some(btypes) = output(btypes, len)
+    {
+        for (btype, &ch) in btypes.iter_mut().zip(input(str, len))
+        {
+            *btype = get_bidi_type(ch);
+        }
+    }
+}
+
+pub unsafe fn fribidi_get_bidi_type_name(t: FriBidiCharType) -> *const c_char
+{
+    c_str(match t
+    {
+        FriBidiCharType_FRIBIDI_TYPE_LTR => "LTR\0",
+        FriBidiCharType_FRIBIDI_TYPE_RTL => "RTL\0",
+        FriBidiCharType_FRIBIDI_TYPE_AL => "AL\0",
+        FriBidiCharType_FRIBIDI_TYPE_EN => "EN\0",
+        FriBidiCharType_FRIBIDI_TYPE_AN => "AN\0",
+        FriBidiCharType_FRIBIDI_TYPE_ES => "ES\0",
+        FriBidiCharType_FRIBIDI_TYPE_ET => "ET\0",
+        FriBidiCharType_FRIBIDI_TYPE_CS => "CS\0",
+        FriBidiCharType_FRIBIDI_TYPE_NSM => "NSM\0",
+        FriBidiCharType_FRIBIDI_TYPE_BN => "BN\0",
+        FriBidiCharType_FRIBIDI_TYPE_BS => "BS\0",
+        FriBidiCharType_FRIBIDI_TYPE_SS => "SS\0",
+        FriBidiCharType_FRIBIDI_TYPE_WS => "WS\0",
+        FriBidiCharType_FRIBIDI_TYPE_ON => "ON\0",
+        FriBidiCharType_FRIBIDI_TYPE_LRE => "LRE\0",
+        FriBidiCharType_FRIBIDI_TYPE_RLE => "RLE\0",
+        FriBidiCharType_FRIBIDI_TYPE_LRO => "LRO\0",
+        FriBidiCharType_FRIBIDI_TYPE_RLO => "RLO\0",
+        FriBidiCharType_FRIBIDI_TYPE_PDF => "PDF\0",
+        FriBidiCharType_FRIBIDI_TYPE_LRI => "LRI\0",
+        FriBidiCharType_FRIBIDI_TYPE_RLI => "RLI\0",
+        FriBidiCharType_FRIBIDI_TYPE_FSI => "FSI\0",
+        FriBidiCharType_FRIBIDI_TYPE_PDI => "PDI\0",
+        FRIBIDI_TYPE_WLTR => "WLTR\0",
+        FRIBIDI_TYPE_WRTL => "WRTL\0",
+        FRIBIDI_TYPE_SENTINEL => "SENTINEL\0",
+        _ => "?\0"
+    })
+}
+
+pub unsafe fn fribidi_get_par_direction(bidi_types: *const FriBidiCharType, len: FriBidiStrIndex) -> FriBidiParType
+{
+    bidi::get_par_direction(input(bidi_types, len))
+}
+
+pub unsafe fn fribidi_get_par_embedding_levels_ex(
+    bidi_types: *const FriBidiCharType,
+    bracket_types: *const FriBidiBracketType,
+    len: FriBidiStrIndex,
+    pbase_dir: *mut FriBidiParType,
+    embedding_levels: *mut FriBidiLevel,
+) -> FriBidiLevel
+{
+    let bracket_types = match bracket_types.is_null()
+    {
+        true => None,
+        false => Some(input(bracket_types, len))
+    };
+
+    match output(embedding_levels, len)
+    {
+        Some(embedding_levels) =>
+            bidi::get_par_embedding_levels(input(bidi_types, len), bracket_types, &mut *pbase_dir, embedding_levels),
+        None => 0
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn fribidi_reorder_line(
+    flags: FriBidiFlags,
+    bidi_types: *const FriBidiCharType,
+    len: FriBidiStrIndex,
+    off: FriBidiStrIndex,
+    base_dir: FriBidiParType,
+    embedding_levels: *mut FriBidiLevel,
+    visual_str: *mut FriBidiChar,
+    map: *mut FriBidiStrIndex,
+) -> FriBidiLevel
+{
+    let end = off + len;
+
+    match output(embedding_levels, end)
+    {
+        Some(embedding_levels) => bidi::reorder_line(
+            flags,
+            input(bidi_types, end),
+            off as usize..end as usize,
+            base_dir,
+            embedding_levels,
+            output(visual_str, end),
+            output(map, end)
+        ),
+        None => 0
+    }
+}
+
+pub unsafe fn fribidi_get_joining_type(ch: FriBidiChar) -> FriBidiJoiningType
+{
+    get_joining_type(ch)
+}
+
+pub unsafe fn fribidi_get_joining_types(str: *const FriBidiChar, len: FriBidiStrIndex, jtypes: *mut FriBidiJoiningType)
+{
+    if let Some(jtypes) = output(jtypes, len)
+    {
+        for (jtype, &ch) in jtypes.iter_mut().zip(input(str, len))
+        {
+            *jtype = get_joining_type(ch);
+        }
+    }
+}
+
+pub unsafe fn fribidi_get_joining_type_name(j: FriBidiJoiningType) -> *const c_char
+{
+    c_str(match j as u32
+    {
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_U => "U\0",
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_R => "R\0",
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_D => "D\0",
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_C => "C\0",
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_T => "T\0",
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_L => "L\0",
+        _FriBidiJoiningTypeEnum_FRIBIDI_JOINING_TYPE_G => "G\0",
+        _ => "?\0"
+    })
+}
+
+pub unsafe fn fribidi_join_arabic(
+    bidi_types: *const FriBidiCharType,
+    len: FriBidiStrIndex,
+    embedding_levels: *const FriBidiLevel,
+    ar_props: *mut FriBidiArabicProp,
+)
+{
+    if let Some(ar_props) = output(ar_props, len)
+    {
+        arabic::join_arabic(input(bidi_types, len), input(embedding_levels, len), ar_props);
+    }
+}
+
+pub unsafe fn fribidi_get_mirror_char(ch: FriBidiChar, mirrored_ch: *mut FriBidiChar) -> c_int
+{
+    // like fribidi, hand back ch itself when it has no mirror
+    let mirror = pair_value(&tables::MIRRORS, ch);
+    if !mirrored_ch.is_null()
+    {
+        *mirrored_ch = mirror.unwrap_or(ch);
+    }
+
+    mirror.is_some() as c_int
+}
+
+pub unsafe fn fribidi_shape_mirroring(embedding_levels: *const FriBidiLevel, len: FriBidiStrIndex, str: *mut FriBidiChar)
+{
+    if let Some(str) = output(str, len)
+    {
+        arabic::shape_mirroring(input(embedding_levels, len), str);
+    }
+}
+
+pub unsafe fn fribidi_get_bracket(ch: FriBidiChar) -> FriBidiBracketType
+{
+    pair_value(&tables::BRACKETS, ch).unwrap_or(0)
+}
+
+pub unsafe fn fribidi_get_bracket_types(
+    str: *const FriBidiChar,
+    len: FriBidiStrIndex,
+    types: *const FriBidiCharType,
+    btypes: *mut FriBidiBracketType,
+)
+{
+    if let Some(btypes) = output(btypes, len)
+    {
+        for ((btype, &ch), &char_type) in btypes.iter_mut().zip(input(str, len)).zip(input(types, len))
+        {
+            // only ON characters can be brackets
+            *btype = match char_type
+            {
+                FriBidiCharType_FRIBIDI_TYPE_ON => fribidi_get_bracket(ch),
+                _ => 0
+            };
+        }
+    }
+}
+
+pub unsafe fn fribidi_shape_arabic(
+    flags: FriBidiFlags,
+    embedding_levels: *const FriBidiLevel,
+    len: FriBidiStrIndex,
+    ar_props: *mut FriBidiArabicProp,
+    str: *mut FriBidiChar,
+)
+{
+    if let (Some(ar_props), Some(str)) = (output(ar_props, len), output(str, len))
+    {
+        arabic::shape_arabic(flags, input(embedding_levels, len), ar_props, str);
+    }
+}
+
+pub unsafe fn fribidi_shape(
+    flags: FriBidiFlags,
+    embedding_levels: *const FriBidiLevel,
+    len: FriBidiStrIndex,
+    ar_props: *mut FriBidiArabicProp,
+    str: *mut FriBidiChar,
+)
+{
+    if len <= 0 || str.is_null()
+    {
+        return;
+    }
+
+    fribidi_shape_arabic(flags, embedding_levels, len, ar_props, str);
+    if flags & FRIBIDI_FLAG_SHAPE_MIRRORING != 0
+    {
+        fribidi_shape_mirroring(embedding_levels, len, str);
+    }
+}
+
+pub unsafe fn fribidi_charset_to_unicode(
+    char_set: FriBidiCharSet,
+    s: *const c_char,
+    len: FriBidiStrIndex,
+    us: *mut FriBidiChar,
+) -> FriBidiStrIndex
+{
+    match output(us, len)
+    {
+        Some(us) => charset::to_unicode(char_set, input(s as *const u8, len), us) as FriBidiStrIndex,
+        None => 0
+    }
+}
+
+/// The output has to hold every encoded character and the NUL terminator.
+pub unsafe fn fribidi_unicode_to_charset(
+    char_set: FriBidiCharSet,
+    us: *const FriBidiChar,
+    len: FriBidiStrIndex,
+    s: *mut c_char,
+) -> FriBidiStrIndex
+{
+    let encoded = charset::from_unicode(char_set, input(us, len));
+    if !s.is_null()
+    {
+        std::ptr::copy_nonoverlapping(encoded.as_ptr(), s as *mut u8, encoded.len());
+        *s.add(encoded.len()) = 0;
+    }
+
+    encoded.len() as FriBidiStrIndex
+}
+
+pub unsafe fn fribidi_parse_charset(s: *const c_char) -> FriBidiCharSet
+{
+    match s.is_null()
+    {
+        true => 0,
+        false => charset::parse(std::ffi::CStr::from_ptr(s).to_bytes())
+    }
+}
+
+pub unsafe fn fribidi_char_set_name(char_set: FriBidiCharSet) -> *const c_char
+{
+    charset::name(char_set).map_or(std::ptr::null(), c_str)
+}
+
+pub unsafe fn fribidi_char_set_title(char_set: FriBidiCharSet) -> *const c_char
+{
+    charset::title(char_set).map_or(std::ptr::null(), c_str)
+}
+
+pub unsafe fn fribidi_char_set_desc(char_set: FriBidiCharSet) -> *const c_char
+{
+    charset::desc(char_set).map_or(std::ptr::null(), c_str)
+}
+
+pub unsafe fn fribidi_remove_bidi_marks(
+    str: *mut FriBidiChar,
+    len: FriBidiStrIndex,
+    positions_to_this: *mut FriBidiStrIndex,
+    position_from_this_list: *mut FriBidiStrIndex,
+    embedding_levels: *mut FriBidiLevel,
+) -> FriBidiStrIndex
+{
+    let str = match output(str, len)
+    {
+        Some(str) if len > 0 => str,
+        _ => return 0
+    };
+    let positions_to_this = output(positions_to_this, len);
+    let mut embedding_levels = output(embedding_levels, len);
+
+    // to_this is computed from from_this, make a private one if needed
+    let mut private_from_this: Vec<FriBidiStrIndex> = Vec::new();
+    let mut from_this = match (output(position_from_this_list, len), &positions_to_this)
+    {
+        (Some(from_this), _) => Some(from_this),
+        (None, Some(to_this)) =>
+        {
+            private_from_this.resize(str.len(), 0);
+            for (i, &position) in to_this.iter().enumerate()
+            {
+                private_from_this[position as usize] = i as FriBidiStrIndex;
+            }
+            Some(private_from_this.as_mut_slice())
+        },
+        (None, None) => None
+    };
+
+    let mut j = 0;
+    for i in 0..str.len()
+    {
+        let char_type = get_bidi_type(str[i]);
+        if char_type & (FRIBIDI_MASK_EXPLICIT | FRIBIDI_MASK_BN | FRIBIDI_MASK_ISOLATE) == 0
+            && str[i] != FRIBIDI_CHAR_LRM
+            && str[i] != FRIBIDI_CHAR_RLM
+        {
+            str[j] = str[i];
+            if let Some(embedding_levels) = embedding_levels.as_deref_mut()
+            {
+                embedding_levels[j] = embedding_levels[i];
+            }
+            if let Some(from_this) = from_this.as_deref_mut()
+            {
+                from_this[j] = from_this[i];
+            }
+            j += 1;
+        }
+    }
+
+    if let (Some(to_this), Some(from_this)) = (positions_to_this, from_this)
+    {
+        to_this.fill(-1);
+        for (i, &position) in from_this.iter().enumerate()
+        {
+            to_this[position as usize] = i as FriBidiStrIndex;
+        }
+    }
+
+    j as FriBidiStrIndex
+}
+
+pub unsafe fn fribidi_log2vis(
+    str: *const FriBidiChar,
+    len: FriBidiStrIndex,
+    pbase_dir: *mut FriBidiParType,
+    visual_str: *mut FriBidiChar,
+    positions_l_to_v: *mut FriBidiStrIndex,
+    positions_v_to_l: *mut FriBidiStrIndex,
+    embedding_levels: *mut FriBidiLevel,
+) -> FriBidiLevel
+{
+    if len <= 0
+    {
+        return 1;
+    }
+
+    let str = input(str, len);
+    let count = str.len();
+    let mut bidi_types: Vec<FriBidiCharType> = vec![0; count];
+    fribidi_get_bidi_types(str.as_ptr(), len, bidi_types.as_mut_ptr());
+    let mut bracket_types: Vec<FriBidiBracketType> = vec![0; count];
+    fribidi_get_bracket_types(str.as_ptr(), len, bidi_types.as_ptr(), bracket_types.as_mut_ptr());
+
+    let mut private_levels: Vec<FriBidiLevel> = Vec::new();
+    let embedding_levels = match output(embedding_levels, len)
+    {
+        Some(embedding_levels) => embedding_levels,
+        None =>
+        {
+            private_levels.resize(count, 0);
+            private_levels.as_mut_slice()
+        }
+    };
+
+    let max_level =
+        bidi::get_par_embedding_levels(&bidi_types, Some(&bracket_types), &mut *pbase_dir, embedding_levels) - 1;
+    if max_level < 0
+    {
+        return 0;
+    }
+
+    // l2v is computed from v2l, make a private one if needed
+    let mut private_v_to_l: Vec<FriBidiStrIndex> = Vec::new();
+    let positions_l_to_v = output(positions_l_to_v, len);
+    let mut positions_v_to_l = match (output(positions_v_to_l, len), &positions_l_to_v)
+    {
+        (Some(v_to_l), _) => Some(v_to_l),
+        (None, Some(_)) =>
+        {
+            private_v_to_l.resize(count, 0);
+            Some(private_v_to_l.as_mut_slice())
+        },
+        (None, None) => None
+    };
+    if let Some(v_to_l) = positions_v_to_l.as_deref_mut()
+    {
+        for (i, position) in v_to_l.iter_mut().enumerate()
+        {
+            *position = i as FriBidiStrIndex;
+        }
+    }
+
+    let mut visual_str = output(visual_str, len);
+    if let Some(visual_str) = visual_str.as_deref_mut()
+    {
+        visual_str.copy_from_slice(str);
+
+        let mut ar_props: Vec<FriBidiArabicProp> = str.iter().map(|&ch| get_joining_type(ch)).collect();
+        arabic::join_arabic(&bidi_types, embedding_levels, &mut ar_props);
+        arabic::shape_arabic(FRIBIDI_FLAGS_DEFAULT | FRIBIDI_FLAGS_ARABIC, embedding_levels, &mut ar_props, visual_str);
+        arabic::shape_mirroring(embedding_levels, visual_str);
+    }
+
+    let status = bidi::reorder_line(
+        FRIBIDI_FLAGS_DEFAULT,
+        &bidi_types,
+        0..count,
+        *pbase_dir,
+        embedding_levels,
+        visual_str,
+        positions_v_to_l.as_deref_mut()
+    );
+
+    if let (Some(l_to_v), Some(v_to_l)) = (positions_l_to_v, positions_v_to_l)
+    {
+        l_to_v.fill(-1);
+        for (i, &position) in v_to_l.iter().enumerate()
+        {
+            l_to_v[position as usize] = i as FriBidiStrIndex;
+        }
+    }
+
+    match status
+    {
+        0 => 0,
+        _ => max_level + 1
+    }
+}
+
+/// Run the native functions and libfribidi side by side on random text.
+#[cfg(all(test, feature = "differential-tests"))]
+mod test
+{
+    use fribidi_sys::fribidi_bindings as c;
+
+    use crate::native;
+
+    const CHARS: [u32; 44] = [
+        'a' as u32, 'b' as u32, 0x5d0, 0x5d1, 0x628, 0x644, 0x627, 0x640, 0x64b, '1' as u32, '2' as u32, 0x663,
+        '+' as u32, '$' as u32, ',' as u32, 0x300, 0x200b, 0x2029, '\t' as u32, ' ' as u32, '!' as u32, '(' as u32,
+        ')' as u32, '[' as u32, ']' as u32, 0x2329, 0x3009, '_' as u32, 0x202a, 0x202b, 0x202c, 0x202d, 0x202e,
+        0x2066, 0x2067, 0x2068, 0x2069, 0x200e, 0x200f, 0x627, 0x644, 0xfeff, 0x1f600, 0x110000,
+    ];
+
+    /// A small LCG, the test does not need more.
+    struct Random(u64);
+
+    impl Random
+    {
+        fn next(&mut self, bound: usize) -> usize
+        {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) as usize % bound
+        }
+    }
+
+    fn random_text(random: &mut Random) -> Vec<u32>
+    {
+        let mut text: Vec<u32> = Vec::new();
+
+        // now and then go past the deepest embedding level
+        if random.next(8) == 0
+        {
+            for _ in 0..120 + random.next(20)
+            {
+                text.push(CHARS[28 + random.next(9)]);
+            }
+        }
+        // and now and then stick to a few kinds of characters
+        let pool = match random.next(2)
+        {
+            0 => &CHARS[..],
+            _ =>
+            {
+                let start = random.next(CHARS.len() - 8);
+                &CHARS[start..start + 8]
+            }
+        };
+        for _ in 0..random.next(60)
+        {
+            text.push(pool[random.next(pool.len())]);
+        }
+
+        text
+    }
+
+    #[test]
+    fn test_same_as_libfribidi()
+    {
+        let mut random = Random(7);
+
+        for round in 0..20000
+        {
+            let text = random_text(&mut random);
+            let len = text.len() as i32;
+            let base_dir = [
+                native::FriBidiParType_FRIBIDI_PAR_ON,
+                native::FriBidiParType_FRIBIDI_PAR_LTR,
+                native::FriBidiParType_FRIBIDI_PAR_RTL,
+                native::FriBidiParType_FRIBIDI_PAR_WLTR,
+                native::FriBidiParType_FRIBIDI_PAR_WRTL,
+            ][round % 5];
+
+            unsafe {
+                let mut types = vec![0; text.len()];
+                let mut c_types = vec![0; text.len()];
+                native::fribidi_get_bidi_types(text.as_ptr(), len, types.as_mut_ptr());
+                c::fribidi_get_bidi_types(text.as_ptr(), len, c_types.as_mut_ptr());
+                assert_eq!(types, c_types);
+
+                let mut brackets = vec![0; text.len()];
+                let mut c_brackets = vec![0; text.len()];
+                native::fribidi_get_bracket_types(text.as_ptr(), len, types.as_ptr(), brackets.as_mut_ptr());
+                c::fribidi_get_bracket_types(text.as_ptr(), len, types.as_ptr(), c_brackets.as_mut_ptr());
+                assert_eq!(brackets, c_brackets);
+
+                assert_eq!(
+                    native::fribidi_get_par_direction(types.as_ptr(), len),
+                    c::fribidi_get_par_direction(types.as_ptr(), len),
+                    "{:x?}",
+                    types
+                );
+
+                let (mut dir, mut c_dir) = (base_dir, base_dir);
+                let mut levels = vec![0; text.len()];
+                let mut c_levels = vec![0; text.len()];
+                let max_level = native::fribidi_get_par_embedding_levels_ex(
+                    types.as_ptr(), brackets.as_ptr(), len, &mut dir, levels.as_mut_ptr()
+                );
+                let c_max_level = c::fribidi_get_par_embedding_levels_ex(
+                    types.as_ptr(), brackets.as_ptr(), len, &mut c_dir, c_levels.as_mut_ptr()
+                );
+                assert_eq!((max_level, dir, &levels), (c_max_level, c_dir, &c_levels), "{:x?} {:x}", text, base_dir);
+
+                // reorder the second half as a line
+                let off = len / 2;
+                let mut visual = text.clone();
+                let mut c_visual = text.clone();
+                let mut map: Vec<i32> = (0..len).collect();
+                let mut c_map = map.clone();
+                let flags = [native::FRIBIDI_FLAGS_DEFAULT, 0][round % 2];
+                assert_eq!(
+                    native::fribidi_reorder_line(
+                        flags, types.as_ptr(), len - off, off, dir, levels.as_mut_ptr(), visual.as_mut_ptr(), map.as_mut_ptr()
+                    ),
+                    c::fribidi_reorder_line(
+                        flags, types.as_ptr(), len - off, off, c_dir, c_levels.as_mut_ptr(), c_visual.as_mut_ptr(), c_map.as_mut_ptr()
+                    )
+                );
+                assert_eq!((&levels, &visual, &map), (&c_levels, &c_visual, &c_map), "{:x?}", text);
+
+                let (mut dir, mut c_dir) = (base_dir, base_dir);
+                let mut visual = vec![0; text.len()];
+                let mut c_visual = vec![0; text.len()];
+                let (mut l2v, mut v2l) = (vec![0; text.len()], vec![0; text.len()]);
+                let (mut c_l2v, mut c_v2l) = (vec![0; text.len()], vec![0; text.len()]);
+                assert_eq!(
+                    native::fribidi_log2vis(
+                        text.as_ptr(), len, &mut dir, visual.as_mut_ptr(), l2v.as_mut_ptr(), v2l.as_mut_ptr(), levels.as_mut_ptr()
+                    ),
+                    c::fribidi_log2vis(
+                        text.as_ptr(), len, &mut c_dir, c_visual.as_mut_ptr(), c_l2v.as_mut_ptr(), c_v2l.as_mut_ptr(),
+                        c_levels.as_mut_ptr()
+                    )
+                );
+                assert_eq!((dir, &visual, &l2v, &v2l, &levels), (c_dir, &c_visual, &c_l2v, &c_v2l, &c_levels), "{:x?}", text);
+
+                let mut props = vec![0; text.len()];
+                native::fribidi_get_joining_types(text.as_ptr(), len, props.as_mut_ptr());
+                let mut c_props = props.clone();
+                native::fribidi_join_arabic(types.as_ptr(), len, levels.as_ptr(), props.as_mut_ptr());
+                c::fribidi_join_arabic(types.as_ptr(), len, levels.as_ptr(), c_props.as_mut_ptr());
+                assert_eq!(props, c_props);
+
+                let flags = native::FRIBIDI_FLAGS_DEFAULT | native::FRIBIDI_FLAGS_ARABIC | native::FRIBIDI_FLAG_SHAPE_ARAB_CONSOLE;
+                let mut shaped = text.clone();
+                let mut c_shaped = text.clone();
+                native::fribidi_shape(flags, levels.as_ptr(), len, props.as_mut_ptr(), shaped.as_mut_ptr());
+                c::fribidi_shape(flags, levels.as_ptr(), len, c_props.as_mut_ptr(), c_shaped.as_mut_ptr());
+                assert_eq!((&props, &shaped), (&c_props, &c_shaped));
+
+                let mut removed = text.clone();
+                let mut c_removed = text.clone();
+                let (mut to_this, mut from_this) = (vec![0; text.len()], (0..len).collect::<Vec<i32>>());
+                let (mut c_to_this, mut c_from_this) = (to_this.clone(), from_this.clone());
+                assert_eq!(
+                    native::fribidi_remove_bidi_marks(
+                        removed.as_mut_ptr(), len, to_this.as_mut_ptr(), from_this.as_mut_ptr(), levels.as_mut_ptr()
+                    ),
+                    c::fribidi_remove_bidi_marks(
+                        c_removed.as_mut_ptr(), len, c_to_this.as_mut_ptr(), c_from_this.as_mut_ptr(), c_levels.as_mut_ptr()
+                    )
+                );
+                assert_eq!((&removed, &to_this, &from_this, &levels), (&c_removed, &c_to_this, &c_from_this, &c_levels));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reorder_line_same_as_libfribidi()
+    {
+        let mut random = Random(11);
+
+        for round in 0..20000
+        {
+            let text = random_text(&mut random);
+            let len = text.len() as i32;
+            let base_dir = [native::FriBidiParType_FRIBIDI_PAR_LTR, native::FriBidiParType_FRIBIDI_PAR_RTL][round % 2];
+            let flags = [native::FRIBIDI_FLAGS_DEFAULT, 0][round / 2 % 2];
+
+            unsafe {
+                let mut types = vec![0; text.len()];
+                native::fribidi_get_bidi_types(text.as_ptr(), len, types.as_mut_ptr());
+
+                // levels no paragraph resolves to, like a caller's own ones
+                let max_level = [2, 8, 126][random.next(3)];
+                let mut levels: Vec<i8> = (0..len).map(|_| random.next(max_level) as i8).collect();
+                let mut c_levels = levels.clone();
+
+                let off = random.next(text.len() + 1) as i32;
+                let line_len = random.next((len - off) as usize + 1) as i32;
+                let mut visual = text.clone();
+                let mut c_visual = text.clone();
+                let mut map: Vec<i32> = (0..len).collect();
+                let mut c_map = map.clone();
+                assert_eq!(
+                    native::fribidi_reorder_line(
+                        flags, types.as_ptr(), line_len, off, base_dir, levels.as_mut_ptr(), visual.as_mut_ptr(), map.as_mut_ptr()
+                    ),
+                    c::fribidi_reorder_line(
+                        flags, types.as_ptr(), line_len, off, base_dir, c_levels.as_mut_ptr(), c_visual.as_mut_ptr(),
+                        c_map.as_mut_ptr()
+                    ),
+                    "{:x?} {:?}",
+                    text,
+                    c_levels
+                );
+                assert_eq!((&levels, &visual, &map), (&c_levels, &c_visual, &c_map), "{:x?}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookups_same_as_libfribidi()
+    {
+        let name = |ptr: *const std::os::raw::c_char| match ptr.is_null()
+        {
+            true => None,
+            false => Some(unsafe { std::ffi::CStr::from_ptr(ptr) }.to_owned())
+        };
+
+        unsafe {
+            for ch in 0..0x110100
+            {
+                assert_eq!(native::fribidi_get_bidi_type(ch), c::fribidi_get_bidi_type(ch), "{:x}", ch);
+                assert_eq!(native::fribidi_get_joining_type(ch), c::fribidi_get_joining_type(ch), "{:x}", ch);
+                assert_eq!(native::fribidi_get_bracket(ch), c::fribidi_get_bracket(ch), "{:x}", ch);
+
+                let (mut mirror, mut c_mirror) = (0, 0);
+                assert_eq!(native::fribidi_get_mirror_char(ch, &mut mirror), c::fribidi_get_mirror_char(ch, &mut c_mirror));
+                assert_eq!(mirror, c_mirror);
+            }
+
+            for raw in (0..0x100).chain((0..32).map(|bit| 1 << bit)).chain(CHARS.iter().map(|&ch| c::fribidi_get_bidi_type(ch)))
+            {
+                assert_eq!(name(native::fribidi_get_bidi_type_name(raw)), name(c::fribidi_get_bidi_type_name(raw)));
+                assert_eq!(
+                    name(native::fribidi_get_joining_type_name(raw as u8)),
+                    name(c::fribidi_get_joining_type_name(raw as u8))
+                );
+            }
+
+            for char_set in native::FriBidiCharSet_FRIBIDI_CHAR_SET_UTF8..=native::FriBidiCharSet_FRIBIDI_CHAR_SET_CP1256
+            {
+                assert_eq!(name(native::fribidi_char_set_name(char_set)), name(c::fribidi_char_set_name(char_set)));
+                assert_eq!(name(native::fribidi_char_set_title(char_set)), name(c::fribidi_char_set_title(char_set)));
+                assert_eq!(name(native::fribidi_char_set_desc(char_set)), name(c::fribidi_char_set_desc(char_set)));
+            }
+            for char_set in ["utf-8", "CAPRTL", "iso8859-6", "Iso8859-8", "cp1255", "CP1256", "UTF8", "", "CP1256 "]
+            {
+                let char_set = std::ffi::CString::new(char_set).unwrap();
+                assert_eq!(native::fribidi_parse_charset(char_set.as_ptr()), c::fribidi_parse_charset(char_set.as_ptr()));
+            }
+
+            let mut random = Random(3);
+            for round in 0..20000
+            {
+                let char_set = 1 + round as u32 % 6;
+
+                // CapRTL escapes and UTF-8 sequences need a dense alphabet
+                let alphabet: &[u8] = match random.next(2)
+                {
+                    0 => b"_><lrLRoiyfIa$1 ",
+                    _ => &[0x41, 0x5f, 0x80, 0xbf, 0xc3, 0xa9, 0xe2, 0x80, 0x8f, 0xf0, 0x9f, 0x98, 0x80, 0xff]
+                };
+                let mut bytes: Vec<u8> = (0..random.next(24)).map(|_| alphabet[random.next(alphabet.len())]).collect();
+                // the C decoders read past a truncated sequence, give them NULs
+                let len = bytes.len() as i32;
+                bytes.extend([0; 4]);
+                let mut us = vec![0; bytes.len()];
+                let mut c_us = vec![0; bytes.len()];
+                let n = native::fribidi_charset_to_unicode(char_set, bytes.as_ptr() as *const _, len, us.as_mut_ptr());
+                let c_n = c::fribidi_charset_to_unicode(char_set, bytes.as_ptr() as *const _, len, c_us.as_mut_ptr());
+                assert_eq!((n, &us[..n as usize]), (c_n, &c_us[..c_n as usize]), "{} {:x?}", char_set, bytes);
+
+                let text: Vec<u32> = (0..random.next(24)).map(|_| match random.next(3)
+                {
+                    0 => CHARS[random.next(CHARS.len())],
+                    1 => random.next(0x800) as u32,
+                    _ => random.next(0x110000) as u32
+                }).collect();
+                let mut s = vec![0; text.len() * 4 + 1];
+                let mut c_s = vec![0; text.len() * 4 + 1];
+                let n = native::fribidi_unicode_to_charset(char_set, text.as_ptr(), text.len() as i32, s.as_mut_ptr());
+                let c_n = c::fribidi_unicode_to_charset(char_set, text.as_ptr(), text.len() as i32, c_s.as_mut_ptr());
+                assert_eq!((n, &s), (c_n, &c_s), "{} {:x?}", char_set, text);
+            }
+        }
+    }
+}
+
@@ -0,0 +1,927 @@
+// Generated by tools/gen_native_tables.py from GNU FriBidi 1.0.8, Unicode 10.0.0.
+// Do not edit.
+
+/// Bidi types of the characters that are not LTR, as (first, last, type).
+pub(crate) static BIDI_TYPES: [(u32, u32, u32); 680] = [
+    (0x0000, 0x0008, 0x100820), (0x0009, 0x0009, 0x402840), (0x000a, 0x000a, 0x202840),
+    (0x000b, 0x000b, 0x402840), (0x000c, 0x000c, 0x800840), (0x000d, 0x000d, 0x202840),
+    (0x000e, 0x001b, 0x100820), (0x001c, 0x001e, 0x202840), (0x001f, 0x001f, 0x402840),
+    (0x0020, 0x0020, 0x800840), (0x0021, 0x0022, 0x40), (0x0023, 0x0025, 0x20420),
+    (0x0026, 0x002a, 0x40), (0x002b, 0x002b, 0x10420), (0x002c, 0x002c, 0x40420),
+    (0x002d, 0x002d, 0x10420), (0x002e, 0x002f, 0x40420), (0x0030, 0x0039, 0x220),
+    (0x003a, 0x003a, 0x40420), (0x003b, 0x0040, 0x40), (0x005b, 0x0060, 0x40),
+    (0x007b, 0x007e, 0x40), (0x007f, 0x0084, 0x100820), (0x0085, 0x0085, 0x202840),
+    (0x0086, 0x009f, 0x100820), (0x00a0, 0x00a0, 0x40420), (0x00a1, 0x00a1, 0x40),
+    (0x00a2, 0x00a5, 0x20420), (0x00a6, 0x00a9, 0x40), (0x00ab, 0x00ac, 0x40),
+    (0x00ad, 0x00ad, 0x100820), (0x00ae, 0x00af, 0x40), (0x00b0, 0x00b1, 0x20420),
+    (0x00b2, 0x00b3, 0x220), (0x00b4, 0x00b4, 0x40), (0x00b6, 0x00b8, 0x40),
+    (0x00b9, 0x00b9, 0x220), (0x00bb, 0x00bf, 0x40), (0x00d7, 0x00d7, 0x40),
+    (0x00f7, 0x00f7, 0x40), (0x02b9, 0x02ba, 0x40), (0x02c2, 0x02cf, 0x40),
+    (0x02d2, 0x02df, 0x40), (0x02e5, 0x02ed, 0x40), (0x02ef, 0x02ff, 0x40),
+    (0x0300, 0x036f, 0x80020), (0x0374, 0x0375, 0x40), (0x037e, 0x037e, 0x40),
+    (0x0384, 0x0385, 0x40), (0x0387, 0x0387, 0x40), (0x03f6, 0x03f6, 0x40),
+    (0x0483, 0x0489, 0x80020), (0x058a, 0x058a, 0x40), (0x058d, 0x058e, 0x40),
+    (0x058f, 0x058f, 0x20420), (0x0590, 0x0590, 0x111), (0x0591, 0x05bd, 0x80020),
+    (0x05be, 0x05be, 0x111), (0x05bf, 0x05bf, 0x80020), (0x05c0, 0x05c0, 0x111),
+    (0x05c1, 0x05c2, 0x80020), (0x05c3, 0x05c3, 0x111), (0x05c4, 0x05c5, 0x80020),
+    (0x05c6, 0x05c6, 0x111), (0x05c7, 0x05c7, 0x80020), (0x05c8, 0x05ff, 0x111),
+    (0x0600, 0x0605, 0x222), (0x0606, 0x0607, 0x40), (0x0608, 0x0608, 0x113),
+    (0x0609, 0x060a, 0x20420), (0x060b, 0x060b, 0x113), (0x060c, 0x060c, 0x40420),
+    (0x060d, 0x060d, 0x113), (0x060e, 0x060f, 0x40), (0x0610, 0x061a, 0x80020),
+    (0x061b, 0x064a, 0x113), (0x064b, 0x065f, 0x80020), (0x0660, 0x0669, 0x222),
+    (0x066a, 0x066a, 0x20420), (0x066b, 0x066c, 0x222), (0x066d, 0x066f, 0x113),
+    (0x0670, 0x0670, 0x80020), (0x0671, 0x06d5, 0x113), (0x06d6, 0x06dc, 0x80020),
+    (0x06dd, 0x06dd, 0x222), (0x06de, 0x06de, 0x40), (0x06df, 0x06e4, 0x80020),
+    (0x06e5, 0x06e6, 0x113), (0x06e7, 0x06e8, 0x80020), (0x06e9, 0x06e9, 0x40),
+    (0x06ea, 0x06ed, 0x80020), (0x06ee, 0x06ef, 0x113), (0x06f0, 0x06f9, 0x220),
+    (0x06fa, 0x0710, 0x113), (0x0711, 0x0711, 0x80020), (0x0712, 0x072f, 0x113),
+    (0x0730, 0x074a, 0x80020), (0x074b, 0x07a5, 0x113), (0x07a6, 0x07b0, 0x80020),
+    (0x07b1, 0x07bf, 0x113), (0x07c0, 0x07ea, 0x111), (0x07eb, 0x07f3, 0x80020),
+    (0x07f4, 0x07f5, 0x111), (0x07f6, 0x07f9, 0x40), (0x07fa, 0x0815, 0x111),
+    (0x0816, 0x0819, 0x80020), (0x081a, 0x081a, 0x111), (0x081b, 0x0823, 0x80020),
+    (0x0824, 0x0824, 0x111), (0x0825, 0x0827, 0x80020), (0x0828, 0x0828, 0x111),
+    (0x0829, 0x082d, 0x80020), (0x082e, 0x0858, 0x111), (0x0859, 0x085b, 0x80020),
+    (0x085c, 0x085f, 0x111), (0x0860, 0x086a, 0x113), (0x086b, 0x089f, 0x111),
+    (0x08a0, 0x08b4, 0x113), (0x08b5, 0x08b5, 0x111), (0x08b6, 0x08bd, 0x113),
+    (0x08be, 0x08d3, 0x111), (0x08d4, 0x08e1, 0x80020), (0x08e2, 0x08e2, 0x222),
+    (0x08e3, 0x0902, 0x80020), (0x093a, 0x093a, 0x80020), (0x093c, 0x093c, 0x80020),
+    (0x0941, 0x0948, 0x80020), (0x094d, 0x094d, 0x80020), (0x0951, 0x0957, 0x80020),
+    (0x0962, 0x0963, 0x80020), (0x0981, 0x0981, 0x80020), (0x09bc, 0x09bc, 0x80020),
+    (0x09c1, 0x09c4, 0x80020), (0x09cd, 0x09cd, 0x80020), (0x09e2, 0x09e3, 0x80020),
+    (0x09f2, 0x09f3, 0x20420), (0x09fb, 0x09fb, 0x20420), (0x0a01, 0x0a02, 0x80020),
+    (0x0a3c, 0x0a3c, 0x80020), (0x0a41, 0x0a42, 0x80020), (0x0a47, 0x0a48, 0x80020),
+    (0x0a4b, 0x0a4d, 0x80020), (0x0a51, 0x0a51, 0x80020), (0x0a70, 0x0a71, 0x80020),
+    (0x0a75, 0x0a75, 0x80020), (0x0a81, 0x0a82, 0x80020), (0x0abc, 0x0abc, 0x80020),
+    (0x0ac1, 0x0ac5, 0x80020), (0x0ac7, 0x0ac8, 0x80020), (0x0acd, 0x0acd, 0x80020),
+    (0x0ae2, 0x0ae3, 0x80020), (0x0af1, 0x0af1, 0x20420), (0x0afa, 0x0aff, 0x80020),
+    (0x0b01, 0x0b01, 0x80020), (0x0b3c, 0x0b3c, 0x80020), (0x0b3f, 0x0b3f, 0x80020),
+    (0x0b41, 0x0b44, 0x80020), (0x0b4d, 0x0b4d, 0x80020), (0x0b56, 0x0b56, 0x80020),
+    (0x0b62, 0x0b63, 0x80020), (0x0b82, 0x0b82, 0x80020), (0x0bc0, 0x0bc0, 0x80020),
+    (0x0bcd, 0x0bcd, 0x80020), (0x0bf3, 0x0bf8, 0x40), (0x0bf9, 0x0bf9, 0x20420),
+    (0x0bfa, 0x0bfa, 0x40), (0x0c00, 0x0c00, 0x80020), (0x0c3e, 0x0c40, 0x80020),
+    (0x0c46, 0x0c48, 0x80020), (0x0c4a, 0x0c4d, 0x80020), (0x0c55, 0x0c56, 0x80020),
+    (0x0c62, 0x0c63, 0x80020), (0x0c78, 0x0c7e, 0x40), (0x0c81, 0x0c81, 0x80020),
+    (0x0cbc, 0x0cbc, 0x80020), (0x0ccc, 0x0ccd, 0x80020), (0x0ce2, 0x0ce3, 0x80020),
+    (0x0d00, 0x0d01, 0x80020), (0x0d3b, 0x0d3c, 0x80020), (0x0d41, 0x0d44, 0x80020),
+    (0x0d4d, 0x0d4d, 0x80020), (0x0d62, 0x0d63, 0x80020), (0x0dca, 0x0dca, 0x80020),
+    (0x0dd2, 0x0dd4, 0x80020), (0x0dd6, 0x0dd6, 0x80020), (0x0e31, 0x0e31, 0x80020),
+    (0x0e34, 0x0e3a, 0x80020), (0x0e3f, 0x0e3f, 0x20420), (0x0e47, 0x0e4e, 0x80020),
+    (0x0eb1, 0x0eb1, 0x80020), (0x0eb4, 0x0eb9, 0x80020), (0x0ebb, 0x0ebc, 0x80020),
+    (0x0ec8, 0x0ecd, 0x80020), (0x0f18, 0x0f19, 0x80020), (0x0f35, 0x0f35, 0x80020),
+    (0x0f37, 0x0f37, 0x80020), (0x0f39, 0x0f39, 0x80020), (0x0f3a, 0x0f3d, 0x40),
+    (0x0f71, 0x0f7e, 0x80020), (0x0f80, 0x0f84, 0x80020), (0x0f86, 0x0f87, 0x80020),
+    (0x0f8d, 0x0f97, 0x80020), (0x0f99, 0x0fbc, 0x80020), (0x0fc6, 0x0fc6, 0x80020),
+    (0x102d, 0x1030, 0x80020), (0x1032, 0x1037, 0x80020), (0x1039, 0x103a, 0x80020),
+    (0x103d, 0x103e, 0x80020), (0x1058, 0x1059, 0x80020), (0x105e, 0x1060, 0x80020),
+    (0x1071, 0x1074, 0x80020), (0x1082, 0x1082, 0x80020), (0x1085, 0x1086, 0x80020),
+    (0x108d, 0x108d, 0x80020), (0x109d, 0x109d, 0x80020), (0x135d, 0x135f, 0x80020),
+    (0x1390, 0x1399, 0x40), (0x1400, 0x1400, 0x40), (0x1680, 0x1680, 0x800840),
+    (0x169b, 0x169c, 0x40), (0x1712, 0x1714, 0x80020), (0x1732, 0x1734, 0x80020),
+    (0x1752, 0x1753, 0x80020), (0x1772, 0x1773, 0x80020), (0x17b4, 0x17b5, 0x80020),
+    (0x17b7, 0x17bd, 0x80020), (0x17c6, 0x17c6, 0x80020), (0x17c9, 0x17d3, 0x80020),
+    (0x17db, 0x17db, 0x20420), (0x17dd, 0x17dd, 0x80020), (0x17f0, 0x17f9, 0x40),
+    (0x1800, 0x180a, 0x40), (0x180b, 0x180d, 0x80020), (0x180e, 0x180e, 0x100820),
+    (0x1885, 0x1886, 0x80020), (0x18a9, 0x18a9, 0x80020), (0x1920, 0x1922, 0x80020),
+    (0x1927, 0x1928, 0x80020), (0x1932, 0x1932, 0x80020), (0x1939, 0x193b, 0x80020),
+    (0x1940, 0x1940, 0x40), (0x1944, 0x1945, 0x40), (0x19de, 0x19ff, 0x40),
+    (0x1a17, 0x1a18, 0x80020), (0x1a1b, 0x1a1b, 0x80020), (0x1a56, 0x1a56, 0x80020),
+    (0x1a58, 0x1a5e, 0x80020), (0x1a60, 0x1a60, 0x80020), (0x1a62, 0x1a62, 0x80020),
+    (0x1a65, 0x1a6c, 0x80020), (0x1a73, 0x1a7c, 0x80020), (0x1a7f, 0x1a7f, 0x80020),
+    (0x1ab0, 0x1abe, 0x80020), (0x1b00, 0x1b03, 0x80020), (0x1b34, 0x1b34, 0x80020),
+    (0x1b36, 0x1b3a, 0x80020), (0x1b3c, 0x1b3c, 0x80020), (0x1b42, 0x1b42, 0x80020),
+    (0x1b6b, 0x1b73, 0x80020), (0x1b80, 0x1b81, 0x80020), (0x1ba2, 0x1ba5, 0x80020),
+    (0x1ba8, 0x1ba9, 0x80020), (0x1bab, 0x1bad, 0x80020), (0x1be6, 0x1be6, 0x80020),
+    (0x1be8, 0x1be9, 0x80020), (0x1bed, 0x1bed, 0x80020), (0x1bef, 0x1bf1, 0x80020),
+    (0x1c2c, 0x1c33, 0x80020), (0x1c36, 0x1c37, 0x80020), (0x1cd0, 0x1cd2, 0x80020),
+    (0x1cd4, 0x1ce0, 0x80020), (0x1ce2, 0x1ce8, 0x80020), (0x1ced, 0x1ced, 0x80020),
+    (0x1cf4, 0x1cf4, 0x80020), (0x1cf8, 0x1cf9, 0x80020), (0x1dc0, 0x1df9, 0x80020),
+    (0x1dfb, 0x1dff, 0x80020), (0x1fbd, 0x1fbd, 0x40), (0x1fbf, 0x1fc1, 0x40),
+    (0x1fcd, 0x1fcf, 0x40), (0x1fdd, 0x1fdf, 0x40), (0x1fed, 0x1fef, 0x40),
+    (0x1ffd, 0x1ffe, 0x40), (0x2000, 0x200a, 0x800840), (0x200b, 0x200d, 0x100820),
+    (0x200f, 0x200f, 0x111), (0x2010, 0x2027, 0x40), (0x2028, 0x2028, 0x800840),
+    (0x2029, 0x2029, 0x202840), (0x202a, 0x202a, 0x1010), (0x202b, 0x202b, 0x1011),
+    (0x202c, 0x202c, 0x1020), (0x202d, 0x202d, 0x5010), (0x202e, 0x202e, 0x5011),
+    (0x202f, 0x202f, 0x40420), (0x2030, 0x2034, 0x20420), (0x2035, 0x2043, 0x40),
+    (0x2044, 0x2044, 0x40420), (0x2045, 0x205e, 0x40), (0x205f, 0x205f, 0x800840),
+    (0x2060, 0x2065, 0x100820), (0x2066, 0x2066, 0x8040), (0x2067, 0x2067, 0x8041),
+    (0x2068, 0x2068, 0x2008040), (0x2069, 0x2069, 0x8060), (0x206a, 0x206f, 0x100820),
+    (0x2070, 0x2070, 0x220), (0x2074, 0x2079, 0x220), (0x207a, 0x207b, 0x10420),
+    (0x207c, 0x207e, 0x40), (0x2080, 0x2089, 0x220), (0x208a, 0x208b, 0x10420),
+    (0x208c, 0x208e, 0x40), (0x20a0, 0x20bf, 0x20420), (0x20d0, 0x20f0, 0x80020),
+    (0x2100, 0x2101, 0x40), (0x2103, 0x2106, 0x40), (0x2108, 0x2109, 0x40),
+    (0x2114, 0x2114, 0x40), (0x2116, 0x2118, 0x40), (0x211e, 0x2123, 0x40),
+    (0x2125, 0x2125, 0x40), (0x2127, 0x2127, 0x40), (0x2129, 0x2129, 0x40),
+    (0x212e, 0x212e, 0x20420), (0x213a, 0x213b, 0x40), (0x2140, 0x2144, 0x40),
+    (0x214a, 0x214d, 0x40), (0x2150, 0x215f, 0x40), (0x2189, 0x218b, 0x40),
+    (0x2190, 0x2211, 0x40), (0x2212, 0x2212, 0x10420), (0x2213, 0x2213, 0x20420),
+    (0x2214, 0x2335, 0x40), (0x237b, 0x2394, 0x40), (0x2396, 0x2426, 0x40),
+    (0x2440, 0x244a, 0x40), (0x2460, 0x2487, 0x40), (0x2488, 0x249b, 0x220),
+    (0x24ea, 0x26ab, 0x40), (0x26ad, 0x27ff, 0x40), (0x2900, 0x2b73, 0x40),
+    (0x2b76, 0x2b95, 0x40), (0x2b98, 0x2bb9, 0x40), (0x2bbd, 0x2bc8, 0x40),
+    (0x2bca, 0x2bd2, 0x40), (0x2bec, 0x2bef, 0x40), (0x2ce5, 0x2cea, 0x40),
+    (0x2cef, 0x2cf1, 0x80020), (0x2cf9, 0x2cff, 0x40), (0x2d7f, 0x2d7f, 0x80020),
+    (0x2de0, 0x2dff, 0x80020), (0x2e00, 0x2e49, 0x40), (0x2e80, 0x2e99, 0x40),
+    (0x2e9b, 0x2ef3, 0x40), (0x2f00, 0x2fd5, 0x40), (0x2ff0, 0x2ffb, 0x40),
+    (0x3000, 0x3000, 0x800840), (0x3001, 0x3004, 0x40), (0x3008, 0x3020, 0x40),
+    (0x302a, 0x302d, 0x80020), (0x3030, 0x3030, 0x40), (0x3036, 0x3037, 0x40),
+    (0x303d, 0x303f, 0x40), (0x3099, 0x309a, 0x80020), (0x309b, 0x309c, 0x40),
+    (0x30a0, 0x30a0, 0x40), (0x30fb, 0x30fb, 0x40), (0x31c0, 0x31e3, 0x40),
+    (0x321d, 0x321e, 0x40), (0x3250, 0x325f, 0x40), (0x327c, 0x327e, 0x40),
+    (0x32b1, 0x32bf, 0x40), (0x32cc, 0x32cf, 0x40), (0x3377, 0x337a, 0x40),
+    (0x33de, 0x33df, 0x40), (0x33ff, 0x33ff, 0x40), (0x4dc0, 0x4dff, 0x40),
+    (0xa490, 0xa4c6, 0x40), (0xa60d, 0xa60f, 0x40), (0xa66f, 0xa672, 0x80020),
+    (0xa673, 0xa673, 0x40), (0xa674, 0xa67d, 0x80020), (0xa67e, 0xa67f, 0x40),
+    (0xa69e, 0xa69f, 0x80020), (0xa6f0, 0xa6f1, 0x80020), (0xa700, 0xa721, 0x40),
+    (0xa788, 0xa788, 0x40), (0xa802, 0xa802, 0x80020), (0xa806, 0xa806, 0x80020),
+    (0xa80b, 0xa80b, 0x80020), (0xa825, 0xa826, 0x80020), (0xa828, 0xa82b, 0x40),
+    (0xa838, 0xa839, 0x20420), (0xa874, 0xa877, 0x40), (0xa8c4, 0xa8c5, 0x80020),
+    (0xa8e0, 0xa8f1, 0x80020), (0xa926, 0xa92d, 0x80020), (0xa947, 0xa951, 0x80020),
+    (0xa980, 0xa982, 0x80020), (0xa9b3, 0xa9b3, 0x80020), (0xa9b6, 0xa9b9, 0x80020),
+    (0xa9bc, 0xa9bc, 0x80020), (0xa9e5, 0xa9e5, 0x80020), (0xaa29, 0xaa2e, 0x80020),
+    (0xaa31, 0xaa32, 0x80020), (0xaa35, 0xaa36, 0x80020), (0xaa43, 0xaa43, 0x80020),
+    (0xaa4c, 0xaa4c, 0x80020), (0xaa7c, 0xaa7c, 0x80020), (0xaab0, 0xaab0, 0x80020),
+    (0xaab2, 0xaab4, 0x80020), (0xaab7, 0xaab8, 0x80020), (0xaabe, 0xaabf, 0x80020),
+    (0xaac1, 0xaac1, 0x80020), (0xaaec, 0xaaed, 0x80020), (0xaaf6, 0xaaf6, 0x80020),
+    (0xabe5, 0xabe5, 0x80020), (0xabe8, 0xabe8, 0x80020), (0xabed, 0xabed, 0x80020),
+    (0xfb1d, 0xfb1d, 0x111), (0xfb1e, 0xfb1e, 0x80020), (0xfb1f, 0xfb28, 0x111),
+    (0xfb29, 0xfb29, 0x10420), (0xfb2a, 0xfb4f, 0x111), (0xfb50, 0xfd3d, 0x113),
+    (0xfd3e, 0xfd3f, 0x40), (0xfd40, 0xfdcf, 0x113), (0xfdd0, 0xfdef, 0x100820),
+    (0xfdf0, 0xfdfc, 0x113), (0xfdfd, 0xfdfd, 0x40), (0xfdfe, 0xfdff, 0x113),
+    (0xfe00, 0xfe0f, 0x80020), (0xfe10, 0xfe19, 0x40), (0xfe20, 0xfe2f, 0x80020),
+    (0xfe30, 0xfe4f, 0x40), (0xfe50, 0xfe50, 0x40420), (0xfe51, 0xfe51, 0x40),
+    (0xfe52, 0xfe52, 0x40420), (0xfe54, 0xfe54, 0x40), (0xfe55, 0xfe55, 0x40420),
+    (0xfe56, 0xfe5e, 0x40), (0xfe5f, 0xfe5f, 0x20420), (0xfe60, 0xfe61, 0x40),
+    (0xfe62, 0xfe63, 0x10420), (0xfe64, 0xfe66, 0x40), (0xfe68, 0xfe68, 0x40),
+    (0xfe69, 0xfe6a, 0x20420), (0xfe6b, 0xfe6b, 0x40), (0xfe70, 0xfefe, 0x113),
+    (0xfeff, 0xfeff, 0x100820), (0xff01, 0xff02, 0x40), (0xff03, 0xff05, 0x20420),
+    (0xff06, 0xff0a, 0x40), (0xff0b, 0xff0b, 0x10420), (0xff0c, 0xff0c, 0x40420),
+    (0xff0d, 0xff0d, 0x10420), (0xff0e, 0xff0f, 0x40420), (0xff10, 0xff19, 0x220),
+    (0xff1a, 0xff1a, 0x40420), (0xff1b, 0xff20, 0x40), (0xff3b, 0xff40, 0x40),
+    (0xff5b, 0xff65, 0x40), (0xffe0, 0xffe1, 0x20420), (0xffe2, 0xffe4, 0x40),
+    (0xffe5, 0xffe6, 0x20420), (0xffe8, 0xffee, 0x40), (0xfff0, 0xfff8, 0x100820),
+    (0xfff9, 0xfffd, 0x40), (0xfffe, 0xffff, 0x100820), (0x10101, 0x10101, 0x40),
+    (0x10140, 0x1018c, 0x40), (0x10190, 0x1019b, 0x40), (0x101a0, 0x101a0, 0x40),
+    (0x101fd, 0x101fd, 0x80020), (0x102e0, 0x102e0, 0x80020), (0x102e1, 0x102fb, 0x220),
+    (0x10376, 0x1037a, 0x80020), (0x10800, 0x1091e, 0x111), (0x1091f, 0x1091f, 0x40),
+    (0x10920, 0x10a00, 0x111), (0x10a01, 0x10a03, 0x80020), (0x10a04, 0x10a04, 0x111),
+    (0x10a05, 0x10a06, 0x80020), (0x10a07, 0x10a0b, 0x111), (0x10a0c, 0x10a0f, 0x80020),
+    (0x10a10, 0x10a37, 0x111), (0x10a38, 0x10a3a, 0x80020), (0x10a3b, 0x10a3e, 0x111),
+    (0x10a3f, 0x10a3f, 0x80020), (0x10a40, 0x10ae4, 0x111), (0x10ae5, 0x10ae6, 0x80020),
+    (0x10ae7, 0x10b38, 0x111), (0x10b39, 0x10b3f, 0x40), (0x10b40, 0x10e5f, 0x111),
+    (0x10e60, 0x10e7e, 0x222), (0x10e7f, 0x10fff, 0x111), (0x11001, 0x11001, 0x80020),
+    (0x11038, 0x11046, 0x80020), (0x11052, 0x11065, 0x40), (0x1107f, 0x11081, 0x80020),
+    (0x110b3, 0x110b6, 0x80020), (0x110b9, 0x110ba, 0x80020), (0x11100, 0x11102, 0x80020),
+    (0x11127, 0x1112b, 0x80020), (0x1112d, 0x11134, 0x80020), (0x11173, 0x11173, 0x80020),
+    (0x11180, 0x11181, 0x80020), (0x111b6, 0x111be, 0x80020), (0x111ca, 0x111cc, 0x80020),
+    (0x1122f, 0x11231, 0x80020), (0x11234, 0x11234, 0x80020), (0x11236, 0x11237, 0x80020),
+    (0x1123e, 0x1123e, 0x80020), (0x112df, 0x112df, 0x80020), (0x112e3, 0x112ea, 0x80020),
+    (0x11300, 0x11301, 0x80020), (0x1133c, 0x1133c, 0x80020), (0x11340, 0x11340, 0x80020),
+    (0x11366, 0x1136c, 0x80020), (0x11370, 0x11374, 0x80020), (0x11438, 0x1143f, 0x80020),
+    (0x11442, 0x11444, 0x80020), (0x11446, 0x11446, 0x80020), (0x114b3, 0x114b8, 0x80020),
+    (0x114ba, 0x114ba, 0x80020), (0x114bf, 0x114c0, 0x80020), (0x114c2, 0x114c3, 0x80020),
+    (0x115b2, 0x115b5, 0x80020), (0x115bc, 0x115bd, 0x80020), (0x115bf, 0x115c0, 0x80020),
+    (0x115dc, 0x115dd, 0x80020), (0x11633, 0x1163a, 0x80020), (0x1163d, 0x1163d, 0x80020),
+    (0x1163f, 0x11640, 0x80020), (0x11660, 0x1166c, 0x40), (0x116ab, 0x116ab, 0x80020),
+    (0x116ad, 0x116ad, 0x80020), (0x116b0, 0x116b5, 0x80020), (0x116b7, 0x116b7, 0x80020),
+    (0x1171d, 0x1171f, 0x80020), (0x11722, 0x11725, 0x80020), (0x11727, 0x1172b, 0x80020),
+    (0x11a01, 0x11a06, 0x80020), (0x11a09, 0x11a0a, 0x80020), (0x11a33, 0x11a38, 0x80020),
+    (0x11a3b, 0x11a3e, 0x80020), (0x11a47, 0x11a47, 0x80020), (0x11a51, 0x11a56, 0x80020),
+    (0x11a59, 0x11a5b, 0x80020), (0x11a8a, 0x11a96, 0x80020), (0x11a98, 0x11a99, 0x80020),
+    (0x11c30, 0x11c36, 0x80020), (0x11c38, 0x11c3d, 0x80020), (0x11c92, 0x11ca7, 0x80020),
+    (0x11caa, 0x11cb0, 0x80020), (0x11cb2, 0x11cb3, 0x80020), (0x11cb5, 0x11cb6, 0x80020),
+    (0x11d31, 0x11d36, 0x80020), (0x11d3a, 0x11d3a, 0x80020), (0x11d3c, 0x11d3d, 0x80020),
+    (0x11d3f, 0x11d45, 0x80020), (0x11d47, 0x11d47, 0x80020), (0x16af0, 0x16af4, 0x80020),
+    (0x16b30, 0x16b36, 0x80020), (0x16f8f, 0x16f92, 0x80020), (0x1bc9d, 0x1bc9e, 0x80020),
+    (0x1bca0, 0x1bca3, 0x100820), (0x1d167, 0x1d169, 0x80020), (0x1d173, 0x1d17a, 0x100820),
+    (0x1d17b, 0x1d182, 0x80020), (0x1d185, 0x1d18b, 0x80020), (0x1d1aa, 0x1d1ad, 0x80020),
+    (0x1d200, 0x1d241, 0x40), (0x1d242, 0x1d244, 0x80020), (0x1d245, 0x1d245, 0x40),
+    (0x1d300, 0x1d356, 0x40), (0x1d6db, 0x1d6db, 0x40), (0x1d715, 0x1d715, 0x40),
+    (0x1d74f, 0x1d74f, 0x40), (0x1d789, 0x1d789, 0x40), (0x1d7c3, 0x1d7c3, 0x40),
+    (0x1d7ce, 0x1d7ff, 0x220), (0x1da00, 0x1da36, 0x80020), (0x1da3b, 0x1da6c, 0x80020),
+    (0x1da75, 0x1da75, 0x80020), (0x1da84, 0x1da84, 0x80020), (0x1da9b, 0x1da9f, 0x80020),
+    (0x1daa1, 0x1daaf, 0x80020), (0x1e000, 0x1e006, 0x80020), (0x1e008, 0x1e018, 0x80020),
+    (0x1e01b, 0x1e021, 0x80020), (0x1e023, 0x1e024, 0x80020), (0x1e026, 0x1e02a, 0x80020),
+    (0x1e800, 0x1e8c4, 0x111), (0x1e8c7, 0x1e8cf, 0x111), (0x1e8d0, 0x1e8d6, 0x80020),
+    (0x1e900, 0x1e943, 0x111), (0x1e944, 0x1e94a, 0x80020), (0x1e950, 0x1e959, 0x111),
+    (0x1e95e, 0x1e95f, 0x111), (0x1ee00, 0x1ee03, 0x113), (0x1ee05, 0x1ee1f, 0x113),
+    (0x1ee21, 0x1ee22, 0x113), (0x1ee24, 0x1ee24, 0x113), (0x1ee27, 0x1ee27, 0x113),
+    (0x1ee29, 0x1ee32, 0x113), (0x1ee34, 0x1ee37, 0x113), (0x1ee39, 0x1ee39, 0x113),
+    (0x1ee3b, 0x1ee3b, 0x113), (0x1ee42, 0x1ee42, 0x113), (0x1ee47, 0x1ee47, 0x113),
+    (0x1ee49, 0x1ee49, 0x113), (0x1ee4b, 0x1ee4b, 0x113), (0x1ee4d, 0x1ee4f, 0x113),
+    (0x1ee51, 0x1ee52, 0x113), (0x1ee54, 0x1ee54, 0x113), (0x1ee57, 0x1ee57, 0x113),
+    (0x1ee59, 0x1ee59, 0x113), (0x1ee5b, 0x1ee5b, 0x113), (0x1ee5d, 0x1ee5d, 0x113),
+    (0x1ee5f, 0x1ee5f, 0x113), (0x1ee61, 0x1ee62, 0x113), (0x1ee64, 0x1ee64, 0x113),
+    (0x1ee67, 0x1ee6a, 0x113), (0x1ee6c, 0x1ee72, 0x113), (0x1ee74, 0x1ee77, 0x113),
+    (0x1ee79, 0x1ee7c, 0x113), (0x1ee7e, 0x1ee7e, 0x113), (0x1ee80, 0x1ee89, 0x113),
+    (0x1ee8b, 0x1ee9b, 0x113), (0x1eea1, 0x1eea3, 0x113), (0x1eea5, 0x1eea9, 0x113),
+    (0x1eeab, 0x1eebb, 0x113), (0x1eef0, 0x1eef1, 0x40), (0x1f000, 0x1f02b, 0x40),
+    (0x1f030, 0x1f093, 0x40), (0x1f0a0, 0x1f0ae, 0x40), (0x1f0b1, 0x1f0bf, 0x40),
+    (0x1f0c1, 0x1f0cf, 0x40), (0x1f0d1, 0x1f0f5, 0x40), (0x1f100, 0x1f10a, 0x220),
+    (0x1f10b, 0x1f10c, 0x40), (0x1f16a, 0x1f16b, 0x40), (0x1f260, 0x1f265, 0x40),
+    (0x1f300, 0x1f6d4, 0x40), (0x1f6e0, 0x1f6ec, 0x40), (0x1f6f0, 0x1f6f8, 0x40),
+    (0x1f700, 0x1f773, 0x40), (0x1f780, 0x1f7d4, 0x40), (0x1f800, 0x1f80b, 0x40),
+    (0x1f810, 0x1f847, 0x40), (0x1f850, 0x1f859, 0x40), (0x1f860, 0x1f887, 0x40),
+    (0x1f890, 0x1f8ad, 0x40), (0x1f900, 0x1f90b, 0x40), (0x1f910, 0x1f93e, 0x40),
+    (0x1f940, 0x1f94c, 0x40), (0x1f950, 0x1f96b, 0x40), (0x1f980, 0x1f997, 0x40),
+    (0x1f9c0, 0x1f9c0, 0x40), (0x1f9d0, 0x1f9e6, 0x40), (0x1fffe, 0x1ffff, 0x100820),
+    (0x2fffe, 0x2ffff, 0x100820), (0x3fffe, 0x3ffff, 0x100820), (0x4fffe, 0x4ffff, 0x100820),
+    (0x5fffe, 0x5ffff, 0x100820), (0x6fffe, 0x6ffff, 0x100820), (0x7fffe, 0x7ffff, 0x100820),
+    (0x8fffe, 0x8ffff, 0x100820), (0x9fffe, 0x9ffff, 0x100820), (0xafffe, 0xaffff, 0x100820),
+    (0xbfffe, 0xbffff, 0x100820), (0xcfffe, 0xcffff, 0x100820), (0xdfffe, 0xe00ff, 0x100820),
+    (0xe0100, 0xe01ef, 0x80020), (0xe01f0, 0xe0fff, 0x100820), (0xefffe, 0xeffff, 0x100820),
+    (0xffffe, 0xfffff, 0x100820), (0x10fffe, 0x10ffff, 0x100820),
+];
+
+/// Joining types of the characters that are not U, as (first, last, type).
+pub(crate) static JOINING_TYPES: [(u32, u32, u8); 438] = [
+    (0x0000, 0x0008, 16), (0x000e, 0x001b, 16), (0x007f, 0x0084, 16), (0x0086, 0x009f, 16),
+    (0x00ad, 0x00ad, 12), (0x0300, 0x036f, 12), (0x0483, 0x0487, 12), (0x0591, 0x05bd, 12),
+    (0x05bf, 0x05bf, 12), (0x05c1, 0x05c2, 12), (0x05c4, 0x05c5, 12), (0x05c7, 0x05c7, 12),
+    (0x0610, 0x061a, 12), (0x061c, 0x061c, 12), (0x0620, 0x0620, 7), (0x0622, 0x0625, 5),
+    (0x0626, 0x0626, 7), (0x0627, 0x0627, 5), (0x0628, 0x0628, 7), (0x0629, 0x0629, 5),
+    (0x062a, 0x062e, 7), (0x062f, 0x0632, 5), (0x0633, 0x063f, 7), (0x0640, 0x0640, 3),
+    (0x0641, 0x0647, 7), (0x0648, 0x0648, 5), (0x0649, 0x064a, 7), (0x064b, 0x065f, 12),
+    (0x066e, 0x066f, 7), (0x0670, 0x0670, 12), (0x0671, 0x0673, 5), (0x0675, 0x0677, 5),
+    (0x0678, 0x0687, 7), (0x0688, 0x0699, 5), (0x069a, 0x06bf, 7), (0x06c0, 0x06c0, 5),
+    (0x06c1, 0x06c2, 7), (0x06c3, 0x06cb, 5), (0x06cc, 0x06cc, 7), (0x06cd, 0x06cd, 5),
+    (0x06ce, 0x06ce, 7), (0x06cf, 0x06cf, 5), (0x06d0, 0x06d1, 7), (0x06d2, 0x06d3, 5),
+    (0x06d5, 0x06d5, 5), (0x06d6, 0x06dc, 12), (0x06df, 0x06e4, 12), (0x06e7, 0x06e8, 12),
+    (0x06ea, 0x06ed, 12), (0x06ee, 0x06ef, 5), (0x06fa, 0x06fc, 7), (0x06ff, 0x06ff, 7),
+    (0x070f, 0x070f, 12), (0x0710, 0x0710, 5), (0x0711, 0x0711, 12), (0x0712, 0x0714, 7),
+    (0x0715, 0x0719, 5), (0x071a, 0x071d, 7), (0x071e, 0x071e, 5), (0x071f, 0x0727, 7),
+    (0x0728, 0x0728, 5), (0x0729, 0x0729, 7), (0x072a, 0x072a, 5), (0x072b, 0x072b, 7),
+    (0x072c, 0x072c, 5), (0x072d, 0x072e, 7), (0x072f, 0x072f, 5), (0x0730, 0x074a, 12),
+    (0x074d, 0x074d, 5), (0x074e, 0x0758, 7), (0x0759, 0x075b, 5), (0x075c, 0x076a, 7),
+    (0x076b, 0x076c, 5), (0x076d, 0x0770, 7), (0x0771, 0x0771, 5), (0x0772, 0x0772, 7),
+    (0x0773, 0x0774, 5), (0x0775, 0x0777, 7), (0x0778, 0x0779, 5), (0x077a, 0x077f, 7),
+    (0x07a6, 0x07b0, 12), (0x07ca, 0x07ea, 7), (0x07eb, 0x07f3, 12), (0x07fa, 0x07fa, 3),
+    (0x0816, 0x0819, 12), (0x081b, 0x0823, 12), (0x0825, 0x0827, 12), (0x0829, 0x082d, 12),
+    (0x0840, 0x0840, 5), (0x0841, 0x0845, 7), (0x0846, 0x0847, 5), (0x0848, 0x0848, 7),
+    (0x0849, 0x0849, 5), (0x084a, 0x0853, 7), (0x0854, 0x0854, 5), (0x0855, 0x0855, 7),
+    (0x0859, 0x085b, 12), (0x0860, 0x0860, 7), (0x0862, 0x0865, 7), (0x0867, 0x0867, 5),
+    (0x0868, 0x0868, 7), (0x0869, 0x086a, 5), (0x08a0, 0x08a9, 7), (0x08aa, 0x08ac, 5),
+    (0x08ae, 0x08ae, 5), (0x08af, 0x08b0, 7), (0x08b1, 0x08b2, 5), (0x08b3, 0x08b4, 7),
+    (0x08b6, 0x08b8, 7), (0x08b9, 0x08b9, 5), (0x08ba, 0x08bd, 7), (0x08d4, 0x08e1, 12),
+    (0x08e3, 0x0902, 12), (0x093a, 0x093a, 12), (0x093c, 0x093c, 12), (0x0941, 0x0948, 12),
+    (0x094d, 0x094d, 12), (0x0951, 0x0957, 12), (0x0962, 0x0963, 12), (0x0981, 0x0981, 12),
+    (0x09bc, 0x09bc, 12), (0x09c1, 0x09c4, 12), (0x09cd, 0x09cd, 12), (0x09e2, 0x09e3, 12),
+    (0x0a01, 0x0a02, 12), (0x0a3c, 0x0a3c, 12), (0x0a41, 0x0a42, 12), (0x0a47, 0x0a48, 12),
+    (0x0a4b, 0x0a4d, 12), (0x0a51, 0x0a51, 12), (0x0a70, 0x0a71, 12), (0x0a75, 0x0a75, 12),
+    (0x0a81, 0x0a82, 12), (0x0abc, 0x0abc, 12), (0x0ac1, 0x0ac5, 12), (0x0ac7, 0x0ac8, 12),
+    (0x0acd, 0x0acd, 12), (0x0ae2, 0x0ae3, 12), (0x0afa, 0x0aff, 12), (0x0b01, 0x0b01, 12),
+    (0x0b3c, 0x0b3c, 12), (0x0b3f, 0x0b3f, 12), (0x0b41, 0x0b44, 12), (0x0b4d, 0x0b4d, 12),
+    (0x0b56, 0x0b56, 12), (0x0b62, 0x0b63, 12), (0x0b82, 0x0b82, 12), (0x0bc0, 0x0bc0, 12),
+    (0x0bcd, 0x0bcd, 12), (0x0c00, 0x0c00, 12), (0x0c3e, 0x0c40, 12), (0x0c46, 0x0c48, 12),
+    (0x0c4a, 0x0c4d, 12), (0x0c55, 0x0c56, 12), (0x0c62, 0x0c63, 12), (0x0c81, 0x0c81, 12),
+    (0x0cbc, 0x0cbc, 12), (0x0cbf, 0x0cbf, 12), (0x0cc6, 0x0cc6, 12), (0x0ccc, 0x0ccd, 12),
+    (0x0ce2, 0x0ce3, 12), (0x0d00, 0x0d01, 12), (0x0d3b, 0x0d3c, 12), (0x0d41, 0x0d44, 12),
+    (0x0d4d, 0x0d4d, 12), (0x0d62, 0x0d63, 12), (0x0dca, 0x0dca, 12), (0x0dd2, 0x0dd4, 12),
+    (0x0dd6, 0x0dd6, 12), (0x0e31, 0x0e31, 12), (0x0e34, 0x0e3a, 12), (0x0e47, 0x0e4e, 12),
+    (0x0eb1, 0x0eb1, 12), (0x0eb4, 0x0eb9, 12), (0x0ebb, 0x0ebc, 12), (0x0ec8, 0x0ecd, 12),
+    (0x0f18, 0x0f19, 12), (0x0f35, 0x0f35, 12), (0x0f37, 0x0f37, 12), (0x0f39, 0x0f39, 12),
+    (0x0f71, 0x0f7e, 12), (0x0f80, 0x0f84, 12), (0x0f86, 0x0f87, 12), (0x0f8d, 0x0f97, 12),
+    (0x0f99, 0x0fbc, 12), (0x0fc6, 0x0fc6, 12), (0x102d, 0x1030, 12), (0x1032, 0x1037, 12),
+    (0x1039, 0x103a, 12), (0x103d, 0x103e, 12), (0x1058, 0x1059, 12), (0x105e, 0x1060, 12),
+    (0x1071, 0x1074, 12), (0x1082, 0x1082, 12), (0x1085, 0x1086, 12), (0x108d, 0x108d, 12),
+    (0x109d, 0x109d, 12), (0x135d, 0x135f, 12), (0x1712, 0x1714, 12), (0x1732, 0x1734, 12),
+    (0x1752, 0x1753, 12), (0x1772, 0x1773, 12), (0x17b4, 0x17b5, 12), (0x17b7, 0x17bd, 12),
+    (0x17c6, 0x17c6, 12), (0x17c9, 0x17d3, 12), (0x17dd, 0x17dd, 12), (0x1807, 0x1807, 7),
+    (0x180a, 0x180a, 3), (0x180b, 0x180d, 12), (0x1820, 0x1877, 7), (0x1885, 0x1886, 12),
+    (0x1887, 0x18a8, 7), (0x18a9, 0x18a9, 12), (0x18aa, 0x18aa, 7), (0x1920, 0x1922, 12),
+    (0x1927, 0x1928, 12), (0x1932, 0x1932, 12), (0x1939, 0x193b, 12), (0x1a17, 0x1a18, 12),
+    (0x1a1b, 0x1a1b, 12), (0x1a56, 0x1a56, 12), (0x1a58, 0x1a5e, 12), (0x1a60, 0x1a60, 12),
+    (0x1a62, 0x1a62, 12), (0x1a65, 0x1a6c, 12), (0x1a73, 0x1a7c, 12), (0x1a7f, 0x1a7f, 12),
+    (0x1ab0, 0x1abd, 12), (0x1b00, 0x1b03, 12), (0x1b34, 0x1b34, 12), (0x1b36, 0x1b3a, 12),
+    (0x1b3c, 0x1b3c, 12), (0x1b42, 0x1b42, 12), (0x1b6b, 0x1b73, 12), (0x1b80, 0x1b81, 12),
+    (0x1ba2, 0x1ba5, 12), (0x1ba8, 0x1ba9, 12), (0x1bab, 0x1bad, 12), (0x1be6, 0x1be6, 12),
+    (0x1be8, 0x1be9, 12), (0x1bed, 0x1bed, 12), (0x1bef, 0x1bf1, 12), (0x1c2c, 0x1c33, 12),
+    (0x1c36, 0x1c37, 12), (0x1cd0, 0x1cd2, 12), (0x1cd4, 0x1ce0, 12), (0x1ce2, 0x1ce8, 12),
+    (0x1ced, 0x1ced, 12), (0x1cf4, 0x1cf4, 12), (0x1cf8, 0x1cf9, 12), (0x1dc0, 0x1df9, 12),
+    (0x1dfb, 0x1dff, 12), (0x200b, 0x200b, 12), (0x200d, 0x200d, 3), (0x200e, 0x200f, 12),
+    (0x202a, 0x202e, 12), (0x2060, 0x2064, 12), (0x206a, 0x206f, 12), (0x20d0, 0x20dc, 12),
+    (0x20e1, 0x20e1, 12), (0x20e5, 0x20f0, 12), (0x2cef, 0x2cf1, 12), (0x2d7f, 0x2d7f, 12),
+    (0x2de0, 0x2dff, 12), (0x302a, 0x302d, 12), (0x3099, 0x309a, 12), (0xa66f, 0xa66f, 12),
+    (0xa674, 0xa67d, 12), (0xa69e, 0xa69f, 12), (0xa6f0, 0xa6f1, 12), (0xa802, 0xa802, 12),
+    (0xa806, 0xa806, 12), (0xa80b, 0xa80b, 12), (0xa825, 0xa826, 12), (0xa840, 0xa871, 7),
+    (0xa872, 0xa872, 6), (0xa8c4, 0xa8c5, 12), (0xa8e0, 0xa8f1, 12), (0xa926, 0xa92d, 12),
+    (0xa947, 0xa951, 12), (0xa980, 0xa982, 12), (0xa9b3, 0xa9b3, 12), (0xa9b6, 0xa9b9, 12),
+    (0xa9bc, 0xa9bc, 12), (0xa9e5, 0xa9e5, 12), (0xaa29, 0xaa2e, 12), (0xaa31, 0xaa32, 12),
+    (0xaa35, 0xaa36, 12), (0xaa43, 0xaa43, 12), (0xaa4c, 0xaa4c, 12), (0xaa7c, 0xaa7c, 12),
+    (0xaab0, 0xaab0, 12), (0xaab2, 0xaab4, 12), (0xaab7, 0xaab8, 12), (0xaabe, 0xaabf, 12),
+    (0xaac1, 0xaac1, 12), (0xaaec, 0xaaed, 12), (0xaaf6, 0xaaf6, 12), (0xabe5, 0xabe5, 12),
+    (0xabe8, 0xabe8, 12), (0xabed, 0xabed, 12), (0xfb1e, 0xfb1e, 12), (0xfe00, 0xfe0f, 12),
+    (0xfe20, 0xfe2f, 12), (0xfeff, 0xfeff, 12), (0xfff9, 0xfffb, 12), (0x101fd, 0x101fd, 12),
+    (0x102e0, 0x102e0, 12), (0x10376, 0x1037a, 12), (0x10a01, 0x10a03, 12), (0x10a05, 0x10a06, 12),
+    (0x10a0c, 0x10a0f, 12), (0x10a38, 0x10a3a, 12), (0x10a3f, 0x10a3f, 12), (0x10ac0, 0x10ac4, 7),
+    (0x10ac5, 0x10ac5, 5), (0x10ac7, 0x10ac7, 5), (0x10ac9, 0x10aca, 5), (0x10acd, 0x10acd, 6),
+    (0x10ace, 0x10ad2, 5), (0x10ad3, 0x10ad6, 7), (0x10ad7, 0x10ad7, 6), (0x10ad8, 0x10adc, 7),
+    (0x10add, 0x10add, 5), (0x10ade, 0x10ae0, 7), (0x10ae1, 0x10ae1, 5), (0x10ae4, 0x10ae4, 5),
+    (0x10ae5, 0x10ae6, 12), (0x10aeb, 0x10aee, 7), (0x10aef, 0x10aef, 5), (0x10b80, 0x10b80, 7),
+    (0x10b81, 0x10b81, 5), (0x10b82, 0x10b82, 7), (0x10b83, 0x10b85, 5), (0x10b86, 0x10b88, 7),
+    (0x10b89, 0x10b89, 5), (0x10b8a, 0x10b8b, 7), (0x10b8c, 0x10b8c, 5), (0x10b8d, 0x10b8d, 7),
+    (0x10b8e, 0x10b8f, 5), (0x10b90, 0x10b90, 7), (0x10b91, 0x10b91, 5), (0x10ba9, 0x10bac, 5),
+    (0x10bad, 0x10bae, 7), (0x11001, 0x11001, 12), (0x11038, 0x11046, 12), (0x1107f, 0x11081, 12),
+    (0x110b3, 0x110b6, 12), (0x110b9, 0x110ba, 12), (0x110bd, 0x110bd, 12), (0x11100, 0x11102, 12),
+    (0x11127, 0x1112b, 12), (0x1112d, 0x11134, 12), (0x11173, 0x11173, 12), (0x11180, 0x11181, 12),
+    (0x111b6, 0x111be, 12), (0x111ca, 0x111cc, 12), (0x1122f, 0x11231, 12), (0x11234, 0x11234, 12),
+    (0x11236, 0x11237, 12), (0x1123e, 0x1123e, 12), (0x112df, 0x112df, 12), (0x112e3, 0x112ea, 12),
+    (0x11300, 0x11301, 12), (0x1133c, 0x1133c, 12), (0x11340, 0x11340, 12), (0x11366, 0x1136c, 12),
+    (0x11370, 0x11374, 12), (0x11438, 0x1143f, 12), (0x11442, 0x11444, 12), (0x11446, 0x11446, 12),
+    (0x114b3, 0x114b8, 12), (0x114ba, 0x114ba, 12), (0x114bf, 0x114c0, 12), (0x114c2, 0x114c3, 12),
+    (0x115b2, 0x115b5, 12), (0x115bc, 0x115bd, 12), (0x115bf, 0x115c0, 12), (0x115dc, 0x115dd, 12),
+    (0x11633, 0x1163a, 12), (0x1163d, 0x1163d, 12), (0x1163f, 0x11640, 12), (0x116ab, 0x116ab, 12),
+    (0x116ad, 0x116ad, 12), (0x116b0, 0x116b5, 12), (0x116b7, 0x116b7, 12), (0x1171d, 0x1171f, 12),
+    (0x11722, 0x11725, 12), (0x11727, 0x1172b, 12), (0x11a01, 0x11a06, 12), (0x11a09, 0x11a0a, 12),
+    (0x11a33, 0x11a38, 12), (0x11a3b, 0x11a3e, 12), (0x11a47, 0x11a47, 12), (0x11a51, 0x11a56, 12),
+    (0x11a59, 0x11a5b, 12), (0x11a8a, 0x11a96, 12), (0x11a98, 0x11a99, 12), (0x11c30, 0x11c36, 12),
+    (0x11c38, 0x11c3d, 12), (0x11c3f, 0x11c3f, 12), (0x11c92, 0x11ca7, 12), (0x11caa, 0x11cb0, 12),
+    (0x11cb2, 0x11cb3, 12), (0x11cb5, 0x11cb6, 12), (0x11d31, 0x11d36, 12), (0x11d3a, 0x11d3a, 12),
+    (0x11d3c, 0x11d3d, 12), (0x11d3f, 0x11d45, 12), (0x11d47, 0x11d47, 12), (0x16af0, 0x16af4, 12),
+    (0x16b30, 0x16b36, 12), (0x16f8f, 0x16f92, 12), (0x1bc9d, 0x1bc9e, 12), (0x1bca0, 0x1bca3, 12),
+    (0x1d167, 0x1d169, 12), (0x1d173, 0x1d182, 12), (0x1d185, 0x1d18b, 12), (0x1d1aa, 0x1d1ad, 12),
+    (0x1d242, 0x1d244, 12), (0x1da00, 0x1da36, 12), (0x1da3b, 0x1da6c, 12), (0x1da75, 0x1da75, 12),
+    (0x1da84, 0x1da84, 12), (0x1da9b, 0x1da9f, 12), (0x1daa1, 0x1daaf, 12), (0x1e000, 0x1e006, 12),
+    (0x1e008, 0x1e018, 12), (0x1e01b, 0x1e021, 12), (0x1e023, 0x1e024, 12), (0x1e026, 0x1e02a, 12),
+    (0x1e8d0, 0x1e8d6, 12), (0x1e900, 0x1e943, 7), (0x1e944, 0x1e94a, 12), (0xe0001, 0xe0001, 12),
+    (0xe0020, 0xe007f, 12), (0xe0100, 0xe01ef, 12),
+];
+
+/// Mirrored characters, as (character, mirror).
+pub(crate) static MIRRORS: [(u32, u32); 364] = [
+    (0x0028, 0x0029), (0x0029, 0x0028), (0x003c, 0x003e), (0x003e, 0x003c), (0x005b, 0x005d),
+    (0x005d, 0x005b), (0x007b, 0x007d), (0x007d, 0x007b), (0x00ab, 0x00bb), (0x00bb, 0x00ab),
+    (0x0f3a, 0x0f3b), (0x0f3b, 0x0f3a), (0x0f3c, 0x0f3d), (0x0f3d, 0x0f3c), (0x169b, 0x169c),
+    (0x169c, 0x169b), (0x2039, 0x203a), (0x203a, 0x2039), (0x2045, 0x2046), (0x2046, 0x2045),
+    (0x207d, 0x207e), (0x207e, 0x207d), (0x208d, 0x208e), (0x208e, 0x208d), (0x2208, 0x220b),
+    (0x2209, 0x220c), (0x220a, 0x220d), (0x220b, 0x2208), (0x220c, 0x2209), (0x220d, 0x220a),
+    (0x2215, 0x29f5), (0x223c, 0x223d), (0x223d, 0x223c), (0x2243, 0x22cd), (0x2252, 0x2253),
+    (0x2253, 0x2252), (0x2254, 0x2255), (0x2255, 0x2254), (0x2264, 0x2265), (0x2265, 0x2264),
+    (0x2266, 0x2267), (0x2267, 0x2266), (0x2268, 0x2269), (0x2269, 0x2268), (0x226a, 0x226b),
+    (0x226b, 0x226a), (0x226e, 0x226f), (0x226f, 0x226e), (0x2270, 0x2271), (0x2271, 0x2270),
+    (0x2272, 0x2273), (0x2273, 0x2272), (0x2274, 0x2275), (0x2275, 0x2274), (0x2276, 0x2277),
+    (0x2277, 0x2276), (0x2278, 0x2279), (0x2279, 0x2278), (0x227a, 0x227b), (0x227b, 0x227a),
+    (0x227c, 0x227d), (0x227d, 0x227c), (0x227e, 0x227f), (0x227f, 0x227e), (0x2280, 0x2281),
+    (0x2281, 0x2280), (0x2282, 0x2283), (0x2283, 0x2282), (0x2284, 0x2285), (0x2285, 0x2284),
+    (0x2286, 0x2287), (0x2287, 0x2286), (0x2288, 0x2289), (0x2289, 0x2288), (0x228a, 0x228b),
+    (0x228b, 0x228a), (0x228f, 0x2290), (0x2290, 0x228f), (0x2291, 0x2292), (0x2292, 0x2291),
+    (0x2298, 0x29b8), (0x22a2, 0x22a3), (0x22a3, 0x22a2), (0x22a6, 0x2ade), (0x22a8, 0x2ae4),
+    (0x22a9, 0x2ae3), (0x22ab, 0x2ae5), (0x22b0, 0x22b1), (0x22b1, 0x22b0), (0x22b2, 0x22b3),
+    (0x22b3, 0x22b2), (0x22b4, 0x22b5), (0x22b5, 0x22b4), (0x22b6, 0x22b7), (0x22b7, 0x22b6),
+    (0x22c9, 0x22ca), (0x22ca, 0x22c9), (0x22cb, 0x22cc), (0x22cc, 0x22cb), (0x22cd, 0x2243),
+    (0x22d0, 0x22d1), (0x22d1, 0x22d0), (0x22d6, 0x22d7), (0x22d7, 0x22d6), (0x22d8, 0x22d9),
+    (0x22d9, 0x22d8), (0x22da, 0x22db), (0x22db, 0x22da), (0x22dc, 0x22dd), (0x22dd, 0x22dc),
+    (0x22de, 0x22df), (0x22df, 0x22de), (0x22e0, 0x22e1), (0x22e1, 0x22e0), (0x22e2, 0x22e3),
+    (0x22e3, 0x22e2), (0x22e4, 0x22e5), (0x22e5, 0x22e4), (0x22e6, 0x22e7), (0x22e7, 0x22e6),
+    (0x22e8, 0x22e9), (0x22e9, 0x22e8), (0x22ea, 0x22eb), (0x22eb, 0x22ea), (0x22ec, 0x22ed),
+    (0x22ed, 0x22ec), (0x22f0, 0x22f1), (0x22f1, 0x22f0), (0x22f2, 0x22fa), (0x22f3, 0x22fb),
+    (0x22f4, 0x22fc), (0x22f6, 0x22fd), (0x22f7, 0x22fe), (0x22fa, 0x22f2), (0x22fb, 0x22f3),
+    (0x22fc, 0x22f4), (0x22fd, 0x22f6), (0x22fe, 0x22f7), (0x2308, 0x2309), (0x2309, 0x2308),
+    (0x230a, 0x230b), (0x230b, 0x230a), (0x2329, 0x232a), (0x232a, 0x2329), (0x2768, 0x2769),
+    (0x2769, 0x2768), (0x276a, 0x276b), (0x276b, 0x276a), (0x276c, 0x276d), (0x276d, 0x276c),
+    (0x276e, 0x276f), (0x276f, 0x276e), (0x2770, 0x2771), (0x2771, 0x2770), (0x2772, 0x2773),
+    (0x2773, 0x2772), (0x2774, 0x2775), (0x2775, 0x2774), (0x27c3, 0x27c4), (0x27c4, 0x27c3),
+    (0x27c5, 0x27c6), (0x27c6, 0x27c5), (0x27c8, 0x27c9), (0x27c9, 0x27c8), (0x27cb, 0x27cd),
+    (0x27cd, 0x27cb), (0x27d5, 0x27d6), (0x27d6, 0x27d5), (0x27dd, 0x27de), (0x27de, 0x27dd),
+    (0x27e2, 0x27e3), (0x27e3, 0x27e2), (0x27e4, 0x27e5), (0x27e5, 0x27e4), (0x27e6, 0x27e7),
+    (0x27e7, 0x27e6), (0x27e8, 0x27e9), (0x27e9, 0x27e8), (0x27ea, 0x27eb), (0x27eb, 0x27ea),
+    (0x27ec, 0x27ed), (0x27ed, 0x27ec), (0x27ee, 0x27ef), (0x27ef, 0x27ee), (0x2983, 0x2984),
+    (0x2984, 0x2983), (0x2985, 0x2986), (0x2986, 0x2985), (0x2987, 0x2988), (0x2988, 0x2987),
+    (0x2989, 0x298a), (0x298a, 0x2989), (0x298b, 0x298c), (0x298c, 0x298b), (0x298d, 0x2990),
+    (0x298e, 0x298f), (0x298f, 0x298e), (0x2990, 0x298d), (0x2991, 0x2992), (0x2992, 0x2991),
+    (0x2993, 0x2994), (0x2994, 0x2993), (0x2995, 0x2996), (0x2996, 0x2995), (0x2997, 0x2998),
+    (0x2998, 0x2997), (0x29b8, 0x2298), (0x29c0, 0x29c1), (0x29c1, 0x29c0), (0x29c4, 0x29c5),
+    (0x29c5, 0x29c4), (0x29cf, 0x29d0), (0x29d0, 0x29cf), (0x29d1, 0x29d2), (0x29d2, 0x29d1),
+    (0x29d4, 0x29d5), (0x29d5, 0x29d4), (0x29d8, 0x29d9), (0x29d9, 0x29d8), (0x29da, 0x29db),
+    (0x29db, 0x29da), (0x29f5, 0x2215), (0x29f8, 0x29f9), (0x29f9, 0x29f8), (0x29fc, 0x29fd),
+    (0x29fd, 0x29fc), (0x2a2b, 0x2a2c), (0x2a2c, 0x2a2b), (0x2a2d, 0x2a2e), (0x2a2e, 0x2a2d),
+    (0x2a34, 0x2a35), (0x2a35, 0x2a34), (0x2a3c, 0x2a3d), (0x2a3d, 0x2a3c), (0x2a64, 0x2a65),
+    (0x2a65, 0x2a64), (0x2a79, 0x2a7a), (0x2a7a, 0x2a79), (0x2a7d, 0x2a7e), (0x2a7e, 0x2a7d),
+    (0x2a7f, 0x2a80), (0x2a80, 0x2a7f), (0x2a81, 0x2a82), (0x2a82, 0x2a81), (0x2a83, 0x2a84),
+    (0x2a84, 0x2a83), (0x2a8b, 0x2a8c), (0x2a8c, 0x2a8b), (0x2a91, 0x2a92), (0x2a92, 0x2a91),
+    (0x2a93, 0x2a94), (0x2a94, 0x2a93), (0x2a95, 0x2a96), (0x2a96, 0x2a95), (0x2a97, 0x2a98),
+    (0x2a98, 0x2a97), (0x2a99, 0x2a9a), (0x2a9a, 0x2a99), (0x2a9b, 0x2a9c), (0x2a9c, 0x2a9b),
+    (0x2aa1, 0x2aa2), (0x2aa2, 0x2aa1), (0x2aa6, 0x2aa7), (0x2aa7, 0x2aa6), (0x2aa8, 0x2aa9),
+    (0x2aa9, 0x2aa8), (0x2aaa, 0x2aab), (0x2aab, 0x2aaa), (0x2aac, 0x2aad), (0x2aad, 0x2aac),
+    (0x2aaf, 0x2ab0), (0x2ab0, 0x2aaf), (0x2ab3, 0x2ab4), (0x2ab4, 0x2ab3), (0x2abb, 0x2abc),
+    (0x2abc, 0x2abb), (0x2abd, 0x2abe), (0x2abe, 0x2abd), (0x2abf, 0x2ac0), (0x2ac0, 0x2abf),
+    (0x2ac1, 0x2ac2), (0x2ac2, 0x2ac1), (0x2ac3, 0x2ac4), (0x2ac4, 0x2ac3), (0x2ac5, 0x2ac6),
+    (0x2ac6, 0x2ac5), (0x2acd, 0x2ace), (0x2ace, 0x2acd), (0x2acf, 0x2ad0), (0x2ad0, 0x2acf),
+    (0x2ad1, 0x2ad2), (0x2ad2, 0x2ad1), (0x2ad3, 0x2ad4), (0x2ad4, 0x2ad3), (0x2ad5, 0x2ad6),
+    (0x2ad6, 0x2ad5), (0x2ade, 0x22a6), (0x2ae3, 0x22a9), (0x2ae4, 0x22a8), (0x2ae5, 0x22ab),
+    (0x2aec, 0x2aed), (0x2aed, 0x2aec), (0x2af7, 0x2af8), (0x2af8, 0x2af7), (0x2af9, 0x2afa),
+    (0x2afa, 0x2af9), (0x2e02, 0x2e03), (0x2e03, 0x2e02), (0x2e04, 0x2e05), (0x2e05, 0x2e04),
+    (0x2e09, 0x2e0a), (0x2e0a, 0x2e09), (0x2e0c, 0x2e0d), (0x2e0d, 0x2e0c), (0x2e1c, 0x2e1d),
+    (0x2e1d, 0x2e1c), (0x2e20, 0x2e21), (0x2e21, 0x2e20), (0x2e22, 0x2e23), (0x2e23, 0x2e22),
+    (0x2e24, 0x2e25), (0x2e25, 0x2e24), (0x2e26, 0x2e27), (0x2e27, 0x2e26), (0x2e28, 0x2e29),
+    (0x2e29, 0x2e28), (0x3008, 0x3009), (0x3009, 0x3008), (0x300a, 0x300b), (0x300b, 0x300a),
+    (0x300c, 0x300d), (0x300d, 0x300c), (0x300e, 0x300f), (0x300f, 0x300e), (0x3010, 0x3011),
+    (0x3011, 0x3010), (0x3014, 0x3015), (0x3015, 0x3014), (0x3016, 0x3017), (0x3017, 0x3016),
+    (0x3018, 0x3019), (0x3019, 0x3018), (0x301a, 0x301b), (0x301b, 0x301a), (0xfe59, 0xfe5a),
+    (0xfe5a, 0xfe59), (0xfe5b, 0xfe5c), (0xfe5c, 0xfe5b), (0xfe5d, 0xfe5e), (0xfe5e, 0xfe5d),
+    (0xfe64, 0xfe65), (0xfe65, 0xfe64), (0xff08, 0xff09), (0xff09, 0xff08), (0xff1c, 0xff1e),
+    (0xff1e, 0xff1c), (0xff3b, 0xff3d), (0xff3d, 0xff3b), (0xff5b, 0xff5d), (0xff5d, 0xff5b),
+    (0xff5f, 0xff60), (0xff60, 0xff5f), (0xff62, 0xff63), (0xff63, 0xff62),
+];
+
+/// Bracket types of the paired brackets, as (character, bracket type).
+pub(crate) static BRACKETS: [(u32, u32); 120] = [
+    (0x0028, 0x80000028), (0x0029, 0x00000028), (0x005b, 0x8000005b), (0x005d, 0x0000005b),
+    (0x007b, 0x8000007b), (0x007d, 0x0000007b), (0x0f3a, 0x80000f3a), (0x0f3b, 0x00000f3a),
+    (0x0f3c, 0x80000f3c), (0x0f3d, 0x00000f3c), (0x169b, 0x8000169b), (0x169c, 0x0000169b),
+    (0x2045, 0x80002045), (0x2046, 0x00002045), (0x207d, 0x80000028), (0x207e, 0x00000028),
+    (0x208d, 0x80000028), (0x208e, 0x00000028), (0x2308, 0x80002308), (0x2309, 0x00002308),
+    (0x230a, 0x8000230a), (0x230b, 0x0000230a), (0x2329, 0x80003008), (0x232a, 0x00003008),
+    (0x2768, 0x80002768), (0x2769, 0x00002768), (0x276a, 0x8000276a), (0x276b, 0x0000276a),
+    (0x276c, 0x8000276c), (0x276d, 0x0000276c), (0x276e, 0x8000276e), (0x276f, 0x0000276e),
+    (0x2770, 0x80002770), (0x2771, 0x00002770), (0x2772, 0x80002772), (0x2773, 0x00002772),
+    (0x2774, 0x80002774), (0x2775, 0x00002774), (0x27c5, 0x800027c5), (0x27c6, 0x000027c5),
+    (0x27e6, 0x800027e6), (0x27e7, 0x000027e6), (0x27e8, 0x800027e8), (0x27e9, 0x000027e8),
+    (0x27ea, 0x800027ea), (0x27eb, 0x000027ea), (0x27ec, 0x800027ec), (0x27ed, 0x000027ec),
+    (0x27ee, 0x800027ee), (0x27ef, 0x000027ee), (0x2983, 0x80002983), (0x2984, 0x00002983),
+    (0x2985, 0x80002985), (0x2986, 0x00002985), (0x2987, 0x80002987), (0x2988, 0x00002987),
+    (0x2989, 0x80002989), (0x298a, 0x00002989), (0x298b, 0x8000298b), (0x298c, 0x0000298b),
+    (0x298d, 0x8000298d), (0x298e, 0x0000298f), (0x298f, 0x8000298f), (0x2990, 0x0000298d),
+    (0x2991, 0x80002991), (0x2992, 0x00002991), (0x2993, 0x80002993), (0x2994, 0x00002993),
+    (0x2995, 0x80002995), (0x2996, 0x00002995), (0x2997, 0x80002997), (0x2998, 0x00002997),
+    (0x29d8, 0x800029d8), (0x29d9, 0x000029d8), (0x29da, 0x800029da), (0x29db, 0x000029da),
+    (0x29fc, 0x800029fc), (0x29fd, 0x000029fc), (0x2e22, 0x80002e22), (0x2e23, 0x00002e22),
+    (0x2e24, 0x80002e24), (0x2e25, 0x00002e24), (0x2e26, 0x80002e26), (0x2e27, 0x00002e26),
+    (0x2e28, 0x80002e28), (0x2e29, 0x00002e28), (0x3008, 0x80003008), (0x3009, 0x00003008),
+    (0x300a, 0x8000300a), (0x300b, 0x0000300a), (0x300c, 0x8000300c), (0x300d, 0x0000300c),
+    (0x300e, 0x8000300e), (0x300f, 0x0000300e), (0x3010, 0x80003010), (0x3011, 0x00003010),
+    (0x3014, 0x80003014), (0x3015, 0x00003014), (0x3016, 0x80003016), (0x3017, 0x00003016),
+    (0x3018, 0x80003018), (0x3019, 0x00003018), (0x301a, 0x8000301a), (0x301b, 0x0000301a),
+    (0xfe59, 0x80000028), (0xfe5a, 0x00000028), (0xfe5b, 0x8000007b), (0xfe5c, 0x0000007b),
+    (0xfe5d, 0x80003014), (0xfe5e, 0x00003014), (0xff08, 0x80000028), (0xff09, 0x00000028),
+    (0xff3b, 0x8000005b), (0xff3d, 0x0000005b), (0xff5b, 0x8000007b), (0xff5d, 0x0000007b),
+    (0xff5f, 0x80002985), (0xff60, 0x00002985), (0xff62, 0x8000300c), (0xff63, 0x0000300c),
+];
+
+/// Presentation forms, as (character, [isolated, final, initial, medial]).
+pub(crate) static ARABIC_SHAPING_PRES: [(u32, [u32; 4]); 76] = [
+    (0x0621, [0xfe80, 0x0621, 0x0621, 0x0621]), (0x0622, [0xfe81, 0xfe82, 0x0622, 0x0622]),
+    (0x0623, [0xfe83, 0xfe84, 0x0623, 0x0623]), (0x0624, [0xfe85, 0xfe86, 0x0624, 0x0624]),
+    (0x0625, [0xfe87, 0xfe88, 0x0625, 0x0625]), (0x0626, [0xfe89, 0xfe8a, 0xfe8b, 0xfe8c]),
+    (0x0627, [0xfe8d, 0xfe8e, 0x0627, 0x0627]), (0x0628, [0xfe8f, 0xfe90, 0xfe91, 0xfe92]),
+    (0x0629, [0xfe93, 0xfe94, 0x0629, 0x0629]), (0x062a, [0xfe95, 0xfe96, 0xfe97, 0xfe98]),
+    (0x062b, [0xfe99, 0xfe9a, 0xfe9b, 0xfe9c]), (0x062c, [0xfe9d, 0xfe9e, 0xfe9f, 0xfea0]),
+    (0x062d, [0xfea1, 0xfea2, 0xfea3, 0xfea4]), (0x062e, [0xfea5, 0xfea6, 0xfea7, 0xfea8]),
+    (0x062f, [0xfea9, 0xfeaa, 0x062f, 0x062f]), (0x0630, [0xfeab, 0xfeac, 0x0630, 0x0630]),
+    (0x0631, [0xfead, 0xfeae, 0x0631, 0x0631]), (0x0632, [0xfeaf, 0xfeb0, 0x0632, 0x0632]),
+    (0x0633, [0xfeb1, 0xfeb2, 0xfeb3, 0xfeb4]), (0x0634, [0xfeb5, 0xfeb6, 0xfeb7, 0xfeb8]),
+    (0x0635, [0xfeb9, 0xfeba, 0xfebb, 0xfebc]), (0x0636, [0xfebd, 0xfebe, 0xfebf, 0xfec0]),
+    (0x0637, [0xfec1, 0xfec2, 0xfec3, 0xfec4]), (0x0638, [0xfec5, 0xfec6, 0xfec7, 0xfec8]),
+    (0x0639, [0xfec9, 0xfeca, 0xfecb, 0xfecc]), (0x063a, [0xfecd, 0xfece, 0xfecf, 0xfed0]),
+    (0x0641, [0xfed1, 0xfed2, 0xfed3, 0xfed4]), (0x0642, [0xfed5, 0xfed6, 0xfed7, 0xfed8]),
+    (0x0643, [0xfed9, 0xfeda, 0xfedb, 0xfedc]), (0x0644, [0xfedd, 0xfede, 0xfedf, 0xfee0]),
+    (0x0645, [0xfee1, 0xfee2, 0xfee3, 0xfee4]), (0x0646, [0xfee5, 0xfee6, 0xfee7, 0xfee8]),
+    (0x0647, [0xfee9, 0xfeea, 0xfeeb, 0xfeec]), (0x0648, [0xfeed, 0xfeee, 0x0648, 0x0648]),
+    (0x0649, [0xfeef, 0xfef0, 0xfbe8, 0xfbe9]), (0x064a, [0xfef1, 0xfef2, 0xfef3, 0xfef4]),
+    (0x0671, [0xfb50, 0xfb51, 0x0671, 0x0671]), (0x0677, [0xfbdd, 0x0677, 0x0677, 0x0677]),
+    (0x0679, [0xfb66, 0xfb67, 0xfb68, 0xfb69]), (0x067a, [0xfb5e, 0xfb5f, 0xfb60, 0xfb61]),
+    (0x067b, [0xfb52, 0xfb53, 0xfb54, 0xfb55]), (0x067e, [0xfb56, 0xfb57, 0xfb58, 0xfb59]),
+    (0x067f, [0xfb62, 0xfb63, 0xfb64, 0xfb65]), (0x0680, [0xfb5a, 0xfb5b, 0xfb5c, 0xfb5d]),
+    (0x0683, [0xfb76, 0xfb77, 0xfb78, 0xfb79]), (0x0684, [0xfb72, 0xfb73, 0xfb74, 0xfb75]),
+    (0x0686, [0xfb7a, 0xfb7b, 0xfb7c, 0xfb7d]), (0x0687, [0xfb7e, 0xfb7f, 0xfb80, 0xfb81]),
+    (0x0688, [0xfb88, 0xfb89, 0x0688, 0x0688]), (0x068c, [0xfb84, 0xfb85, 0x068c, 0x068c]),
+    (0x068d, [0xfb82, 0xfb83, 0x068d, 0x068d]), (0x068e, [0xfb86, 0xfb87, 0x068e, 0x068e]),
+    (0x0691, [0xfb8c, 0xfb8d, 0x0691, 0x0691]), (0x0698, [0xfb8a, 0xfb8b, 0x0698, 0x0698]),
+    (0x06a4, [0xfb6a, 0xfb6b, 0xfb6c, 0xfb6d]), (0x06a6, [0xfb6e, 0xfb6f, 0xfb70, 0xfb71]),
+    (0x06a9, [0xfb8e, 0xfb8f, 0xfb90, 0xfb91]), (0x06ad, [0xfbd3, 0xfbd4, 0xfbd5, 0xfbd6]),
+    (0x06af, [0xfb92, 0xfb93, 0xfb94, 0xfb95]), (0x06b1, [0xfb9a, 0xfb9b, 0xfb9c, 0xfb9d]),
+    (0x06b3, [0xfb96, 0xfb97, 0xfb98, 0xfb99]), (0x06ba, [0xfb9e, 0xfb9f, 0x06ba, 0x06ba]),
+    (0x06bb, [0xfba0, 0xfba1, 0xfba2, 0xfba3]), (0x06be, [0xfbaa, 0xfbab, 0xfbac, 0xfbad]),
+    (0x06c0, [0xfba4, 0xfba5, 0x06c0, 0x06c0]), (0x06c1, [0xfba6, 0xfba7, 0xfba8, 0xfba9]),
+    (0x06c5, [0xfbe0, 0xfbe1, 0x06c5, 0x06c5]), (0x06c6, [0xfbd9, 0xfbda, 0x06c6, 0x06c6]),
+    (0x06c7, [0xfbd7, 0xfbd8, 0x06c7, 0x06c7]), (0x06c8, [0xfbdb, 0xfbdc, 0x06c8, 0x06c8]),
+    (0x06c9, [0xfbe2, 0xfbe3, 0x06c9, 0x06c9]), (0x06cb, [0xfbde, 0xfbdf, 0x06cb, 0x06cb]),
+    (0x06cc, [0xfbfc, 0xfbfd, 0xfbfe, 0xfbff]), (0x06d0, [0xfbe4, 0xfbe5, 0xfbe6, 0xfbe7]),
+    (0x06d2, [0xfbae, 0xfbaf, 0x06d2, 0x06d2]), (0x06d3, [0xfbb0, 0xfbb1, 0x06d3, 0x06d3]),
+];
+
+/// Presentation forms, as (character, [isolated, final, initial, medial]).
+pub(crate) static ARABIC_SHAPING_NSM: [(u32, [u32; 4]); 8] = [
+    (0x064b, [0xfe70, 0xfe71, 0xfe70, 0xfe71]), (0x064c, [0xfe72, 0xfe72, 0xfe72, 0xfe72]),
+    (0x064d, [0xfe74, 0xfe74, 0xfe74, 0xfe74]), (0x064e, [0xfe76, 0xfe77, 0xfe76, 0xfe77]),
+    (0x064f, [0xfe78, 0xfe79, 0xfe78, 0xfe79]), (0x0650, [0xfe7a, 0xfe7b, 0xfe7a, 0xfe7b]),
+    (0x0651, [0xfe7c, 0xfe7d, 0xfe7c, 0xfe7d]), (0x0652, [0xfe7e, 0xfe7f, 0xfe7e, 0xfe7f]),
+];
+
+/// Ligatures, as (first, second, ligature).
+pub(crate) static LIGATURES_MANDATORY: [(u32, u32, u32); 8] = [
+    (0xfedf, 0xfe82, 0xfef5), (0xfedf, 0xfe84, 0xfef7), (0xfedf, 0xfe88, 0xfef9), (0xfedf, 0xfe8e, 0xfefb),
+    (0xfee0, 0xfe82, 0xfef6), (0xfee0, 0xfe84, 0xfef8), (0xfee0, 0xfe88, 0xfefa), (0xfee0, 0xfe8e, 0xfefc),
+];
+
+/// Ligatures, as (first, second, ligature).
+pub(crate) static LIGATURES_CONSOLE: [(u32, u32, u32); 55] = [
+    (0x0640, 0xfc60, 0xfcf2), (0x0640, 0xfc61, 0xfcf3), (0x0640, 0xfc62, 0xfcf4), (0x0640, 0xfe70, 0xfe71),
+    (0x0640, 0xfe76, 0xfe77), (0x0640, 0xfe78, 0xfe79), (0x0640, 0xfe7a, 0xfe7b), (0x0640, 0xfe7c, 0xfe7d),
+    (0x0640, 0xfe7e, 0xfe7f), (0xfbe8, 0x0654, 0xfe8b), (0xfbe9, 0x0654, 0xfe8c), (0xfbfc, 0x0654, 0xfe89),
+    (0xfbfc, 0x0670, 0xfc5d), (0xfbfd, 0x0654, 0xfe8a), (0xfbfd, 0x0670, 0xfc90), (0xfe7c, 0x0670, 0xfc63),
+    (0xfe7c, 0xfe72, 0xfc5e), (0xfe7c, 0xfe74, 0xfc5f), (0xfe7c, 0xfe76, 0xfc60), (0xfe7c, 0xfe78, 0xfc61),
+    (0xfe7c, 0xfe7a, 0xfc62), (0xfe7d, 0xfe76, 0xfcf2), (0xfe7d, 0xfe77, 0xfcf2), (0xfe7d, 0xfe78, 0xfcf3),
+    (0xfe7d, 0xfe79, 0xfcf3), (0xfe7d, 0xfe7a, 0xfcf4), (0xfe7d, 0xfe7b, 0xfcf4), (0xfe8d, 0x0653, 0xfe81),
+    (0xfe8d, 0x0654, 0xfe83), (0xfe8d, 0x0655, 0xfe87), (0xfe8d, 0xfe70, 0xfd3d), (0xfe8e, 0x0653, 0xfe82),
+    (0xfe8e, 0x0654, 0xfe84), (0xfe8e, 0x0655, 0xfe88), (0xfe8e, 0xfe70, 0xfd3c), (0xfee9, 0x0654, 0xfba4),
+    (0xfeea, 0x0654, 0xfba5), (0xfeed, 0x0654, 0xfe85), (0xfeee, 0x0654, 0xfe86), (0xfeef, 0x0654, 0xfe89),
+    (0xfeef, 0x0670, 0xfc5d), (0xfef0, 0x0654, 0xfe8a), (0xfef0, 0x0670, 0xfc90), (0xfef1, 0x0654, 0xfe89),
+    (0xfef1, 0x0670, 0xfc5d), (0xfef2, 0x0654, 0xfe8a), (0xfef2, 0x0670, 0xfc90), (0xfef3, 0x0654, 0xfe8b),
+    (0xfef4, 0x0654, 0xfe8c), (0xfefb, 0x0653, 0xfef5), (0xfefb, 0x0654, 0xfef7), (0xfefb, 0x0655, 0xfef9),
+    (0xfefc, 0x0653, 0xfef6), (0xfefc, 0x0654, 0xfef8), (0xfefc, 0x0655, 0xfefa),
+];
+
+/// CapRTL characters, indexed by their ASCII code.
+pub(crate) static CAP_RTL_TO_UNICODE: [u32; 128] = [
+    0x0040, 0x005c, 0x005e, 0x0060, 0x0041, 0x0590, 0x007c, 0x007e,
+    0x00a1, 0x00a6, 0x00a7, 0x00a8, 0x00a9, 0x000d, 0x202e, 0x202b,
+    0x202d, 0x202a, 0x202c, 0x000c, 0x2066, 0x2067, 0x2068, 0x2069,
+    0x00ac, 0x00ae, 0x00af, 0x00b4, 0x00b6, 0x00b7, 0x00b8, 0x00bc,
+    0x0020, 0x0021, 0x0022, 0x00bd, 0x0024, 0x00be, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002a, 0x0023, 0x002c, 0x00bf, 0x002b, 0x002d,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0600, 0x0601,
+    0x0602, 0x0603, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f,
+    0x05be, 0x0608, 0x060b, 0x060d, 0x061b, 0x061c, 0x061d, 0x05c0,
+    0x05c3, 0x05c6, 0x05c8, 0x05c9, 0x05ca, 0x05cb, 0x05cc, 0x05cd,
+    0x05ce, 0x05cf, 0x05d0, 0x05d1, 0x05d2, 0x05d3, 0x05d4, 0x05d5,
+    0x05d6, 0x05d7, 0x05d8, 0x005b, 0x000a, 0x005d, 0x0000, 0x005f,
+    0x0300, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x007b, 0x0009, 0x007d, 0x1680, 0x00d7,
+];
+
+/// Characters CapRTL can encode, as (character, byte).
+pub(crate) static CAP_RTL_FROM_UNICODE: [(u32, u8); 118] = [
+    (0x0000, 0x5e), (0x0009, 0x7c), (0x000a, 0x5c), (0x000c, 0x13), (0x000d, 0x0d), (0x0020, 0x20),
+    (0x0021, 0x21), (0x0022, 0x22), (0x0023, 0x2b), (0x0024, 0x24), (0x0026, 0x26), (0x0027, 0x27),
+    (0x0028, 0x28), (0x0029, 0x29), (0x002a, 0x2a), (0x002b, 0x2e), (0x002c, 0x2c), (0x002d, 0x2f),
+    (0x0030, 0x30), (0x0031, 0x31), (0x0032, 0x32), (0x0033, 0x33), (0x0034, 0x34), (0x0035, 0x35),
+    (0x003a, 0x3a), (0x003b, 0x3b), (0x003c, 0x3c), (0x003d, 0x3d), (0x003e, 0x3e), (0x003f, 0x3f),
+    (0x0040, 0x00), (0x0041, 0x04), (0x005b, 0x5b), (0x005c, 0x01), (0x005d, 0x5d), (0x005e, 0x02),
+    (0x0060, 0x03), (0x0061, 0x61), (0x0062, 0x62), (0x0063, 0x63), (0x0064, 0x64), (0x0065, 0x65),
+    (0x0066, 0x66), (0x0067, 0x67), (0x0068, 0x68), (0x0069, 0x69), (0x006a, 0x6a), (0x006b, 0x6b),
+    (0x006c, 0x6c), (0x006d, 0x6d), (0x006e, 0x6e), (0x006f, 0x6f), (0x0070, 0x70), (0x0071, 0x71),
+    (0x0072, 0x72), (0x0073, 0x73), (0x0074, 0x74), (0x0075, 0x75), (0x0076, 0x76), (0x0077, 0x77),
+    (0x0078, 0x78), (0x0079, 0x79), (0x007a, 0x7a), (0x007b, 0x7b), (0x007c, 0x06), (0x007d, 0x7d),
+    (0x007e, 0x07), (0x00a1, 0x08), (0x00a6, 0x09), (0x00a7, 0x0a), (0x00a8, 0x0b), (0x00a9, 0x0c),
+    (0x00ac, 0x18), (0x00ae, 0x19), (0x00af, 0x1a), (0x00b4, 0x1b), (0x00b6, 0x1c), (0x00b7, 0x1d),
+    (0x00b8, 0x1e), (0x00bc, 0x1f), (0x00bd, 0x23), (0x00be, 0x25), (0x00bf, 0x2d), (0x00d7, 0x7f),
+    (0x0300, 0x60), (0x0590, 0x05), (0x05be, 0x40), (0x05c0, 0x47), (0x05c3, 0x48), (0x05c6, 0x49),
+    (0x05c8, 0x4a), (0x05c9, 0x4b), (0x05ca, 0x4c), (0x05cb, 0x4d), (0x05cc, 0x4e), (0x05cd, 0x4f),
+    (0x05ce, 0x50), (0x05cf, 0x51), (0x05d0, 0x52), (0x05d1, 0x53), (0x05d2, 0x54), (0x05d3, 0x55),
+    (0x05d4, 0x56), (0x05d5, 0x57), (0x05d6, 0x58), (0x05d7, 0x59), (0x05d8, 0x5a), (0x0600, 0x36),
+    (0x0601, 0x37), (0x0602, 0x38), (0x0603, 0x39), (0x0608, 0x41), (0x060b, 0x42), (0x060d, 0x43),
+    (0x061b, 0x44), (0x061c, 0x45), (0x061d, 0x46), (0x1680, 0x7e),
+];
+
+pub(crate) const CAP_RTL_DESCRIPTION: &str = "CapRTL is a character set for testing with the reference\nimplementation, with explicit marks escape strings, and\nthe property that contains all unicode character types in\nASCII range 1-127.\n\nWarning: CapRTL character types are subject to change.\n\nCapRTL's character types:\n\n  * 0x00 ^@ ON    * 0x01 ^A ON    * 0x02 ^B ON    * 0x03 ^C ON  \n  * 0x04 ^D LTR   * 0x05 ^E RTL   * 0x06 ^F ON    * 0x07 ^G ON  \n  * 0x08 ^H ON    * 0x09 ^I ON    * 0x0a ^J ON    * 0x0b ^K ON  \n  * 0x0c ^L ON    * 0x0d ^M BS    * 0x0e ^N RLO   * 0x0f ^O RLE \n  * 0x10 ^P LRO   * 0x11 ^Q LRE   * 0x12 ^R PDF   * 0x13 ^S WS  \n  * 0x14 ^T LRI   * 0x15 ^U RLI   * 0x16 ^V FSI   * 0x17 ^W PDI \n  * 0x18 ^X ON    * 0x19 ^Y ON    * 0x1a ^Z ON    * 0x1b ^[ ON  \n  * 0x1c ^\\ ON    * 0x1d ^] ON    * 0x1e ^^ ON    * 0x1f ^_ ON  \n  * 0x20    WS    * 0x21  ! ON    * 0x22  \" ON    * 0x23  # ON  \n  * 0x24  $ ET    * 0x25  % ON    * 0x26  & ON    * 0x27  ' ON  \n  * 0x28  ( ON    * 0x29  ) ON    * 0x2a  * ON    * 0x2b  + ET  \n  * 0x2c  , CS    * 0x2d  - ON    * 0x2e  . ES    * 0x2f  / ES  \n  * 0x30  0 EN    * 0x31  1 EN    * 0x32  2 EN    * 0x33  3 EN  \n  * 0x34  4 EN    * 0x35  5 EN    * 0x36  6 AN    * 0x37  7 AN  \n  * 0x38  8 AN    * 0x39  9 AN    * 0x3a  : CS    * 0x3b  ; ON  \n  * 0x3c  < ON    * 0x3d  = ON    * 0x3e  > ON    * 0x3f  ? ON  \n  * 0x40  @ RTL   * 0x41  A AL    * 0x42  B AL    * 0x43  C AL  \n  * 0x44  D AL    * 0x45  E AL    * 0x46  F AL    * 0x47  G RTL \n  * 0x48  H RTL   * 0x49  I RTL   * 0x4a  J RTL   * 0x4b  K RTL \n  * 0x4c  L RTL   * 0x4d  M RTL   * 0x4e  N RTL   * 0x4f  O RTL \n  * 0x50  P RTL   * 0x51  Q RTL   * 0x52  R RTL   * 0x53  S RTL \n  * 0x54  T RTL   * 0x55  U RTL   * 0x56  V RTL   * 0x57  W RTL \n  * 0x58  X RTL   * 0x59  Y RTL   * 0x5a  Z RTL   * 0x5b  [ ON  \n  * 0x5c  \\ BS    * 0x5d  ] ON    * 0x5e  ^ BN    * 0x5f  _ ON  \n  * 0x60  ` NSM   * 0x61  a LTR   * 0x62  b LTR   * 0x63  c LTR \n  * 0x64  d LTR   * 0x65  e LTR   * 0x66  f LTR   * 0x67  g LTR \n  * 0x68  h LTR   * 0x69  i LTR   * 0x6a  j LTR   * 0x6b  k LTR \n  * 0x6c  l LTR   * 0x6d  m LTR   * 0x6e  n LTR   * 0x6f  o LTR \n  * 0x70  p LTR   * 0x71  q LTR   * 0x72  r LTR   * 0x73  s LTR \n  * 0x74  t LTR   * 0x75  u LTR   * 0x76  v LTR   * 0x77  w LTR \n  * 0x78  x LTR   * 0x79  y LTR   * 0x7a  z LTR   * 0x7b  { ON  \n  * 0x7c  | SS    * 0x7d  } ON    * 0x7e  ~ WS    * 0x7f    ON  \n\nEscape sequences:\n  Character `_' is used to escape explicit marks. The list is:\n    * _>  LRM\n    * _<  RLM\n    * _l  LRE\n    * _r  RLE\n    * _L  LRO\n    * _R  RLO\n    * _o  PDF\n    * _i  LRI\n    * _y  RLI\n    * _f  FSI\n    * _I  PDI\n    * __  `_' itself\n\n\0";
+
+/// ISO8859_6 characters, indexed by their byte.
+pub(crate) static ISO8859_6_TO_UNICODE: [u32; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007,
+    0x0008, 0x0009, 0x000a, 0x000b, 0x000c, 0x000d, 0x000e, 0x000f,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017,
+    0x0018, 0x0019, 0x001a, 0x001b, 0x001c, 0x001d, 0x001e, 0x001f,
+    0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002a, 0x002b, 0x002c, 0x002d, 0x002e, 0x002f,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f,
+    0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005a, 0x005b, 0x005c, 0x005d, 0x005e, 0x005f,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x007b, 0x007c, 0x007d, 0x007e, 0x007f,
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008a, 0x008b, 0x008c, 0x008d, 0x008e, 0x008f,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009a, 0x009b, 0x009c, 0x009d, 0x009e, 0x009f,
+    0x00a0, 0x00a1, 0x00a2, 0x00a3, 0x00a4, 0x00a5, 0x00a6, 0x00a7,
+    0x00a8, 0x00a9, 0x00aa, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x00af,
+    0x00b0, 0x00b1, 0x00b2, 0x00b3, 0x00b4, 0x00b5, 0x00b6, 0x00b7,
+    0x00b8, 0x00b9, 0x00ba, 0x00bb, 0x00bc, 0x00bd, 0x00be, 0x00bf,
+    0x00c0, 0x0621, 0x0622, 0x0623, 0x0624, 0x0625, 0x0626, 0x0627,
+    0x0628, 0x0629, 0x062a, 0x062b, 0x062c, 0x062d, 0x062e, 0x062f,
+    0x0630, 0x0631, 0x0632, 0x0633, 0x0634, 0x0635, 0x0636, 0x0637,
+    0x0638, 0x0639, 0x063a, 0x063b, 0x063c, 0x063d, 0x063e, 0x063f,
+    0x0640, 0x0641, 0x0642, 0x0643, 0x0644, 0x0645, 0x0646, 0x0647,
+    0x0648, 0x0649, 0x064a, 0x064b, 0x064c, 0x064d, 0x064e, 0x064f,
+    0x0650, 0x0651, 0x0652, 0x00f3, 0x00f4, 0x00f5, 0x00f6, 0x00f7,
+    0x00f8, 0x00f9, 0x00fa, 0x00fb, 0x00fc, 0x00fd, 0x00fe, 0x00ff,
+];
+
+/// Characters ISO8859_6 can encode, as (character, byte).
+pub(crate) static ISO8859_6_FROM_UNICODE: [(u32, u8); 309] = [
+    (0x0000, 0x00), (0x0001, 0x01), (0x0002, 0x02), (0x0003, 0x03), (0x0004, 0x04), (0x0005, 0x05),
+    (0x0006, 0x06), (0x0007, 0x07), (0x0008, 0x08), (0x0009, 0x09), (0x000a, 0x0a), (0x000b, 0x0b),
+    (0x000c, 0x0c), (0x000d, 0x0d), (0x000e, 0x0e), (0x000f, 0x0f), (0x0010, 0x10), (0x0011, 0x11),
+    (0x0012, 0x12), (0x0013, 0x13), (0x0014, 0x14), (0x0015, 0x15), (0x0016, 0x16), (0x0017, 0x17),
+    (0x0018, 0x18), (0x0019, 0x19), (0x001a, 0x1a), (0x001b, 0x1b), (0x001c, 0x1c), (0x001d, 0x1d),
+    (0x001e, 0x1e), (0x001f, 0x1f), (0x0020, 0x20), (0x0021, 0x21), (0x0022, 0x22), (0x0023, 0x23),
+    (0x0024, 0x24), (0x0025, 0x25), (0x0026, 0x26), (0x0027, 0x27), (0x0028, 0x28), (0x0029, 0x29),
+    (0x002a, 0x2a), (0x002b, 0x2b), (0x002c, 0x2c), (0x002d, 0x2d), (0x002e, 0x2e), (0x002f, 0x2f),
+    (0x0030, 0x30), (0x0031, 0x31), (0x0032, 0x32), (0x0033, 0x33), (0x0034, 0x34), (0x0035, 0x35),
+    (0x0036, 0x36), (0x0037, 0x37), (0x0038, 0x38), (0x0039, 0x39), (0x003a, 0x3a), (0x003b, 0x3b),
+    (0x003c, 0x3c), (0x003d, 0x3d), (0x003e, 0x3e), (0x003f, 0x3f), (0x0040, 0x40), (0x0041, 0x41),
+    (0x0042, 0x42), (0x0043, 0x43), (0x0044, 0x44), (0x0045, 0x45), (0x0046, 0x46), (0x0047, 0x47),
+    (0x0048, 0x48), (0x0049, 0x49), (0x004a, 0x4a), (0x004b, 0x4b), (0x004c, 0x4c), (0x004d, 0x4d),
+    (0x004e, 0x4e), (0x004f, 0x4f), (0x0050, 0x50), (0x0051, 0x51), (0x0052, 0x52), (0x0053, 0x53),
+    (0x0054, 0x54), (0x0055, 0x55), (0x0056, 0x56), (0x0057, 0x57), (0x0058, 0x58), (0x0059, 0x59),
+    (0x005a, 0x5a), (0x005b, 0x5b), (0x005c, 0x5c), (0x005d, 0x5d), (0x005e, 0x5e), (0x005f, 0x5f),
+    (0x0060, 0x60), (0x0061, 0x61), (0x0062, 0x62), (0x0063, 0x63), (0x0064, 0x64), (0x0065, 0x65),
+    (0x0066, 0x66), (0x0067, 0x67), (0x0068, 0x68), (0x0069, 0x69), (0x006a, 0x6a), (0x006b, 0x6b),
+    (0x006c, 0x6c), (0x006d, 0x6d), (0x006e, 0x6e), (0x006f, 0x6f), (0x0070, 0x70), (0x0071, 0x71),
+    (0x0072, 0x72), (0x0073, 0x73), (0x0074, 0x74), (0x0075, 0x75), (0x0076, 0x76), (0x0077, 0x77),
+    (0x0078, 0x78), (0x0079, 0x79), (0x007a, 0x7a), (0x007b, 0x7b), (0x007c, 0x7c), (0x007d, 0x7d),
+    (0x007e, 0x7e), (0x007f, 0x7f), (0x0080, 0x80), (0x0081, 0x81), (0x0082, 0x82), (0x0083, 0x83),
+    (0x0084, 0x84), (0x0085, 0x85), (0x0086, 0x86), (0x0087, 0x87), (0x0088, 0x88), (0x0089, 0x89),
+    (0x008a, 0x8a), (0x008b, 0x8b), (0x008c, 0x8c), (0x008d, 0x8d), (0x008e, 0x8e), (0x008f, 0x8f),
+    (0x0090, 0x90), (0x0091, 0x91), (0x0092, 0x92), (0x0093, 0x93), (0x0094, 0x94), (0x0095, 0x95),
+    (0x0096, 0x96), (0x0097, 0x97), (0x0098, 0x98), (0x0099, 0x99), (0x009a, 0x9a), (0x009b, 0x9b),
+    (0x009c, 0x9c), (0x009d, 0x9d), (0x009e, 0x9e), (0x009f, 0x9f), (0x00a0, 0xa0), (0x00a1, 0xa1),
+    (0x00a2, 0xa2), (0x00a3, 0xa3), (0x00a4, 0xa4), (0x00a5, 0xa5), (0x00a6, 0xa6), (0x00a7, 0xa7),
+    (0x00a8, 0xa8), (0x00a9, 0xa9), (0x00aa, 0xaa), (0x00ab, 0xab), (0x00ac, 0xac), (0x00ad, 0xad),
+    (0x00ae, 0xae), (0x00af, 0xaf), (0x00b0, 0xb0), (0x00b1, 0xb1), (0x00b2, 0xb2), (0x00b3, 0xb3),
+    (0x00b4, 0xb4), (0x00b5, 0xb5), (0x00b6, 0xb6), (0x00b7, 0xb7), (0x00b8, 0xb8), (0x00b9, 0xb9),
+    (0x00ba, 0xba), (0x00bb, 0xbb), (0x00bc, 0xbc), (0x00bd, 0xbd), (0x00be, 0xbe), (0x00bf, 0xbf),
+    (0x00c0, 0xc0), (0x00c1, 0xc1), (0x00c2, 0xc2), (0x00c3, 0xc3), (0x00c4, 0xc4), (0x00c5, 0xc5),
+    (0x00c6, 0xc6), (0x00c7, 0xc7), (0x00c8, 0xc8), (0x00c9, 0xc9), (0x00ca, 0xca), (0x00cb, 0xcb),
+    (0x00cc, 0xcc), (0x00cd, 0xcd), (0x00ce, 0xce), (0x00cf, 0xcf), (0x00d0, 0xd0), (0x00d1, 0xd1),
+    (0x00d2, 0xd2), (0x00d3, 0xd3), (0x00d4, 0xd4), (0x00d5, 0xd5), (0x00d6, 0xd6), (0x00d7, 0xd7),
+    (0x00d8, 0xd8), (0x00d9, 0xd9), (0x00da, 0xda), (0x00db, 0xdb), (0x00dc, 0xdc), (0x00dd, 0xdd),
+    (0x00de, 0xde), (0x00df, 0xdf), (0x00e0, 0xe0), (0x00e1, 0xe1), (0x00e2, 0xe2), (0x00e3, 0xe3),
+    (0x00e4, 0xe4), (0x00e5, 0xe5), (0x00e6, 0xe6), (0x00e7, 0xe7), (0x00e8, 0xe8), (0x00e9, 0xe9),
+    (0x00ea, 0xea), (0x00eb, 0xeb), (0x00ec, 0xec), (0x00ed, 0xed), (0x00ee, 0xee), (0x00ef, 0xef),
+    (0x00f0, 0xf0), (0x00f1, 0xf1), (0x00f2, 0xf2), (0x00f3, 0xf3), (0x00f4, 0xf4), (0x00f5, 0xf5),
+    (0x00f6, 0xf6), (0x00f7, 0xf7), (0x00f8, 0xf8), (0x00f9, 0xf9), (0x00fa, 0xfa), (0x00fb, 0xfb),
+    (0x00fc, 0xfc), (0x00fd, 0xfd), (0x00fe, 0xfe), (0x00ff, 0xff), (0x060c, 0xac), (0x061b, 0xbb),
+    (0x061f, 0xbf), (0x0621, 0xc1), (0x0622, 0xc2), (0x0623, 0xc3), (0x0624, 0xc4), (0x0625, 0xc5),
+    (0x0626, 0xc6), (0x0627, 0xc7), (0x0628, 0xc8), (0x0629, 0xc9), (0x062a, 0xca), (0x062b, 0xcb),
+    (0x062c, 0xcc), (0x062d, 0xcd), (0x062e, 0xce), (0x062f, 0xcf), (0x0630, 0xd0), (0x0631, 0xd1),
+    (0x0632, 0xd2), (0x0633, 0xd3), (0x0634, 0xd4), (0x0635, 0xd5), (0x0636, 0xd6), (0x0637, 0xd7),
+    (0x0638, 0xd8), (0x0639, 0xd9), (0x063a, 0xda), (0x063b, 0xdb), (0x063c, 0xdc), (0x063d, 0xdd),
+    (0x063e, 0xde), (0x063f, 0xdf), (0x0640, 0xe0), (0x0641, 0xe1), (0x0642, 0xe2), (0x0643, 0xe3),
+    (0x0644, 0xe4), (0x0645, 0xe5), (0x0646, 0xe6), (0x0647, 0xe7), (0x0648, 0xe8), (0x0649, 0xe9),
+    (0x064a, 0xea), (0x064b, 0xeb), (0x064c, 0xec), (0x064d, 0xed), (0x064e, 0xee), (0x064f, 0xef),
+    (0x0650, 0xf0), (0x0651, 0xf1), (0x0652, 0xf2),
+];
+
+/// ISO8859_8 characters, indexed by their byte.
+pub(crate) static ISO8859_8_TO_UNICODE: [u32; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007,
+    0x0008, 0x0009, 0x000a, 0x000b, 0x000c, 0x000d, 0x000e, 0x000f,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017,
+    0x0018, 0x0019, 0x001a, 0x001b, 0x001c, 0x001d, 0x001e, 0x001f,
+    0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002a, 0x002b, 0x002c, 0x002d, 0x002e, 0x002f,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f,
+    0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005a, 0x005b, 0x005c, 0x005d, 0x005e, 0x005f,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x007b, 0x007c, 0x007d, 0x007e, 0x007f,
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008a, 0x008b, 0x008c, 0x008d, 0x008e, 0x008f,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009a, 0x009b, 0x009c, 0x009d, 0x009e, 0x009f,
+    0x00a0, 0x00a1, 0x00a2, 0x00a3, 0x00a4, 0x00a5, 0x00a6, 0x00a7,
+    0x00a8, 0x00a9, 0x00aa, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x00af,
+    0x00b0, 0x00b1, 0x00b2, 0x00b3, 0x00b4, 0x00b5, 0x00b6, 0x00b7,
+    0x00b8, 0x00b9, 0x00ba, 0x00bb, 0x00bc, 0x00bd, 0x00be, 0x00bf,
+    0x00c0, 0x00c1, 0x00c2, 0x00c3, 0x00c4, 0x00c5, 0x00c6, 0x00c7,
+    0x00c8, 0x00c9, 0x00ca, 0x00cb, 0x00cc, 0x00cd, 0x00ce, 0x00cf,
+    0x00d0, 0x00d1, 0x00d2, 0x00d3, 0x00d4, 0x00d5, 0x00d6, 0x00d7,
+    0x00d8, 0x00d9, 0x00da, 0x202d, 0x202e, 0x202c, 0x003f, 0x003f,
+    0x05d0, 0x05d1, 0x05d2, 0x05d3, 0x05d4, 0x05d5, 0x05d6, 0x05d7,
+    0x05d8, 0x05d9, 0x05da, 0x05db, 0x05dc, 0x05dd, 0x05de, 0x05df,
+    0x05e0, 0x05e1, 0x05e2, 0x05e3, 0x05e4, 0x05e5, 0x05e6, 0x05e7,
+    0x05e8, 0x05e9, 0x05ea, 0x202a, 0x202b, 0x200e, 0x200f, 0x003f,
+];
+
+/// Characters ISO8859_8 can encode, as (character, byte).
+pub(crate) static ISO8859_8_FROM_UNICODE: [(u32, u8); 162] = [
+    (0x0000, 0x00), (0x0001, 0x01), (0x0002, 0x02), (0x0003, 0x03), (0x0004, 0x04), (0x0005, 0x05),
+    (0x0006, 0x06), (0x0007, 0x07), (0x0008, 0x08), (0x0009, 0x09), (0x000a, 0x0a), (0x000b, 0x0b),
+    (0x000c, 0x0c), (0x000d, 0x0d), (0x000e, 0x0e), (0x000f, 0x0f), (0x0010, 0x10), (0x0011, 0x11),
+    (0x0012, 0x12), (0x0013, 0x13), (0x0014, 0x14), (0x0015, 0x15), (0x0016, 0x16), (0x0017, 0x17),
+    (0x0018, 0x18), (0x0019, 0x19), (0x001a, 0x1a), (0x001b, 0x1b), (0x001c, 0x1c), (0x001d, 0x1d),
+    (0x001e, 0x1e), (0x001f, 0x1f), (0x0020, 0x20), (0x0021, 0x21), (0x0022, 0x22), (0x0023, 0x23),
+    (0x0024, 0x24), (0x0025, 0x25), (0x0026, 0x26), (0x0027, 0x27), (0x0028, 0x28), (0x0029, 0x29),
+    (0x002a, 0x2a), (0x002b, 0x2b), (0x002c, 0x2c), (0x002d, 0x2d), (0x002e, 0x2e), (0x002f, 0x2f),
+    (0x0030, 0x30), (0x0031, 0x31), (0x0032, 0x32), (0x0033, 0x33), (0x0034, 0x34), (0x0035, 0x35),
+    (0x0036, 0x36), (0x0037, 0x37), (0x0038, 0x38), (0x0039, 0x39), (0x003a, 0x3a), (0x003b, 0x3b),
+    (0x003c, 0x3c), (0x003d, 0x3d), (0x003e, 0x3e), (0x003f, 0x3f), (0x0040, 0x40), (0x0041, 0x41),
+    (0x0042, 0x42), (0x0043, 0x43), (0x0044, 0x44), (0x0045, 0x45), (0x0046, 0x46), (0x0047, 0x47),
+    (0x0048, 0x48), (0x0049, 0x49), (0x004a, 0x4a), (0x004b, 0x4b), (0x004c, 0x4c), (0x004d, 0x4d),
+    (0x004e, 0x4e), (0x004f, 0x4f), (0x0050, 0x50), (0x0051, 0x51), (0x0052, 0x52), (0x0053, 0x53),
+    (0x0054, 0x54), (0x0055, 0x55), (0x0056, 0x56), (0x0057, 0x57), (0x0058, 0x58), (0x0059, 0x59),
+    (0x005a, 0x5a), (0x005b, 0x5b), (0x005c, 0x5c), (0x005d, 0x5d), (0x005e, 0x5e), (0x005f, 0x5f),
+    (0x0060, 0x60), (0x0061, 0x61), (0x0062, 0x62), (0x0063, 0x63), (0x0064, 0x64), (0x0065, 0x65),
+    (0x0066, 0x66), (0x0067, 0x67), (0x0068, 0x68), (0x0069, 0x69), (0x006a, 0x6a), (0x006b, 0x6b),
+    (0x006c, 0x6c), (0x006d, 0x6d), (0x006e, 0x6e), (0x006f, 0x6f), (0x0070, 0x70), (0x0071, 0x71),
+    (0x0072, 0x72), (0x0073, 0x73), (0x0074, 0x74), (0x0075, 0x75), (0x0076, 0x76), (0x0077, 0x77),
+    (0x0078, 0x78), (0x0079, 0x79), (0x007a, 0x7a), (0x007b, 0x7b), (0x007c, 0x7c), (0x007d, 0x7d),
+    (0x007e, 0x7e), (0x007f, 0x7f), (0x05d0, 0xe0), (0x05d1, 0xe1), (0x05d2, 0xe2), (0x05d3, 0xe3),
+    (0x05d4, 0xe4), (0x05d5, 0xe5), (0x05d6, 0xe6), (0x05d7, 0xe7), (0x05d8, 0xe8), (0x05d9, 0xe9),
+    (0x05da, 0xea), (0x05db, 0xeb), (0x05dc, 0xec), (0x05dd, 0xed), (0x05de, 0xee), (0x05df, 0xef),
+    (0x05e0, 0xf0), (0x05e1, 0xf1), (0x05e2, 0xf2), (0x05e3, 0xf3), (0x05e4, 0xf4), (0x05e5, 0xf5),
+    (0x05e6, 0xf6), (0x05e7, 0xf7), (0x05e8, 0xf8), (0x05e9, 0xf9), (0x05ea, 0xfa), (0x200e, 0xfd),
+    (0x200f, 0xfe), (0x202a, 0xfb), (0x202b, 0xfc), (0x202c, 0xdd), (0x202d, 0xdb), (0x202e, 0xdc),
+];
+
+/// CP1255 characters, indexed by their byte.
+pub(crate) static CP1255_TO_UNICODE: [u32; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007,
+    0x0008, 0x0009, 0x000a, 0x000b, 0x000c, 0x000d, 0x000e, 0x000f,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017,
+    0x0018, 0x0019, 0x001a, 0x001b, 0x001c, 0x001d, 0x001e, 0x001f,
+    0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002a, 0x002b, 0x002c, 0x002d, 0x002e, 0x002f,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f,
+    0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005a, 0x005b, 0x005c, 0x005d, 0x005e, 0x005f,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x007b, 0x007c, 0x007d, 0x007e, 0x007f,
+    0x20ac, 0x0081, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+    0x02c6, 0x2030, 0x008a, 0x2039, 0x008c, 0x008d, 0x008e, 0x008f,
+    0x0090, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+    0x02dc, 0x2122, 0x009a, 0x203a, 0x009c, 0x009d, 0x009e, 0x009f,
+    0x00a0, 0x00a1, 0x00a2, 0x00a3, 0x20aa, 0x00a5, 0x00a6, 0x00a7,
+    0x00a8, 0x00a9, 0x00d7, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x00af,
+    0x00b0, 0x00b1, 0x00b2, 0x00b3, 0x00b4, 0x00b5, 0x00b6, 0x00b7,
+    0x00b8, 0x00b9, 0x00f7, 0x00bb, 0x00bc, 0x00bd, 0x00be, 0x00bf,
+    0x05b0, 0x05b1, 0x05b2, 0x05b3, 0x05b4, 0x05b5, 0x05b6, 0x05b7,
+    0x05b8, 0x05b9, 0x05ba, 0x05bb, 0x05bc, 0x05bd, 0x05be, 0x05bf,
+    0x05c0, 0x05c1, 0x05c2, 0x05c3, 0x05f0, 0x05f1, 0x05f2, 0x05f3,
+    0x05f4, 0x00d9, 0x00da, 0x00db, 0x00dc, 0x00dd, 0x00de, 0x00df,
+    0x05d0, 0x05d1, 0x05d2, 0x05d3, 0x05d4, 0x05d5, 0x05d6, 0x05d7,
+    0x05d8, 0x05d9, 0x05da, 0x05db, 0x05dc, 0x05dd, 0x05de, 0x05df,
+    0x05e0, 0x05e1, 0x05e2, 0x05e3, 0x05e4, 0x05e5, 0x05e6, 0x05e7,
+    0x05e8, 0x05e9, 0x05ea, 0x00fb, 0x00fc, 0x200e, 0x200f, 0x00ff,
+];
+
+/// Characters CP1255 can encode, as (character, byte).
+pub(crate) static CP1255_FROM_UNICODE: [(u32, u8); 310] = [
+    (0x0000, 0x00), (0x0001, 0x01), (0x0002, 0x02), (0x0003, 0x03), (0x0004, 0x04), (0x0005, 0x05),
+    (0x0006, 0x06), (0x0007, 0x07), (0x0008, 0x08), (0x0009, 0x09), (0x000a, 0x0a), (0x000b, 0x0b),
+    (0x000c, 0x0c), (0x000d, 0x0d), (0x000e, 0x0e), (0x000f, 0x0f), (0x0010, 0x10), (0x0011, 0x11),
+    (0x0012, 0x12), (0x0013, 0x13), (0x0014, 0x14), (0x0015, 0x15), (0x0016, 0x16), (0x0017, 0x17),
+    (0x0018, 0x18), (0x0019, 0x19), (0x001a, 0x1a), (0x001b, 0x1b), (0x001c, 0x1c), (0x001d, 0x1d),
+    (0x001e, 0x1e), (0x001f, 0x1f), (0x0020, 0x20), (0x0021, 0x21), (0x0022, 0x22), (0x0023, 0x23),
+    (0x0024, 0x24), (0x0025, 0x25), (0x0026, 0x26), (0x0027, 0x27), (0x0028, 0x28), (0x0029, 0x29),
+    (0x002a, 0x2a), (0x002b, 0x2b), (0x002c, 0x2c), (0x002d, 0x2d), (0x002e, 0x2e), (0x002f, 0x2f),
+    (0x0030, 0x30), (0x0031, 0x31), (0x0032, 0x32), (0x0033, 0x33), (0x0034, 0x34), (0x0035, 0x35),
+    (0x0036, 0x36), (0x0037, 0x37), (0x0038, 0x38), (0x0039, 0x39), (0x003a, 0x3a), (0x003b, 0x3b),
+    (0x003c, 0x3c), (0x003d, 0x3d), (0x003e, 0x3e), (0x003f, 0x3f), (0x0040, 0x40), (0x0041, 0x41),
+    (0x0042, 0x42), (0x0043, 0x43), (0x0044, 0x44), (0x0045, 0x45), (0x0046, 0x46), (0x0047, 0x47),
+    (0x0048, 0x48), (0x0049, 0x49), (0x004a, 0x4a), (0x004b, 0x4b), (0x004c, 0x4c), (0x004d, 0x4d),
+    (0x004e, 0x4e), (0x004f, 0x4f), (0x0050, 0x50), (0x0051, 0x51), (0x0052, 0x52), (0x0053, 0x53),
+    (0x0054, 0x54), (0x0055, 0x55), (0x0056, 0x56), (0x0057, 0x57), (0x0058, 0x58), (0x0059, 0x59),
+    (0x005a, 0x5a), (0x005b, 0x5b), (0x005c, 0x5c), (0x005d, 0x5d), (0x005e, 0x5e), (0x005f, 0x5f),
+    (0x0060, 0x60), (0x0061, 0x61), (0x0062, 0x62), (0x0063, 0x63), (0x0064, 0x64), (0x0065, 0x65),
+    (0x0066, 0x66), (0x0067, 0x67), (0x0068, 0x68), (0x0069, 0x69), (0x006a, 0x6a), (0x006b, 0x6b),
+    (0x006c, 0x6c), (0x006d, 0x6d), (0x006e, 0x6e), (0x006f, 0x6f), (0x0070, 0x70), (0x0071, 0x71),
+    (0x0072, 0x72), (0x0073, 0x73), (0x0074, 0x74), (0x0075, 0x75), (0x0076, 0x76), (0x0077, 0x77),
+    (0x0078, 0x78), (0x0079, 0x79), (0x007a, 0x7a), (0x007b, 0x7b), (0x007c, 0x7c), (0x007d, 0x7d),
+    (0x007e, 0x7e), (0x007f, 0x7f), (0x0080, 0x80), (0x0081, 0x81), (0x0082, 0x82), (0x0083, 0x83),
+    (0x0084, 0x84), (0x0085, 0x85), (0x0086, 0x86), (0x0087, 0x87), (0x0088, 0x88), (0x0089, 0x89),
+    (0x008a, 0x8a), (0x008b, 0x8b), (0x008c, 0x8c), (0x008d, 0x8d), (0x008e, 0x8e), (0x008f, 0x8f),
+    (0x0090, 0x90), (0x0091, 0x91), (0x0092, 0x92), (0x0093, 0x93), (0x0094, 0x94), (0x0095, 0x95),
+    (0x0096, 0x96), (0x0097, 0x97), (0x0098, 0x98), (0x0099, 0x99), (0x009a, 0x9a), (0x009b, 0x9b),
+    (0x009c, 0x9c), (0x009d, 0x9d), (0x009e, 0x9e), (0x009f, 0x9f), (0x00a0, 0xa0), (0x00a1, 0xa1),
+    (0x00a2, 0xa2), (0x00a3, 0xa3), (0x00a4, 0xa4), (0x00a5, 0xa5), (0x00a6, 0xa6), (0x00a7, 0xa7),
+    (0x00a8, 0xa8), (0x00a9, 0xa9), (0x00aa, 0xaa), (0x00ab, 0xab), (0x00ac, 0xac), (0x00ad, 0xad),
+    (0x00ae, 0xae), (0x00af, 0xaf), (0x00b0, 0xb0), (0x00b1, 0xb1), (0x00b2, 0xb2), (0x00b3, 0xb3),
+    (0x00b4, 0xb4), (0x00b5, 0xb5), (0x00b6, 0xb6), (0x00b7, 0xb7), (0x00b8, 0xb8), (0x00b9, 0xb9),
+    (0x00ba, 0xba), (0x00bb, 0xbb), (0x00bc, 0xbc), (0x00bd, 0xbd), (0x00be, 0xbe), (0x00bf, 0xbf),
+    (0x00c0, 0xc0), (0x00c1, 0xc1), (0x00c2, 0xc2), (0x00c3, 0xc3), (0x00c4, 0xc4), (0x00c5, 0xc5),
+    (0x00c6, 0xc6), (0x00c7, 0xc7), (0x00c8, 0xc8), (0x00c9, 0xc9), (0x00ca, 0xca), (0x00cb, 0xcb),
+    (0x00cc, 0xcc), (0x00cd, 0xcd), (0x00ce, 0xce), (0x00cf, 0xcf), (0x00d0, 0xd0), (0x00d1, 0xd1),
+    (0x00d2, 0xd2), (0x00d3, 0xd3), (0x00d4, 0xd4), (0x00d5, 0xd5), (0x00d6, 0xd6), (0x00d7, 0xd7),
+    (0x00d8, 0xd8), (0x00d9, 0xd9), (0x00da, 0xda), (0x00db, 0xdb), (0x00dc, 0xdc), (0x00dd, 0xdd),
+    (0x00de, 0xde), (0x00df, 0xdf), (0x00e0, 0xe0), (0x00e1, 0xe1), (0x00e2, 0xe2), (0x00e3, 0xe3),
+    (0x00e4, 0xe4), (0x00e5, 0xe5), (0x00e6, 0xe6), (0x00e7, 0xe7), (0x00e8, 0xe8), (0x00e9, 0xe9),
+    (0x00ea, 0xea), (0x00eb, 0xeb), (0x00ec, 0xec), (0x00ed, 0xed), (0x00ee, 0xee), (0x00ef, 0xef),
+    (0x00f0, 0xf0), (0x00f1, 0xf1), (0x00f2, 0xf2), (0x00f3, 0xf3), (0x00f4, 0xf4), (0x00f5, 0xf5),
+    (0x00f6, 0xf6), (0x00f7, 0xf7), (0x00f8, 0xf8), (0x00f9, 0xf9), (0x00fa, 0xfa), (0x00fb, 0xfb),
+    (0x00fc, 0xfc), (0x00fd, 0xfd), (0x00fe, 0xfe), (0x00ff, 0xff), (0x05b0, 0xc0), (0x05b1, 0xc1),
+    (0x05b2, 0xc2), (0x05b3, 0xc3), (0x05b4, 0xc4), (0x05b5, 0xc5), (0x05b6, 0xc6), (0x05b7, 0xc7),
+    (0x05b8, 0xc8), (0x05b9, 0xc9), (0x05ba, 0xca), (0x05bb, 0xcb), (0x05bc, 0xcc), (0x05bd, 0xcd),
+    (0x05be, 0xce), (0x05bf, 0xcf), (0x05c0, 0xd0), (0x05c1, 0xd1), (0x05c2, 0xd2), (0x05c3, 0xd3),
+    (0x05d0, 0xe0), (0x05d1, 0xe1), (0x05d2, 0xe2), (0x05d3, 0xe3), (0x05d4, 0xe4), (0x05d5, 0xe5),
+    (0x05d6, 0xe6), (0x05d7, 0xe7), (0x05d8, 0xe8), (0x05d9, 0xe9), (0x05da, 0xea), (0x05db, 0xeb),
+    (0x05dc, 0xec), (0x05dd, 0xed), (0x05de, 0xee), (0x05df, 0xef), (0x05e0, 0xf0), (0x05e1, 0xf1),
+    (0x05e2, 0xf2), (0x05e3, 0xf3), (0x05e4, 0xf4), (0x05e5, 0xf5), (0x05e6, 0xf6), (0x05e7, 0xf7),
+    (0x05e8, 0xf8), (0x05e9, 0xf9), (0x05ea, 0xfa), (0x05f0, 0xd4), (0x05f1, 0xd5), (0x05f2, 0xd6),
+    (0x05f3, 0xd7), (0x05f4, 0xd8), (0x200e, 0xfd), (0x200f, 0xfe),
+];
+
+/// CP1256 characters, indexed by their byte.
+pub(crate) static CP1256_TO_UNICODE: [u32; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007,
+    0x0008, 0x0009, 0x000a, 0x000b, 0x000c, 0x000d, 0x000e, 0x000f,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017,
+    0x0018, 0x0019, 0x001a, 0x001b, 0x001c, 0x001d, 0x001e, 0x001f,
+    0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002a, 0x002b, 0x002c, 0x002d, 0x002e, 0x002f,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f,
+    0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005a, 0x005b, 0x005c, 0x005d, 0x005e, 0x005f,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d, 0x006e, 0x006f,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x007b, 0x007c, 0x007d, 0x007e, 0x007f,
+    0x20ac, 0x067e, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+    0x02c6, 0x2030, 0x0679, 0x2039, 0x0152, 0x0686, 0x0698, 0x0688,
+    0x06af, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+    0x06a9, 0x2122, 0x0691, 0x203a, 0x0153, 0x200c, 0x200d, 0x06ba,
+    0x00a0, 0x060c, 0x00a2, 0x00a3, 0x00a4, 0x00a5, 0x00a6, 0x00a7,
+    0x00a8, 0x00a9, 0x06be, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x00af,
+    0x00b0, 0x00b1, 0x00b2, 0x00b3, 0x00b4, 0x00b5, 0x00b6, 0x00b7,
+    0x00b8, 0x00b9, 0x061b, 0x00bb, 0x00bc, 0x00bd, 0x00be, 0x061f,
+    0x06c1, 0x0621, 0x0622, 0x0623, 0x0624, 0x0625, 0x0626, 0x0627,
+    0x0628, 0x0629, 0x062a, 0x062b, 0x062c, 0x062d, 0x062e, 0x062f,
+    0x0630, 0x0631, 0x0632, 0x0633, 0x0634, 0x0635, 0x0636, 0x00d7,
+    0x0637, 0x0638, 0x0639, 0x063a, 0x0640, 0x0641, 0x0642, 0x0643,
+    0x00e0, 0x0644, 0x00e2, 0x0645, 0x0646, 0x0647, 0x0648, 0x00e7,
+    0x00e8, 0x00e9, 0x00ea, 0x00eb, 0x0649, 0x064a, 0x00ee, 0x00ef,
+    0x064b, 0x064c, 0x064d, 0x064e, 0x00f4, 0x064f, 0x0650, 0x00f7,
+    0x0651, 0x00f9, 0x0652, 0x00fb, 0x00fc, 0x200e, 0x200f, 0x00ff,
+];
+
+/// Characters CP1256 can encode, as (character, byte).
+pub(crate) static CP1256_FROM_UNICODE: [(u32, u8); 340] = [
+    (0x0000, 0x00), (0x0001, 0x01), (0x0002, 0x02), (0x0003, 0x03), (0x0004, 0x04), (0x0005, 0x05),
+    (0x0006, 0x06), (0x0007, 0x07), (0x0008, 0x08), (0x0009, 0x09), (0x000a, 0x0a), (0x000b, 0x0b),
+    (0x000c, 0x0c), (0x000d, 0x0d), (0x000e, 0x0e), (0x000f, 0x0f), (0x0010, 0x10), (0x0011, 0x11),
+    (0x0012, 0x12), (0x0013, 0x13), (0x0014, 0x14), (0x0015, 0x15), (0x0016, 0x16), (0x0017, 0x17),
+    (0x0018, 0x18), (0x0019, 0x19), (0x001a, 0x1a), (0x001b, 0x1b), (0x001c, 0x1c), (0x001d, 0x1d),
+    (0x001e, 0x1e), (0x001f, 0x1f), (0x0020, 0x20), (0x0021, 0x21), (0x0022, 0x22), (0x0023, 0x23),
+    (0x0024, 0x24), (0x0025, 0x25), (0x0026, 0x26), (0x0027, 0x27), (0x0028, 0x28), (0x0029, 0x29),
+    (0x002a, 0x2a), (0x002b, 0x2b), (0x002c, 0x2c), (0x002d, 0x2d), (0x002e, 0x2e), (0x002f, 0x2f),
+    (0x0030, 0x30), (0x0031, 0x31), (0x0032, 0x32), (0x0033, 0x33), (0x0034, 0x34), (0x0035, 0x35),
+    (0x0036, 0x36), (0x0037, 0x37), (0x0038, 0x38), (0x0039, 0x39), (0x003a, 0x3a), (0x003b, 0x3b),
+    (0x003c, 0x3c), (0x003d, 0x3d), (0x003e, 0x3e), (0x003f, 0x3f), (0x0040, 0x40), (0x0041, 0x41),
+    (0x0042, 0x42), (0x0043, 0x43), (0x0044, 0x44), (0x0045, 0x45), (0x0046, 0x46), (0x0047, 0x47),
+    (0x0048, 0x48), (0x0049, 0x49), (0x004a, 0x4a), (0x004b, 0x4b), (0x004c, 0x4c), (0x004d, 0x4d),
+    (0x004e, 0x4e), (0x004f, 0x4f), (0x0050, 0x50), (0x0051, 0x51), (0x0052, 0x52), (0x0053, 0x53),
+    (0x0054, 0x54), (0x0055, 0x55), (0x0056, 0x56), (0x0057, 0x57), (0x0058, 0x58), (0x0059, 0x59),
+    (0x005a, 0x5a), (0x005b, 0x5b), (0x005c, 0x5c), (0x005d, 0x5d), (0x005e, 0x5e), (0x005f, 0x5f),
+    (0x0060, 0x60), (0x0061, 0x61), (0x0062, 0x62), (0x0063, 0x63), (0x0064, 0x64), (0x0065, 0x65),
+    (0x0066, 0x66), (0x0067, 0x67), (0x0068, 0x68), (0x0069, 0x69), (0x006a, 0x6a), (0x006b, 0x6b),
+    (0x006c, 0x6c), (0x006d, 0x6d), (0x006e, 0x6e), (0x006f, 0x6f), (0x0070, 0x70), (0x0071, 0x71),
+    (0x0072, 0x72), (0x0073, 0x73), (0x0074, 0x74), (0x0075, 0x75), (0x0076, 0x76), (0x0077, 0x77),
+    (0x0078, 0x78), (0x0079, 0x79), (0x007a, 0x7a), (0x007b, 0x7b), (0x007c, 0x7c), (0x007d, 0x7d),
+    (0x007e, 0x7e), (0x007f, 0x7f), (0x0080, 0x80), (0x0081, 0x81), (0x0082, 0x82), (0x0083, 0x83),
+    (0x0084, 0x84), (0x0085, 0x85), (0x0086, 0x86), (0x0087, 0x87), (0x0088, 0x88), (0x0089, 0x89),
+    (0x008a, 0x8a), (0x008b, 0x8b), (0x008c, 0x8c), (0x008d, 0x8d), (0x008e, 0x8e), (0x008f, 0x8f),
+    (0x0090, 0x90), (0x0091, 0x91), (0x0092, 0x92), (0x0093, 0x93), (0x0094, 0x94), (0x0095, 0x95),
+    (0x0096, 0x96), (0x0097, 0x97), (0x0098, 0x98), (0x0099, 0x99), (0x009a, 0x9a), (0x009b, 0x9b),
+    (0x009c, 0x9c), (0x009d, 0x9d), (0x009e, 0x9e), (0x009f, 0x9f), (0x00a0, 0xa0), (0x00a1, 0xa1),
+    (0x00a2, 0xa2), (0x00a3, 0xa3), (0x00a4, 0xa4), (0x00a5, 0xa5), (0x00a6, 0xa6), (0x00a7, 0xa7),
+    (0x00a8, 0xa8), (0x00a9, 0xa9), (0x00aa, 0xaa), (0x00ab, 0xab), (0x00ac, 0xac), (0x00ad, 0xad),
+    (0x00ae, 0xae), (0x00af, 0xaf), (0x00b0, 0xb0), (0x00b1, 0xb1), (0x00b2, 0xb2), (0x00b3, 0xb3),
+    (0x00b4, 0xb4), (0x00b5, 0xb5), (0x00b6, 0xb6), (0x00b7, 0xb7), (0x00b8, 0xb8), (0x00b9, 0xb9),
+    (0x00ba, 0xba), (0x00bb, 0xbb), (0x00bc, 0xbc), (0x00bd, 0xbd), (0x00be, 0xbe), (0x00bf, 0xbf),
+    (0x00c0, 0xc0), (0x00c1, 0xc1), (0x00c2, 0xc2), (0x00c3, 0xc3), (0x00c4, 0xc4), (0x00c5, 0xc5),
+    (0x00c6, 0xc6), (0x00c7, 0xc7), (0x00c8, 0xc8), (0x00c9, 0xc9), (0x00ca, 0xca), (0x00cb, 0xcb),
+    (0x00cc, 0xcc), (0x00cd, 0xcd), (0x00ce, 0xce), (0x00cf, 0xcf), (0x00d0, 0xd0), (0x00d1, 0xd1),
+    (0x00d2, 0xd2), (0x00d3, 0xd3), (0x00d4, 0xd4), (0x00d5, 0xd5), (0x00d6, 0xd6), (0x00d7, 0xd7),
+    (0x00d8, 0xd8), (0x00d9, 0xd9), (0x00da, 0xda), (0x00db, 0xdb), (0x00dc, 0xdc), (0x00dd, 0xdd),
+    (0x00de, 0xde), (0x00df, 0xdf), (0x00e0, 0xe0), (0x00e1, 0xe1), (0x00e2, 0xe2), (0x00e3, 0xe3),
+    (0x00e4, 0xe4), (0x00e5, 0xe5), (0x00e6, 0xe6), (0x00e7, 0xe7), (0x00e8, 0xe8), (0x00e9, 0xe9),
+    (0x00ea, 0xea), (0x00eb, 0xeb), (0x00ec, 0xec), (0x00ed, 0xed), (0x00ee, 0xee), (0x00ef, 0xef),
+    (0x00f0, 0xf0), (0x00f1, 0xf1), (0x00f2, 0xf2), (0x00f3, 0xf3), (0x00f4, 0xf4), (0x00f5, 0xf5),
+    (0x00f6, 0xf6), (0x00f7, 0xf7), (0x00f8, 0xf8), (0x00f9, 0xf9), (0x00fa, 0xfa), (0x00fb, 0xfb),
+    (0x00fc, 0xfc), (0x00fd, 0xfd), (0x00fe, 0xfe), (0x00ff, 0xff), (0x0152, 0x8c), (0x0153, 0x9c),
+    (0x0192, 0x83), (0x02c6, 0x88), (0x060c, 0xa1), (0x061b, 0xba), (0x061f, 0xbf), (0x0621, 0xc1),
+    (0x0622, 0xc2), (0x0623, 0xc3), (0x0624, 0xc4), (0x0625, 0xc5), (0x0626, 0xc6), (0x0627, 0xc7),
+    (0x0628, 0xc8), (0x0629, 0xc9), (0x062a, 0xca), (0x062b, 0xcb), (0x062c, 0xcc), (0x062d, 0xcd),
+    (0x062e, 0xce), (0x062f, 0xcf), (0x0630, 0xd0), (0x0631, 0xd1), (0x0632, 0xd2), (0x0633, 0xd3),
+    (0x0634, 0xd4), (0x0635, 0xd5), (0x0636, 0xd6), (0x0637, 0xd8), (0x0638, 0xd9), (0x0639, 0xda),
+    (0x063a, 0xdb), (0x0640, 0xdc), (0x0641, 0xdd), (0x0642, 0xde), (0x0643, 0xdf), (0x0644, 0xe1),
+    (0x0645, 0xe3), (0x0646, 0xe4), (0x0647, 0xe5), (0x0648, 0xe6), (0x0649, 0xec), (0x064a, 0xed),
+    (0x064b, 0xf0), (0x064c, 0xf1), (0x064d, 0xf2), (0x064e, 0xf3), (0x064f, 0xf5), (0x0650, 0xf6),
+    (0x0651, 0xf8), (0x0652, 0xfa), (0x0679, 0x8a), (0x067e, 0x81), (0x0686, 0x8d), (0x0688, 0x8f),
+    (0x0691, 0x9a), (0x0698, 0x8e), (0x06a9, 0x98), (0x06af, 0x90), (0x06ba, 0x9f), (0x06be, 0xaa),
+    (0x06c1, 0xc0), (0x200c, 0x9d), (0x200d, 0x9e), (0x200e, 0xfd), (0x200f, 0xfe), (0x2013, 0x96),
+    (0x2014, 0x97), (0x2018, 0x91), (0x2019, 0x92), (0x201a, 0x82), (0x201c, 0x93), (0x201d, 0x94),
+    (0x201e, 0x84), (0x2020, 0x86), (0x2021, 0x87), (0x2022, 0x95), (0x2026, 0x85), (0x2030, 0x89),
+    (0x2039, 0x8b), (0x203a, 0x9b), (0x20ac, 0x80), (0x2122, 0x99),
+];
+
@@ -1,6 +1,18 @@
-use fribidi_sys::fribidi_bindings;
+use std::ops::Range;
 
+use crate::fribidi_bindings;
+
+use widestring::{U32Str, U32String};
+
+use crate::bracket::BracketType;
 use crate::char::CharType;
+use crate::error::Error;
+use crate::flag::FriBidiFlag;
+use crate::input::{BidiInput, PositionMap};
+use crate::level::LevelType;
+use crate::mirror;
+use crate::run::BidiRun;
+use crate::Fribidi;
 
 pub type Paragraph = u32;
 
@@ -15,6 +27,26 @@ pub enum ParagraphType
     WeakRightToLeft = fribidi_bindings::FriBidiParType_FRIBIDI_PAR_WRTL,
 }
 
+/// Convert a raw fribidi paragraph type value, as returned by `as_raw`, back
+/// to a ParagraphType.  Other values give `InvalidParagraphType`.
+impl TryFrom<Paragraph> for ParagraphType
+{
+    type Error = Error;
+
+    fn try_from(raw: Paragraph) -> Result<Self, Self::Error>
+    {
+        match raw
+        {
+            fribidi_bindings::FriBidiParType_FRIBIDI_PAR_LTR => Ok(ParagraphType::LeftToRight),
+            fribidi_bindings::FriBidiParType_FRIBIDI_PAR_RTL => Ok(ParagraphType::RightToLeft),
+            fribidi_bindings::FriBidiParType_FRIBIDI_PAR_ON => Ok(ParagraphType::OtherNeutral),
+            fribidi_bindings::FriBidiParType_FRIBIDI_PAR_WLTR => Ok(ParagraphType::WeakLeftToRight),
+            fribidi_bindings::FriBidiParType_FRIBIDI_PAR_WRTL => Ok(ParagraphType::WeakRightToLeft),
+            _ => Err(Error::InvalidParagraphType(raw))
+        }
+    }
+}
+
 impl ParagraphType
 {
     /// same as `fribidi_get_par_direction` - get base paragraph direction
@@ -35,15 +67,20 @@ impl ParagraphType
     /// Returns: Base pargraph direction.  No weak paragraph direction is returned,
     /// only LeftToRight, RightToLeft, or OtherNeutral.
     ///
-    pub fn direction (char_types: &Vec<CharType>) -> ParagraphType
+    pub fn direction (char_types: &[CharType]) -> Result<ParagraphType, Error>
     {
+        let len = Error::ffi_len(char_types.len())?;
         let par_direction = unsafe {
-            // let bidi_type_name = fribidi_bindings::fribidi_get_bidi_type_name(char_type as u32);
-            // fribidi_bindings::fribidi_get_par_direction(bidi_type_name, len);
-            fribidi_bindings::fribidi_get_par_direction(char_types.as_ptr() as *const u32, char_types.len() as i32)
+            fribidi_bindings::fribidi_get_par_direction(char_types.as_ptr() as *const u32, len)
         };
 
-        unsafe { std::mem::transmute (par_direction) }
+        Self::try_from(par_direction)
+    }
+
+    /// The raw fribidi paragraph type value.
+    pub const fn as_raw(self) -> Paragraph
+    {
+        self as Paragraph
     }
 
     /// Weaken type for paragraph fallback purposes:
@@ -54,10 +91,228 @@ impl ParagraphType
     }
 }
 
+/// A single paragraph run through the bidi algorithm.
+///
+/// This owns the text together with everything fribidi resolves for it: the
+/// bidi types, the bracket types, the embedding levels and the base
+/// direction.  It is the usual chain of `CharType::into_chartypes`,
+/// `BracketType::parse` and `Fribidi::get_paragraph_embedding_levels_ex`,
+/// done once.
+#[derive(Debug, Clone)]
+pub struct BidiParagraph
+{
+    text: U32String,
+    char_types: Vec<CharType>,
+    bracket_types: Vec<BracketType>,
+    levels: Vec<LevelType>,
+    max_level: LevelType,
+    base_direction: ParagraphType,
+}
+
+impl BidiParagraph
+{
+    /// Resolve the embedding levels of text.
+    ///
+    /// base is the requested paragraph direction; pass OtherNeutral to let
+    /// fribidi detect it from the first strong character (rules P2 and P3).
+    ///
+    pub fn new<T: BidiInput + ?Sized>(text: &T, base: ParagraphType) -> Result<BidiParagraph, Error>
+    {
+        Self::from_u32string(text.to_u32str().into_owned(), base)
+    }
+
+    pub(crate) fn from_u32string(text: U32String, base: ParagraphType) -> Result<BidiParagraph, Error>
+    {
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let bracket_types = BracketType::parse(&text, &char_types)?;
+
+        let (levels, max_level, base_direction) = Fribidi::get_paragraph_embedding_levels_ex(
+            &char_types,
+            Some(&bracket_types),
+            base
+        )?;
+
+        Ok(BidiParagraph {
+            text,
+            char_types,
+            bracket_types,
+            levels,
+            max_level,
+            base_direction
+        })
+    }
+
+    /// The logical text of the paragraph.
+    pub fn text(&self) -> &U32Str
+    {
+        &self.text
+    }
+
+    /// Number of characters in the paragraph.
+    pub fn len(&self) -> usize
+    {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.text.is_empty()
+    }
+
+    pub fn char_types(&self) -> &[CharType]
+    {
+        &self.char_types
+    }
+
+    pub fn bracket_types(&self) -> &[BracketType]
+    {
+        &self.bracket_types
+    }
+
+    /// The resolved embedding levels, one per character, before rule L1 part
+    /// 4 is applied per line.
+    pub fn levels(&self) -> &[LevelType]
+    {
+        &self.levels
+    }
+
+    /// Maximum level found plus one.
+    pub fn max_level(&self) -> LevelType
+    {
+        self.max_level
+    }
+
+    /// The resolved paragraph direction, LeftToRight or RightToLeft.
+    pub fn base_direction(&self) -> ParagraphType
+    {
+        self.base_direction
+    }
+
+    /// The level runs of the paragraph in logical order: maximal ranges of
+    /// characters sharing the same embedding level.
+    pub fn runs(&self) -> Vec<(Range<usize>, LevelType)>
+    {
+        let mut runs: Vec<(Range<usize>, LevelType)> = Vec::new();
+        for (index, &level) in self.levels.iter().enumerate()
+        {
+            match runs.last_mut()
+            {
+                Some((range, run_level)) if *run_level == level => range.end = index + 1,
+                _ => runs.push((index..index + 1, level))
+            }
+        }
+
+        runs
+    }
+
+    /// Reorder the characters in range to visual order, as one line of the
+    /// paragraph.  Mirroring and rule L1 part 4 are applied to the line.
+    pub fn visual_line(&self, range: Range<usize>) -> Result<String, Error>
+    {
+        Ok(self.visual_line_map(range)?.0)
+    }
+
+    /// Same as `visual_line`, along with the visual to logical map of the
+    /// line in characters, UTF-8 and UTF-16 offsets.  The positions are
+    /// relative to the start of the line on both sides.
+    pub fn visual_line_map(&self, range: Range<usize>) -> Result<(String, PositionMap), Error>
+    {
+        Error::check_range(&range, self.len())?;
+
+        let logical_str = &self.text[range.clone()];
+        let mut visual_str = logical_str.to_owned();
+        let mut levels = self.levels[range.clone()].to_vec();
+
+        // mirroring works on the logical order, before the line is reordered
+        mirror::shape_mirroring(&levels, &mut visual_str)?;
+
+        let (_, map) = Fribidi::reorder_line(
+            FriBidiFlag::Default,
+            &self.char_types[range],
+            self.base_direction,
+            &mut levels,
+            &mut visual_str
+        )?;
+
+        let visual_to_logic = PositionMap::from_reorder_map(&map, logical_str, &visual_str)?;
+        Ok((visual_str.to_string_lossy(), visual_to_logic))
+    }
+
+    /// The level runs of one line of the paragraph, in visual order, with
+    /// rule L1 part 4 applied to the end of line.
+    pub fn visual_runs(&self, line: Range<usize>) -> Result<Vec<BidiRun>, Error>
+    {
+        Error::check_range(&line, self.len())?;
+
+        let mut visual_str = self.text[line.clone()].to_owned();
+        let mut levels = self.levels[line.clone()].to_vec();
+
+        let (_, map) = Fribidi::reorder_line(
+            FriBidiFlag::Default,
+            &self.char_types[line.clone()],
+            self.base_direction,
+            &mut levels,
+            &mut visual_str
+        )?;
+
+        Ok(BidiRun::visual_runs(&levels, &map)?
+            .map(|run| BidiRun {
+                logical_range: run.logical_range.start + line.start..run.logical_range.end + line.start,
+                ..run
+            })
+            .collect())
+    }
+
+    /// Reorder the paragraph broken into lines, as a layout engine that picks
+    /// the line breaks before reordering needs.
+    ///
+    /// lines are character ranges of the paragraph in logical order and must
+    /// not overlap.  The embedding levels are resolved only once, for the
+    /// whole paragraph, and each line is reordered on its own with rule L1
+    /// part 4 applied to its trailing white space.
+    ///
+    /// Returns: the visual string of every line.
+    ///
+    pub fn visual_lines(&self, lines: &[Range<usize>]) -> Result<Vec<String>, Error>
+    {
+        let mut visual_str = self.text.clone();
+        let mut levels = self.levels.clone();
+
+        mirror::shape_mirroring(&levels, &mut visual_str)?;
+
+        let mut previous_end = 0;
+        for line in lines
+        {
+            if line.start < previous_end
+            {
+                return Err(Error::InvalidRange { start: line.start, end: line.end, len: self.len() });
+            }
+            previous_end = line.end;
+
+            Fribidi::reorder_line_range(
+                FriBidiFlag::Default,
+                &self.char_types,
+                line.clone(),
+                self.base_direction,
+                &mut levels,
+                &mut visual_str,
+                None
+            )?;
+        }
+
+        Ok(lines
+            .iter()
+            .map(|line| visual_str[line.clone()].to_string_lossy())
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod test
 {
-    use crate::{char::CharType, paragraph::ParagraphType};
+    use std::ops::Range;
+
+    use crate::{char::CharType, error::Error, level::LevelType, paragraph::{BidiParagraph, ParagraphType}};
 
     #[test]
     fn test_direction ()
@@ -65,8 +320,70 @@ mod test
         let char_types = vec![CharType::ArabicLetter, CharType::ArabicNumeral, CharType::LeftToRight, CharType::LeftToRight];
         let gt = ParagraphType::RightToLeft;
 
-        let par_dir = ParagraphType::direction(&char_types);
+        let par_dir = ParagraphType::direction(&char_types).unwrap();
 
         assert_eq!(par_dir, gt);
+        assert_eq!(ParagraphType::direction(&[CharType::WhiteSpace]), Ok(ParagraphType::OtherNeutral));
+        assert_eq!(ParagraphType::try_from(ParagraphType::WeakRightToLeft.as_raw()), Ok(ParagraphType::WeakRightToLeft));
+        assert_eq!(ParagraphType::try_from(0x12345), Err(Error::InvalidParagraphType(0x12345)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bidi_paragraph ()
+    {
+        let paragraph = BidiParagraph::new("abc (אבג)", ParagraphType::OtherNeutral).unwrap();
+
+        assert_eq!(paragraph.base_direction(), ParagraphType::LeftToRight);
+        assert_eq!(paragraph.levels(), [0, 0, 0, 0, 0, 1, 1, 1, 0].map(LevelType).as_slice());
+        assert_eq!(paragraph.runs(), vec![(0..5, LevelType(0)), (5..8, LevelType(1)), (8..9, LevelType(0))]);
+        assert_eq!(paragraph.visual_line(0..9).unwrap(), "abc (גבא)");
+        assert_eq!(paragraph.visual_line(5..8).unwrap(), "גבא");
+        assert_eq!(
+            paragraph.visual_line(5..10),
+            Err(Error::InvalidRange { start: 5, end: 10, len: 9 })
+        );
+
+        let paragraph = BidiParagraph::new("אבג abc", ParagraphType::OtherNeutral).unwrap();
+
+        assert_eq!(paragraph.base_direction(), ParagraphType::RightToLeft);
+        assert_eq!(paragraph.visual_line(0..7).unwrap(), "abc גבא");
+
+        let (visual, map) = paragraph.visual_line_map(0..7).unwrap();
+        assert_eq!(visual, "abc גבא");
+        assert_eq!(map.chars, vec![4, 5, 6, 3, 2, 1, 0]);
+        assert_eq!(map.utf8, vec![7, 8, 9, 6, 4, 4, 2, 2, 0, 0]);
+        assert_eq!(map.utf16, vec![4, 5, 6, 3, 2, 1, 0]);
+
+        let paragraph = BidiParagraph::new("(אבג)", ParagraphType::RightToLeft).unwrap();
+
+        assert_eq!(paragraph.visual_line(0..5).unwrap(), "(גבא)");
+    }
+
+    #[test]
+    fn test_visual_lines ()
+    {
+        let paragraph = BidiParagraph::new("abc אבג דהו", ParagraphType::LeftToRight).unwrap();
+
+        // the space ending the first line is reset to the paragraph level
+        assert_eq!(paragraph.visual_line(0..11).unwrap(), "abc והד גבא");
+        assert_eq!(paragraph.visual_lines(&[0..8, 8..11]).unwrap(), vec!["abc גבא ", "והד"]);
+        assert_eq!(
+            paragraph.visual_lines(&[0..8, 7..11]),
+            Err(Error::InvalidRange { start: 7, end: 11, len: 11 })
+        );
+    }
+
+    #[test]
+    fn test_visual_runs ()
+    {
+        let paragraph = BidiParagraph::new("abc אבג 123 דהו", ParagraphType::LeftToRight).unwrap();
+        let runs: Vec<(Range<usize>, LevelType)> = paragraph
+            .visual_runs(4..15)
+            .unwrap()
+            .into_iter()
+            .map(|run| (run.logical_range, run.level))
+            .collect();
+
+        assert_eq!(runs, vec![(11..15, LevelType(1)), (8..11, LevelType(2)), (4..8, LevelType(1))]);
+    }
+}
@@ -0,0 +1,115 @@
+use std::ops::Range;
+
+use crate::char::CharType;
+use crate::error::Error;
+use crate::level::LevelType;
+
+/// A run of characters sharing one embedding level, as laid out on a line.
+///
+/// This is what shapers like HarfBuzz work on: every run is shaped on its own
+/// with the given direction, and the runs are drawn in visual order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BidiRun
+{
+    /// The characters of the run, in logical indices.
+    pub logical_range: Range<usize>,
+    pub level: LevelType,
+    /// LeftToRight for even levels, RightToLeft for odd ones.
+    pub direction: CharType,
+}
+
+/// Iterator over the runs of a reordered line, in visual order.  Returned by
+/// `BidiRun::visual_runs`.
+#[derive(Debug, Clone)]
+pub struct VisualRuns<'a>
+{
+    embedding_levels: &'a [LevelType],
+    map: &'a [u32],
+    position: usize,
+}
+
+impl Iterator for VisualRuns<'_>
+{
+    type Item = BidiRun;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let first = *self.map.get(self.position)? as usize;
+        let level = self.embedding_levels[first];
+        let mut logical_range = first..first + 1;
+
+        self.position += 1;
+        while let Some(&logical) = self.map.get(self.position)
+        {
+            let logical = logical as usize;
+            if self.embedding_levels[logical] != level
+            {
+                break;
+            }
+
+            logical_range.start = logical_range.start.min(logical);
+            logical_range.end = logical_range.end.max(logical + 1);
+            self.position += 1;
+        }
+
+        Some(BidiRun { logical_range, level, direction: level.to_chartype() })
+    }
+}
+
+impl BidiRun
+{
+    /// Split a reordered line into its level runs, in visual order.
+    ///
+    /// embedding_levels are the logical embedding levels after
+    /// `Fribidi::reorder_line` has applied rule L1 part 4 to them, and map is
+    /// the map returned by it: the logical index of every visual position.
+    /// The map may cover a single line of the levels.
+    ///
+    pub fn visual_runs<'a>(embedding_levels: &'a [LevelType], map: &'a [u32]) -> Result<VisualRuns<'a>, Error>
+    {
+        if let Some(&logical) = map.iter().find(|&&logical| logical as usize >= embedding_levels.len())
+        {
+            return Err(Error::InvalidRange {
+                start: logical as usize,
+                end: logical as usize + 1,
+                len: embedding_levels.len()
+            });
+        }
+
+        Ok(VisualRuns { embedding_levels, map, position: 0 })
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::char::CharType;
+    use crate::error::Error;
+    use crate::level::LevelType;
+    use crate::run::BidiRun;
+
+    #[test]
+    fn test_visual_runs()
+    {
+        // "abc אבג 123 דהו" in a left to right paragraph
+        let embedding_levels = [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1].map(LevelType);
+        let map: Vec<u32> = vec![0, 1, 2, 3, 14, 13, 12, 11, 8, 9, 10, 7, 6, 5, 4];
+
+        let runs: Vec<BidiRun> = BidiRun::visual_runs(&embedding_levels, &map).unwrap().collect();
+
+        assert_eq!(
+            runs,
+            vec![
+                BidiRun { logical_range: 0..4, level: LevelType(0), direction: CharType::LeftToRight },
+                BidiRun { logical_range: 11..15, level: LevelType(1), direction: CharType::RightToLeft },
+                BidiRun { logical_range: 8..11, level: LevelType(2), direction: CharType::LeftToRight },
+                BidiRun { logical_range: 4..8, level: LevelType(1), direction: CharType::RightToLeft },
+            ]
+        );
+
+        assert_eq!(
+            BidiRun::visual_runs(&embedding_levels[..4], &map).err(),
+            Some(Error::InvalidRange { start: 14, end: 15, len: 4 })
+        );
+    }
+}
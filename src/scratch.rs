@@ -0,0 +1,155 @@
+use widestring::U32Str;
+
+use crate::bracket::BracketType;
+use crate::char::CharType;
+use crate::error::Error;
+use crate::level::LevelType;
+use crate::paragraph::ParagraphType;
+use crate::Fribidi;
+
+/// Reusable buffers for repeated bidi calls.
+///
+/// Each call sizes the buffers to its input and keeps them afterwards, so
+/// that once the workspace has seen the longest line, rendering more lines
+/// through it no longer allocates on the Rust side.  The results stay
+/// readable until the next call, which replaces all of them: after
+/// `paragraph_embedding_levels`, the visual string and position maps of an
+/// earlier `logic_to_visual` are gone, and read empty.
+#[derive(Debug, Clone, Default)]
+pub struct BidiScratch
+{
+    visual_str: Vec<u32>,
+    positions_logic_to_visual: Vec<i32>,
+    positions_visual_to_logic: Vec<i32>,
+    embedding_levels: Vec<LevelType>,
+}
+
+impl BidiScratch
+{
+    pub fn new() -> BidiScratch
+    {
+        BidiScratch::default()
+    }
+
+    /// A workspace whose buffers already hold len characters.
+    pub fn with_capacity(len: usize) -> BidiScratch
+    {
+        BidiScratch {
+            visual_str: Vec::with_capacity(len),
+            positions_logic_to_visual: Vec::with_capacity(len),
+            positions_visual_to_logic: Vec::with_capacity(len),
+            embedding_levels: Vec::with_capacity(len),
+        }
+    }
+
+    fn resize(&mut self, len: usize)
+    {
+        self.visual_str.resize(len, 0);
+        self.positions_logic_to_visual.resize(len, 0);
+        self.positions_visual_to_logic.resize(len, 0);
+        self.embedding_levels.resize(len, LevelType(0));
+    }
+
+    /// Run `Fribidi::logic_to_visual_into` on input_str, filling the visual
+    /// string, both position maps and the embedding levels.
+    ///
+    /// Returns: the maximum level found plus one.
+    ///
+    pub fn logic_to_visual(
+        &mut self,
+        input_str: &U32Str,
+        paragraph_direction: ParagraphType
+    ) -> Result<LevelType, Error>
+    {
+        self.resize(input_str.len());
+
+        Fribidi::logic_to_visual_into(
+            input_str,
+            paragraph_direction,
+            &mut self.visual_str,
+            Some(&mut self.positions_logic_to_visual),
+            Some(&mut self.positions_visual_to_logic),
+            Some(&mut self.embedding_levels)
+        )
+    }
+
+    /// Run `Fribidi::get_paragraph_embedding_levels_into`, filling the
+    /// embedding levels only.  The visual string and position maps are
+    /// emptied, keeping their capacity.
+    pub fn paragraph_embedding_levels(
+        &mut self,
+        char_types: &[CharType],
+        bracket_types: Option<&[BracketType]>,
+        paragraph_direction: ParagraphType
+    ) -> Result<(LevelType, ParagraphType), Error>
+    {
+        self.visual_str.clear();
+        self.positions_logic_to_visual.clear();
+        self.positions_visual_to_logic.clear();
+        self.embedding_levels.resize(char_types.len(), LevelType(0));
+
+        Fribidi::get_paragraph_embedding_levels_into(
+            char_types,
+            bracket_types,
+            paragraph_direction,
+            &mut self.embedding_levels
+        )
+    }
+
+    /// The visual string of the last `logic_to_visual` call.
+    pub fn visual_str(&self) -> &U32Str
+    {
+        U32Str::from_slice(&self.visual_str)
+    }
+
+    /// The logical to visual map of the last `logic_to_visual` call.
+    pub fn positions_logic_to_visual(&self) -> &[i32]
+    {
+        &self.positions_logic_to_visual
+    }
+
+    /// The visual to logical map of the last `logic_to_visual` call.
+    pub fn positions_visual_to_logic(&self) -> &[i32]
+    {
+        &self.positions_visual_to_logic
+    }
+
+    /// The embedding levels of the last call.
+    pub fn embedding_levels(&self) -> &[LevelType]
+    {
+        &self.embedding_levels
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use widestring::U32String;
+
+    use crate::char::CharType;
+    use crate::level::LevelType;
+    use crate::paragraph::ParagraphType;
+    use crate::scratch::BidiScratch;
+
+    #[test]
+    fn test_bidi_scratch()
+    {
+        let mut scratch = BidiScratch::with_capacity(16);
+
+        let max_level = scratch.logic_to_visual(&U32String::from("abc אבג"), ParagraphType::LeftToRight).unwrap();
+        assert_eq!(max_level, LevelType(2));
+        assert_eq!(scratch.visual_str(), U32String::from("abc גבא").as_ustr());
+        assert_eq!(scratch.positions_visual_to_logic(), [0, 1, 2, 3, 6, 5, 4]);
+
+        scratch.logic_to_visual(&U32String::from("אב"), ParagraphType::OtherNeutral).unwrap();
+        assert_eq!(scratch.visual_str(), U32String::from("בא").as_ustr());
+        assert_eq!(scratch.embedding_levels(), [LevelType(1); 2]);
+
+        let char_types = CharType::into_chartypes("a א");
+        let levels = scratch.paragraph_embedding_levels(&char_types, None, ParagraphType::RightToLeft).unwrap();
+        assert_eq!(levels, (LevelType(3), ParagraphType::RightToLeft));
+        assert_eq!(scratch.embedding_levels(), [2, 1, 1].map(LevelType));
+        assert!(scratch.visual_str().is_empty());
+        assert!(scratch.positions_visual_to_logic().is_empty());
+    }
+}
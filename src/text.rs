@@ -0,0 +1,177 @@
+use std::ops::Range;
+
+use widestring::{U32Str, U32String};
+
+use crate::char::CharType;
+use crate::error::Error;
+use crate::input::BidiInput;
+use crate::paragraph::{BidiParagraph, ParagraphType};
+
+const CARRIAGE_RETURN: u32 = '\r' as u32;
+const LINE_FEED: u32 = '\n' as u32;
+
+/// A multi-paragraph text run through the bidi algorithm.
+///
+/// The text is split into paragraphs at block separators (newlines, U+2029
+/// PARAGRAPH SEPARATOR, ...), as rule P1 of the Unicode Bidirectional
+/// Algorithm asks, and every paragraph is resolved on its own.  The
+/// separators themselves are kept out of the paragraphs and copied as they
+/// are into the visual output.
+#[derive(Debug, Clone)]
+pub struct BidiText
+{
+    text: U32String,
+    ranges: Vec<Range<usize>>,
+    paragraphs: Vec<BidiParagraph>,
+}
+
+impl BidiText
+{
+    /// Split text into paragraphs and resolve each of them.
+    ///
+    /// When base is OtherNeutral the direction of every paragraph is
+    /// detected from its content, and the paragraphs without a strong
+    /// character follow the heuristic described in `ParagraphType::direction`:
+    /// the leading ones take the direction of the first non-neutral
+    /// paragraph, the others carry over the direction of the paragraph
+    /// before them.  Any other base is used for all the paragraphs.
+    ///
+    pub fn new<T: BidiInput + ?Sized>(text: &T, base: ParagraphType) -> Result<BidiText, Error>
+    {
+        let text = text.to_u32str().into_owned();
+        let char_types = CharType::into_chartypes(text.as_vec());
+        let ranges = Self::split_paragraphs(&text, &char_types);
+
+        let mut paragraphs: Vec<BidiParagraph> = Vec::with_capacity(ranges.len());
+        for range in ranges.iter()
+        {
+            let paragraph_base = match base
+            {
+                ParagraphType::OtherNeutral => match ParagraphType::direction(&char_types[range.clone()])?
+                {
+                    ParagraphType::OtherNeutral => match paragraphs.last()
+                    {
+                        Some(previous) => previous.base_direction(),
+                        None => ParagraphType::direction(&char_types[range.start..])?
+                    },
+                    direction => direction
+                },
+                _ => base
+            };
+
+            paragraphs.push(BidiParagraph::from_u32string(text[range.clone()].to_owned(), paragraph_base)?);
+        }
+
+        Ok(BidiText { text, ranges, paragraphs })
+    }
+
+    /// Ranges of the paragraphs in the text, block separators excluded.
+    fn split_paragraphs(text: &U32Str, char_types: &[CharType]) -> Vec<Range<usize>>
+    {
+        let chars = text.as_slice();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+
+        while index < chars.len()
+        {
+            if char_types[index] != CharType::BlockSeparator
+            {
+                index += 1;
+                continue;
+            }
+
+            ranges.push(start..index);
+
+            // CR LF is a single paragraph separator
+            index += match chars[index] == CARRIAGE_RETURN && chars.get(index + 1) == Some(&LINE_FEED)
+            {
+                true => 2,
+                false => 1
+            };
+            start = index;
+        }
+
+        if start < chars.len() || ranges.is_empty()
+        {
+            ranges.push(start..chars.len());
+        }
+
+        ranges
+    }
+
+    /// The whole logical text, separators included.
+    pub fn text(&self) -> &U32Str
+    {
+        &self.text
+    }
+
+    /// The resolved paragraphs, in logical order.
+    pub fn paragraphs(&self) -> &[BidiParagraph]
+    {
+        &self.paragraphs
+    }
+
+    /// Where each paragraph lies in `text()`, separators excluded.
+    pub fn paragraph_ranges(&self) -> &[Range<usize>]
+    {
+        &self.ranges
+    }
+
+    /// The visual text: every paragraph reordered as a single line, joined
+    /// by the original block separators.
+    pub fn visual(&self) -> Result<String, Error>
+    {
+        let mut visual = String::with_capacity(self.text.len());
+        for (index, (range, paragraph)) in self.ranges.iter().zip(self.paragraphs.iter()).enumerate()
+        {
+            visual.push_str(&paragraph.visual_line(0..paragraph.len())?);
+
+            let separator_end = self.ranges.get(index + 1).map_or(self.text.len(), |next| next.start);
+            visual.push_str(&self.text[range.end..separator_end].to_string_lossy());
+        }
+
+        Ok(visual)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::paragraph::ParagraphType;
+    use crate::text::BidiText;
+
+    #[test]
+    fn test_bidi_text()
+    {
+        let text = BidiText::new("abc אבג\r\nאבג abc\n123\u{2029}", ParagraphType::OtherNeutral).unwrap();
+        let directions: Vec<ParagraphType> = text
+            .paragraphs()
+            .iter()
+            .map(|paragraph| paragraph.base_direction())
+            .collect();
+
+        assert_eq!(text.paragraph_ranges(), [0..7, 9..16, 17..20]);
+        assert_eq!(directions, vec![ParagraphType::LeftToRight, ParagraphType::RightToLeft, ParagraphType::RightToLeft]);
+        assert_eq!(text.visual().unwrap(), "abc גבא\r\nabc גבא\n123\u{2029}");
+    }
+
+    #[test]
+    fn test_leading_neutral_paragraph()
+    {
+        let text = BidiText::new("123\n\nאבג\nabc", ParagraphType::OtherNeutral).unwrap();
+        let directions: Vec<ParagraphType> = text
+            .paragraphs()
+            .iter()
+            .map(|paragraph| paragraph.base_direction())
+            .collect();
+
+        assert_eq!(
+            directions,
+            vec![ParagraphType::RightToLeft, ParagraphType::RightToLeft, ParagraphType::RightToLeft, ParagraphType::LeftToRight]
+        );
+
+        let text = BidiText::new("abc\nאבג", ParagraphType::LeftToRight).unwrap();
+        assert_eq!(text.paragraphs()[1].base_direction(), ParagraphType::LeftToRight);
+    }
+}
@@ -0,0 +1,156 @@
+use std::ffi::CStr;
+use std::fmt;
+
+use crate::fribidi_bindings;
+
+/// Version of the fribidi library, with its libtool interface version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LibraryVersion
+{
+    pub major: u32,
+    pub minor: u32,
+    pub micro: u32,
+    pub interface: u32,
+}
+
+impl LibraryVersion
+{
+    /// The version of the fribidi headers the bindings were generated from,
+    /// which may not be the one of the library loaded at runtime.
+    pub const COMPILED: LibraryVersion = LibraryVersion {
+        major: fribidi_bindings::FRIBIDI_MAJOR_VERSION,
+        minor: fribidi_bindings::FRIBIDI_MINOR_VERSION,
+        micro: fribidi_bindings::FRIBIDI_MICRO_VERSION,
+        interface: fribidi_bindings::FRIBIDI_INTERFACE_VERSION,
+    };
+
+    /// Read the version out of `fribidi_version_info`, whose first two lines
+    /// are like "(GNU FriBidi) 1.0.8" and "interface version 4,".
+    fn parse(version_info: &str) -> Option<LibraryVersion>
+    {
+        let mut lines = version_info.lines();
+        let (major, minor, micro) = parse_triple(lines.next()?.rsplit(' ').next()?)?;
+        let interface = lines
+            .next()?
+            .strip_prefix("interface version ")?
+            .trim_end_matches(',')
+            .parse()
+            .ok()?;
+
+        Some(LibraryVersion { major, minor, micro, interface })
+    }
+
+    /// Version of the linked library, read from `version_info`.
+    ///
+    /// Returns: None if `fribidi_version_info` does not start with the
+    /// library and interface versions.
+    ///
+    pub fn linked() -> Option<LibraryVersion>
+    {
+        LibraryVersion::parse(version_info())
+    }
+}
+
+impl fmt::Display for LibraryVersion
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
+
+/// Version of the Unicode Character Database fribidi takes its data from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeVersion
+{
+    pub major: u32,
+    pub minor: u32,
+    pub micro: u32,
+}
+
+impl UnicodeVersion
+{
+    /// The Unicode version of the fribidi headers the bindings were generated
+    /// from, which may not be the one of the library loaded at runtime.
+    pub const COMPILED: UnicodeVersion = UnicodeVersion {
+        major: fribidi_bindings::FRIBIDI_UNICODE_MAJOR_VERSION,
+        minor: fribidi_bindings::FRIBIDI_UNICODE_MINOR_VERSION,
+        micro: fribidi_bindings::FRIBIDI_UNICODE_MICRO_VERSION,
+    };
+
+    /// same as `fribidi_unicode_version` - the Unicode version of the linked
+    /// library's data
+    ///
+    /// Returns: None if `fribidi_unicode_version` is not a major.minor.micro
+    /// version.
+    ///
+    pub fn linked() -> Option<UnicodeVersion>
+    {
+        let unicode_version = unsafe { CStr::from_ptr(fribidi_bindings::fribidi_unicode_version) };
+        let (major, minor, micro) = parse_triple(unicode_version.to_str().ok()?)?;
+
+        Some(UnicodeVersion { major, minor, micro })
+    }
+}
+
+impl fmt::Display for UnicodeVersion
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
+
+fn parse_triple(version: &str) -> Option<(u32, u32, u32)>
+{
+    let mut numbers = version.split('.').map(|number| number.parse().ok());
+    let triple = (numbers.next()??, numbers.next()??, numbers.next()??);
+
+    match numbers.next()
+    {
+        None => Some(triple),
+        Some(_) => None
+    }
+}
+
+/// same as `fribidi_version_info` - the version banner of the linked library
+///
+/// It starts with the library, interface and Unicode versions, and goes on
+/// with the build options and the copyright notice.
+///
+pub fn version_info() -> &'static str
+{
+    let version_info = unsafe { CStr::from_ptr(fribidi_bindings::fribidi_version_info) };
+    version_info.to_str().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::version::{LibraryVersion, UnicodeVersion, version_info};
+
+    #[test]
+    fn test_parse_version_info()
+    {
+        let version_info = "(GNU FriBidi) 1.0.8\ninterface version 4,\n\
+                            Unicode Character Database version 10.0.0,\nConfigure options.\n";
+
+        assert_eq!(
+            LibraryVersion::parse(version_info),
+            Some(LibraryVersion { major: 1, minor: 0, micro: 8, interface: 4 })
+        );
+        assert_eq!(LibraryVersion::parse("(GNU FriBidi) 1.0\ninterface version 4,"), None);
+        assert_eq!(LibraryVersion::parse("(GNU FriBidi) 1.0.8"), None);
+    }
+
+    #[test]
+    fn test_linked_versions()
+    {
+        let library = LibraryVersion::linked().unwrap();
+        let unicode = UnicodeVersion::linked().unwrap();
+
+        assert!(version_info().starts_with(&format!("(GNU FriBidi) {}\n", library)));
+        assert!(version_info().contains(&format!("Unicode Character Database version {},", unicode)));
+        assert_eq!(library.interface, LibraryVersion::COMPILED.interface);
+    }
+}
@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+"""Download BidiTest.txt and BidiCharacterTest.txt into tests/data/, for the
+conformance tests of src/conformance.rs.
+
+The tests read the files of the Unicode version the backend is built for,
+by default the one of fribidi-sys/src/fribidi_bindings.rs:
+
+    tools/fetch_bidi_tests.py [unicode-version]
+
+The pure-rust backend follows fribidi 1.0.8, that is Unicode 10.0.0.
+"""
+
+import os
+import re
+import sys
+import urllib.request
+
+ROOT_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+DATA_DIR = os.path.join(ROOT_DIR, "tests", "data")
+BINDINGS = os.path.join(ROOT_DIR, "fribidi-sys", "src", "fribidi_bindings.rs")
+URL = "https://www.unicode.org/Public/{version}/ucd/{name}"
+NAMES = ["BidiTest.txt", "BidiCharacterTest.txt"]
+
+
+def bindings_unicode_version():
+    with open(BINDINGS) as bindings:
+        return re.search(r'FRIBIDI_UNICODE_VERSION: .* = b"([\d.]+)\\0"', bindings.read()).group(1)
+
+
+def main():
+    version = sys.argv[1] if len(sys.argv) > 1 else bindings_unicode_version()
+    if not re.fullmatch(r"\d+\.\d+\.\d+", version):
+        sys.exit(__doc__)
+
+    version_dir = os.path.join(DATA_DIR, "unicode-" + version)
+    os.makedirs(version_dir, exist_ok=True)
+    for name in NAMES:
+        with urllib.request.urlopen(URL.format(version=version, name=name)) as response:
+            data = response.read()
+        with open(os.path.join(version_dir, name), "wb") as test_file:
+            test_file.write(data)
+        print("%s: %d bytes" % (os.path.join(version_dir, name), len(data)))
+
+
+if __name__ == "__main__":
+    main()
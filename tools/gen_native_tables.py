@@ -0,0 +1,198 @@
+#!/usr/bin/env python3
+"""Generate src/native/tables.rs, the Unicode data of the pure-rust backend.
+
+The tables are read back from an installed libfribidi through ctypes rather
+than from the Unicode Character Database, so that the native backend gives
+the very same answers as the C library it stands in for:
+
+    tools/gen_native_tables.py [path/to/libfribidi.so] > src/native/tables.rs
+"""
+
+import ctypes
+import ctypes.util
+import sys
+
+UNICODE_CHARS = 0x110000
+TYPE_LTR = 0x110
+JOINING_TYPE_U = 0
+ARAB_SHAPES = 0x04
+FLAG_SHAPE_ARAB_PRES = 0x100
+FLAG_SHAPE_ARAB_LIGA = 0x200
+FLAG_SHAPE_ARAB_CONSOLE = 0x400
+CHAR_SET_CAP_RTL = 2
+SINGLE_BYTE_CHAR_SETS = [(3, "ISO8859_6"), (4, "ISO8859_8"), (5, "CP1255"), (6, "CP1256")]
+# Where fribidi takes the Arabic presentation forms and ligatures from.
+ARABIC_CHARS = (
+    list(range(0x0600, 0x0700)) + list(range(0x0750, 0x0780)) + list(range(0x08a0, 0x0900))
+    + list(range(0xfb50, 0xfe00)) + list(range(0xfe70, 0xff00))
+)
+
+
+def load(path):
+    lib = ctypes.CDLL(path)
+    lib.fribidi_char_set_desc.restype = ctypes.c_char_p
+    return lib
+
+
+def all_chars():
+    return (ctypes.c_uint32 * UNICODE_CHARS)(*range(UNICODE_CHARS))
+
+
+def ranges(values, default):
+    """(first, last, value) for the runs of values that are not default."""
+    result = []
+    for ch, value in enumerate(values):
+        if value == default:
+            continue
+        if result and result[-1][2] == value and result[-1][1] == ch - 1:
+            result[-1][1] = ch
+        else:
+            result.append([ch, ch, value])
+    return result
+
+
+def shape_arabic(lib, flags, chars, props, level=1):
+    n = len(chars)
+    text = (ctypes.c_uint32 * n)(*chars)
+    ar_props = (ctypes.c_uint8 * n)(*props)
+    levels = (ctypes.c_int8 * n)(*([level] * n))
+    lib.fribidi_shape_arabic(flags, levels, n, ar_props, text)
+    return list(text), list(ar_props)
+
+
+def shaping_table(lib, flags):
+    """Every character the joining shaping of flags changes, with its four
+    forms indexed by joining shape."""
+    table = {}
+    chars = list(range(0x10000))
+    for shape in range(4):
+        shaped, _ = shape_arabic(lib, flags, chars, [ARAB_SHAPES | shape] * len(chars))
+        for ch, shaped_ch in zip(chars, shaped):
+            if shaped_ch != ch:
+                table.setdefault(ch, [ch] * 4)[shape] = shaped_ch
+    return sorted(table.items())
+
+
+def ligature_table(lib, flags):
+    """Every pair of characters the ligature shaping of flags joins."""
+    table = []
+    for first in ARABIC_CHARS:
+        chars = []
+        for second in ARABIC_CHARS:
+            chars += [first, second, 0x20]
+        shaped, props = shape_arabic(lib, flags, chars, [0] * len(chars))
+        for index, second in enumerate(ARABIC_CHARS):
+            if shaped[3 * index + 1] != second:
+                assert shaped[3 * index] == 0xfeff and props[3 * index] != 0
+                table.append((first, second, shaped[3 * index + 1]))
+    return table
+
+
+def to_unicode(lib, char_set, data):
+    out = (ctypes.c_uint32 * (len(data) + 1))()
+    n = lib.fribidi_charset_to_unicode(char_set, bytes(data), len(data), out)
+    return list(out[:n])
+
+
+def from_unicode(lib, char_set, chars):
+    out = ctypes.create_string_buffer(len(chars) * 4 + 1)
+    n = lib.fribidi_unicode_to_charset(char_set, (ctypes.c_uint32 * len(chars))(*chars), len(chars), out)
+    return out.raw[:n]
+
+
+def rust_str(value):
+    escapes = {"\\": "\\\\", '"': '\\"', "\n": "\\n", "\0": "\\0"}
+    return '"' + "".join(escapes.get(ch, ch) for ch in value) + '"'
+
+
+def hexes(values):
+    return ", ".join("0x%04x" % value for value in values)
+
+
+def write_rows(out, name, rust_type, rows, per_line):
+    out.write("pub(crate) static %s: [%s; %d] = [\n" % (name, rust_type, len(rows)))
+    for start in range(0, len(rows), per_line):
+        out.write("    " + " ".join(row + "," for row in rows[start:start + per_line]) + "\n")
+    out.write("];\n\n")
+
+
+def main():
+    lib = load(sys.argv[1] if len(sys.argv) > 1 else ctypes.util.find_library("fribidi"))
+    version = ctypes.c_char_p.in_dll(lib, "fribidi_version_info").value.decode().split("\n")[0]
+    version = version.replace("(GNU FriBidi)", "GNU FriBidi").strip()
+    unicode_version = ctypes.c_char_p.in_dll(lib, "fribidi_unicode_version").value.decode()
+    out = sys.stdout
+
+    out.write("// Generated by tools/gen_native_tables.py from %s, Unicode %s.\n"
+              % (version, unicode_version))
+    out.write("// Do not edit.\n\n")
+
+    chars = all_chars()
+    bidi_types = (ctypes.c_uint32 * UNICODE_CHARS)()
+    lib.fribidi_get_bidi_types(chars, UNICODE_CHARS, bidi_types)
+    out.write("/// Bidi types of the characters that are not LTR, as (first, last, type).\n")
+    write_rows(out, "BIDI_TYPES", "(u32, u32, u32)",
+               ["(0x%04x, 0x%04x, 0x%x)" % tuple(row) for row in ranges(bidi_types, TYPE_LTR)], 3)
+
+    joining_types = (ctypes.c_uint8 * UNICODE_CHARS)()
+    lib.fribidi_get_joining_types(chars, UNICODE_CHARS, joining_types)
+    out.write("/// Joining types of the characters that are not U, as (first, last, type).\n")
+    write_rows(out, "JOINING_TYPES", "(u32, u32, u8)",
+               ["(0x%04x, 0x%04x, %d)" % tuple(row) for row in ranges(joining_types, JOINING_TYPE_U)], 4)
+
+    mirrored = ctypes.c_uint32()
+    mirrors = [(ch, mirrored.value) for ch in range(UNICODE_CHARS)
+               if lib.fribidi_get_mirror_char(ch, ctypes.byref(mirrored))]
+    out.write("/// Mirrored characters, as (character, mirror).\n")
+    write_rows(out, "MIRRORS", "(u32, u32)", ["(%s)" % hexes(row) for row in mirrors], 5)
+
+    brackets = [(ch, lib.fribidi_get_bracket(ch) & 0xffffffff) for ch in range(UNICODE_CHARS)
+                if lib.fribidi_get_bracket(ch)]
+    out.write("/// Bracket types of the paired brackets, as (character, bracket type).\n")
+    write_rows(out, "BRACKETS", "(u32, u32)", ["(0x%04x, 0x%08x)" % row for row in brackets], 4)
+
+    for name, flags in (("ARABIC_SHAPING_PRES", FLAG_SHAPE_ARAB_PRES),
+                        ("ARABIC_SHAPING_NSM", FLAG_SHAPE_ARAB_CONSOLE)):
+        out.write("/// Presentation forms, as (character, [isolated, final, initial, medial]).\n")
+        write_rows(out, name, "(u32, [u32; 4])",
+                   ["(0x%04x, [%s])" % (ch, hexes(forms)) for ch, forms in shaping_table(lib, flags)], 2)
+
+    for name, flags in (("LIGATURES_MANDATORY", FLAG_SHAPE_ARAB_LIGA),
+                        ("LIGATURES_CONSOLE", FLAG_SHAPE_ARAB_CONSOLE)):
+        out.write("/// Ligatures, as (first, second, ligature).\n")
+        write_rows(out, name, "(u32, u32, u32)", ["(%s)" % hexes(row) for row in ligature_table(lib, flags)], 4)
+
+    cap_rtl = to_unicode(lib, CHAR_SET_CAP_RTL, range(0x80))
+    assert len(cap_rtl) == 0x80 and to_unicode(lib, CHAR_SET_CAP_RTL, range(0x80, 0x100)) == [0x3f] * 0x80
+    out.write("/// CapRTL characters, indexed by their ASCII code.\n")
+    write_rows(out, "CAP_RTL_TO_UNICODE", "u32", ["0x%04x" % ch for ch in cap_rtl], 8)
+
+    # escaped characters encode to two bytes, leave them out
+    plain = [ch for ch in range(UNICODE_CHARS)
+             if not (bidi_types[ch] & 0x9000) and ch not in (0x5f, 0x200e, 0x200f)]
+    encoded = from_unicode(lib, CHAR_SET_CAP_RTL, plain)
+    assert len(encoded) == len(plain)
+    out.write("/// Characters CapRTL can encode, as (character, byte).\n")
+    write_rows(out, "CAP_RTL_FROM_UNICODE", "(u32, u8)",
+               ["(0x%04x, 0x%02x)" % (ch, byte) for ch, byte in zip(plain, encoded) if byte != 0x3f or ch == 0x3f], 6)
+
+    # NUL terminated, as fribidi_char_set_desc returns it
+    desc = lib.fribidi_char_set_desc(CHAR_SET_CAP_RTL).decode() + "\0"
+    out.write("pub(crate) const CAP_RTL_DESCRIPTION: &str = %s;\n\n" % rust_str(desc))
+
+    for char_set, name in SINGLE_BYTE_CHAR_SETS:
+        decoded = to_unicode(lib, char_set, range(0x100))
+        assert len(decoded) == 0x100
+        out.write("/// %s characters, indexed by their byte.\n" % name)
+        write_rows(out, name + "_TO_UNICODE", "u32", ["0x%04x" % ch for ch in decoded], 8)
+
+        encoded = from_unicode(lib, char_set, range(UNICODE_CHARS))
+        assert len(encoded) == UNICODE_CHARS
+        out.write("/// Characters %s can encode, as (character, byte).\n" % name)
+        write_rows(out, name + "_FROM_UNICODE", "(u32, u8)",
+                   ["(0x%04x, 0x%02x)" % (ch, byte) for ch, byte in enumerate(encoded) if byte != 0x3f or ch == 0x3f],
+                   6)
+
+
+if __name__ == "__main__":
+    main()
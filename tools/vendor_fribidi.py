@@ -0,0 +1,129 @@
+#!/usr/bin/env python3
+"""Copy a fribidi release into fribidi-sys/vendor/fribidi, for the `static` feature.
+
+Only the library sources are kept. The Unicode tables, which fribidi's own
+build generates from gen.tab/unidata with small host programs, are generated
+here once, so that building the crate needs nothing more than a C compiler:
+
+    tools/vendor_fribidi.py path/to/fribidi-x.y.z [cc]
+
+The release has to be the one fribidi-sys/src/fribidi_bindings.rs is for.
+
+fribidi-config.h is not vendored, fribidi-sys/build.rs writes it for the
+target it compiles for.
+"""
+
+import os
+import re
+import shutil
+import subprocess
+import sys
+import tempfile
+
+SYS_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "fribidi-sys")
+VENDOR_DIR = os.path.join(SYS_DIR, "vendor", "fribidi")
+COMPRESSION = "2"
+# The generator, its unidata inputs and its output, as in gen.tab/meson.build.
+TABLES = [
+    ("gen-bidi-type-tab", ["UnicodeData.txt"], "bidi-type.tab.i"),
+    ("gen-joining-type-tab", ["UnicodeData.txt", "ArabicShaping.txt"], "joining-type.tab.i"),
+    ("gen-arabic-shaping-tab", ["UnicodeData.txt"], "arabic-shaping.tab.i"),
+    ("gen-mirroring-tab", ["BidiMirroring.txt"], "mirroring.tab.i"),
+    ("gen-brackets-tab", ["BidiBrackets.txt", "UnicodeData.txt"], "brackets.tab.i"),
+    ("gen-brackets-type-tab", ["BidiBrackets.txt"], "brackets-type.tab.i"),
+]
+# Just enough for the generators, which run on this machine.
+HOST_FRIBIDI_CONFIG_H = """#ifndef FRIBIDI_CONFIG_H
+#define FRIBIDI_CONFIG_H
+#define FRIBIDI "fribidi"
+#define FRIBIDI_NAME "GNU FriBidi"
+#define FRIBIDI_VERSION "{version}"
+#define FRIBIDI_INTERFACE_VERSION_STRING "4"
+#define FRIBIDI_SIZEOF_INT 4
+#define FRIBIDI_BUILT_WITH_MSVC 0
+#endif
+"""
+HOST_CONFIG_H = """#define STDC_HEADERS 1
+#define HAVE_STDLIB_H 1
+#define HAVE_STRING_H 1
+#define HAVE_STRINGS_H 1
+"""
+
+
+def bindings_version():
+    with open(os.path.join(SYS_DIR, "src", "fribidi_bindings.rs")) as bindings:
+        return re.search(r'FRIBIDI_VERSION: [^=]+= b"([^"\\]+)', bindings.read()).group(1)
+
+
+def check_version(source_dir, version):
+    with open(os.path.join(source_dir, "meson.build")) as meson_build:
+        match = re.search(r"version\s*:\s*'([^']+)'", meson_build.read())
+    if match is None or match.group(1) != version:
+        sys.exit("expected fribidi %s in %s, as fribidi-sys/src/fribidi_bindings.rs" % (version, source_dir))
+
+
+def run(command, output_path=None):
+    if output_path is None:
+        subprocess.run(command, check=True)
+    else:
+        with open(output_path, "w") as output:
+            subprocess.run(command, check=True, stdout=output)
+
+
+def generate_tables(source_dir, lib_dir, compiler, version):
+    gen_tab_dir = os.path.join(source_dir, "gen.tab")
+    unidata_dir = os.path.join(gen_tab_dir, "unidata")
+
+    with tempfile.TemporaryDirectory() as build_dir:
+        with open(os.path.join(build_dir, "fribidi-config.h"), "w") as config:
+            config.write(HOST_FRIBIDI_CONFIG_H.format(version=version))
+        with open(os.path.join(build_dir, "config.h"), "w") as config:
+            config.write(HOST_CONFIG_H)
+
+        def build(program, sources):
+            executable = os.path.join(build_dir, program)
+            run(
+                [compiler, "-DHAVE_CONFIG_H", "-I" + build_dir, "-I" + lib_dir, "-I" + gen_tab_dir, "-o", executable]
+                + [os.path.join(gen_tab_dir, source) for source in sources]
+            )
+            return executable
+
+        # the tables include the version header, so it goes first
+        generator = build("gen-unicode-version", ["gen-unicode-version.c"])
+        run(
+            [generator, os.path.join(unidata_dir, "ReadMe.txt"), os.path.join(unidata_dir, "BidiMirroring.txt"),
+             "gen-unicode-version"],
+            os.path.join(lib_dir, "fribidi-unicode-version.h"),
+        )
+
+        for program, inputs, output in TABLES:
+            generator = build(program, [program + ".c", "packtab.c"])
+            run(
+                [generator, COMPRESSION] + [os.path.join(unidata_dir, name) for name in inputs] + [program],
+                os.path.join(lib_dir, output),
+            )
+
+
+def main():
+    if len(sys.argv) not in (2, 3):
+        sys.exit(__doc__)
+    source_dir = sys.argv[1]
+    compiler = sys.argv[2] if len(sys.argv) == 3 else os.environ.get("CC", "cc")
+    version = bindings_version()
+    check_version(source_dir, version)
+
+    shutil.rmtree(VENDOR_DIR, ignore_errors=True)
+    lib_dir = os.path.join(VENDOR_DIR, "lib")
+    os.makedirs(lib_dir)
+    shutil.copy(os.path.join(source_dir, "COPYING"), VENDOR_DIR)
+    for name in sorted(os.listdir(os.path.join(source_dir, "lib"))):
+        # a configured tree may hold its own fribidi-config.h, which would
+        # shadow the one build.rs writes
+        if name.endswith((".c", ".h")) and name not in ("fribidi-config.h", "config.h"):
+            shutil.copy(os.path.join(source_dir, "lib", name), lib_dir)
+
+    generate_tables(source_dir, lib_dir, compiler, version)
+
+
+if __name__ == "__main__":
+    main()